@@ -0,0 +1,621 @@
+//! Business-day calendars, with weekends and public holidays.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use cal::DatePiece;
+use cal::datetime::{LocalDate, Month, Weekday, Year};
+
+
+/// A **business calendar** knows which days are working days, and which
+/// are weekends or holidays.
+///
+/// Holidays can be given as a list of dates, or as rules that produce a
+/// date every year, such as “the 25th of December”, “the last Monday in
+/// May”, or “the day after Easter Sunday”.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::{LocalDate, Month};
+/// use datetime::business::{BusinessCalendar, HolidayRule, Observance};
+///
+/// let mut calendar = BusinessCalendar::new();
+/// calendar.add_rule(HolidayRule::fixed("Christmas Day", Month::December, 25)
+///                       .observed(Observance::NextMonday));
+///
+/// // Christmas 2016 was a Sunday, so the holiday was taken on Monday.
+/// let friday = LocalDate::ymd(2016, Month::December, 23).unwrap();
+/// assert_eq!(calendar.add_business_days(friday, 1),
+///            LocalDate::ymd(2016, Month::December, 27).unwrap());
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+pub struct BusinessCalendar {
+    weekend: [bool; 7],
+    holidays: BTreeSet<LocalDate>,
+    rules: HolidaySet,
+}
+
+impl BusinessCalendar {
+
+    /// Creates a new calendar with Saturday and Sunday as the weekend,
+    /// and no holidays.
+    pub fn new() -> BusinessCalendar {
+        BusinessCalendar::with_weekend(&[ Weekday::Saturday, Weekday::Sunday ])
+    }
+
+    /// Creates a new calendar with the given days as the weekend, and no
+    /// holidays.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if every day of the week is part of the weekend, as there
+    /// would be no business days at all.
+    pub fn with_weekend(weekend: &[Weekday]) -> BusinessCalendar {
+        let days = weekend_days(weekend);
+        assert!(days.iter().any(|d| !d), "a business calendar needs at least one working day a week");
+        BusinessCalendar { weekend: days, holidays: BTreeSet::new(), rules: HolidaySet::new() }
+    }
+
+    /// Adds a single date as a holiday.
+    pub fn add_holiday(&mut self, date: LocalDate) {
+        let _ = self.holidays.insert(date);
+    }
+
+    /// Adds a rule for a holiday that happens every year.
+    pub fn add_rule(&mut self, rule: HolidayRule) {
+        self.rules.rules.push(rule);
+    }
+
+    /// Adds every rule in a set of holidays, such as one of the built-in
+    /// sets in the `holidays` module.
+    pub fn add_holiday_set(&mut self, set: &HolidaySet) {
+        self.rules.extend(set);
+    }
+
+    /// Returns whether the given date falls on the weekend.
+    pub fn is_weekend(&self, date: LocalDate) -> bool {
+        self.weekend[date.weekday() as usize]
+    }
+
+    /// Returns whether the given date is a holiday, either from the list
+    /// of dates or from one of the rules. Holidays that are observed on a
+    /// different day count on the day they are observed.
+    pub fn is_holiday(&self, date: LocalDate) -> bool {
+        ObservedHolidays::new(self).is_holiday(date)
+    }
+
+    /// Returns whether the given date is a business day, meaning it isn’t
+    /// on the weekend and isn’t a holiday.
+    pub fn is_business_day(&self, date: LocalDate) -> bool {
+        ObservedHolidays::new(self).is_business_day(date)
+    }
+
+    /// Returns the dates of every holiday observed in the given year, in
+    /// order.
+    pub fn holidays(&self, year: i64) -> Vec<LocalDate> {
+        let mut dates: BTreeSet<LocalDate> = self.holidays.iter().filter(|d| d.year() == year).cloned().collect();
+
+        for y in year - 1 ..= year + 1 {
+            for holiday in self.rules.holidays_with_weekend(Year(y), &self.weekend) {
+                if holiday.observed.year() == year {
+                    let _ = dates.insert(holiday.observed);
+                }
+            }
+        }
+
+        dates.into_iter().collect()
+    }
+
+    /// Returns the first business day after the given date.
+    pub fn next_business_day(&self, date: LocalDate) -> LocalDate {
+        ObservedHolidays::new(self).step(date, 1)
+    }
+
+    /// Returns the last business day before the given date.
+    pub fn previous_business_day(&self, date: LocalDate) -> LocalDate {
+        ObservedHolidays::new(self).step(date, -1)
+    }
+
+    /// Returns the date that’s the given number of business days after
+    /// the given date, or before it if the number is negative. This is how
+    /// settlement dates such as “T+2” are worked out.
+    ///
+    /// Adding zero business days returns the date unchanged, even if it
+    /// isn’t a business day itself.
+    pub fn add_business_days(&self, date: LocalDate, days: i64) -> LocalDate {
+        let direction = days.signum();
+        let mut observed = ObservedHolidays::new(self);
+        (0 .. days.abs()).fold(date, |d, _| observed.step(d, direction))
+    }
+
+    /// Returns the number of business days from the start date up to but
+    /// not including the end date, which is negative if the end date is
+    /// before the start date.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, Month};
+    /// use datetime::business::BusinessCalendar;
+    ///
+    /// let calendar = BusinessCalendar::new();
+    /// let monday = LocalDate::ymd(2017, Month::May, 1).unwrap();
+    /// let next_monday = LocalDate::ymd(2017, Month::May, 8).unwrap();
+    ///
+    /// assert_eq!(calendar.business_days_between(monday, next_monday), 5);
+    /// assert_eq!(calendar.business_days_between(next_monday, monday), -5);
+    /// ```
+    pub fn business_days_between(&self, start: LocalDate, end: LocalDate) -> i64 {
+        if end < start {
+            return -self.business_days_between(end, start);
+        }
+
+        let mut observed = ObservedHolidays::new(self);
+        (0 .. start.days_until(end))
+            .filter(|n| observed.is_business_day(start.add_days(*n)))
+            .count() as i64
+    }
+
+    /// Moves a date that isn’t a business day onto one, following the
+    /// given convention. Business days are returned unchanged.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, Month};
+    /// use datetime::business::{BusinessCalendar, Rolling};
+    ///
+    /// let calendar = BusinessCalendar::new();
+    /// let saturday = LocalDate::ymd(2017, Month::September, 30).unwrap();
+    ///
+    /// assert_eq!(calendar.roll(saturday, Rolling::Following),
+    ///            LocalDate::ymd(2017, Month::October, 2).unwrap());
+    /// assert_eq!(calendar.roll(saturday, Rolling::ModifiedFollowing),
+    ///            LocalDate::ymd(2017, Month::September, 29).unwrap());
+    /// ```
+    pub fn roll(&self, date: LocalDate, rolling: Rolling) -> LocalDate {
+        let mut observed = ObservedHolidays::new(self);
+        if observed.is_business_day(date) {
+            return date;
+        }
+
+        let following = observed.step(date, 1);
+        let preceding = observed.step(date, -1);
+
+        match rolling {
+            Rolling::Following          => following,
+            Rolling::Preceding          => preceding,
+            Rolling::ModifiedFollowing  => if following.month() == date.month() { following } else { preceding },
+            Rolling::ModifiedPreceding  => if preceding.month() == date.month() { preceding } else { following },
+        }
+    }
+}
+
+impl Default for BusinessCalendar {
+    fn default() -> BusinessCalendar {
+        BusinessCalendar::new()
+    }
+}
+
+
+/// The dates a business calendar’s holiday rules are observed on, worked
+/// out a year at a time as they’re needed, so methods that look at many
+/// days in a row don’t work out the same year over and over again.
+struct ObservedHolidays<'c> {
+    calendar: &'c BusinessCalendar,
+    years: BTreeMap<i64, BTreeSet<LocalDate>>,
+}
+
+impl<'c> ObservedHolidays<'c> {
+    fn new(calendar: &'c BusinessCalendar) -> ObservedHolidays<'c> {
+        ObservedHolidays { calendar, years: BTreeMap::new() }
+    }
+
+    fn is_holiday(&mut self, date: LocalDate) -> bool {
+        if self.calendar.holidays.contains(&date) {
+            return true;
+        }
+
+        // An observed holiday can move into the neighbouring year, such
+        // as New Year’s Day being observed on the 31st of December.
+        for year in date.year() - 1 ..= date.year() + 1 {
+            let calendar = self.calendar;
+            let observed = self.years.entry(year).or_insert_with(|| {
+                calendar.rules.holidays_with_weekend(Year(year), &calendar.weekend)
+                        .into_iter().map(|h| h.observed).collect()
+            });
+
+            if observed.contains(&date) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn is_business_day(&mut self, date: LocalDate) -> bool {
+        !self.calendar.is_weekend(date) && !self.is_holiday(date)
+    }
+
+    /// Returns the first business day after the given date, or before it
+    /// if the direction is negative.
+    fn step(&mut self, mut date: LocalDate, direction: i64) -> LocalDate {
+        loop {
+            date = date.add_days(direction);
+            if self.is_business_day(date) {
+                return date;
+            }
+        }
+    }
+}
+
+
+/// A convention for moving a date onto a business day.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Rolling {
+
+    /// Use the next business day.
+    Following,
+
+    /// Use the next business day, unless it’s in the next month, in which
+    /// case use the previous business day.
+    ModifiedFollowing,
+
+    /// Use the previous business day.
+    Preceding,
+
+    /// Use the previous business day, unless it’s in the previous month,
+    /// in which case use the next business day.
+    ModifiedPreceding,
+}
+
+
+/// A rule for a holiday that happens once every year.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct HolidayRule {
+
+    /// The name of the holiday.
+    pub name: String,
+
+    /// The day of the year the holiday falls on.
+    pub date: HolidayDate,
+
+    /// What happens when the holiday falls on a weekend.
+    pub observance: Observance,
+
+    /// The first year the holiday happens in, if it was introduced at
+    /// some point.
+    pub first_year: Option<i64>,
+
+    /// The last year the holiday happens in, if it was abolished or moved
+    /// at some point.
+    pub last_year: Option<i64>,
+}
+
+/// The day of the year that a holiday rule falls on.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum HolidayDate {
+
+    /// The same day of the same month every year. The holiday is skipped
+    /// in years where the day doesn’t exist, such as the 29th of February
+    /// in common years.
+    Fixed { month: Month, day: i8 },
+
+    /// The nth occurrence of a weekday in a month, such as the fourth
+    /// Thursday in November. Negative numbers count back from the end of
+    /// the month, so -1 is the last one.
+    NthWeekday { month: Month, n: i8, weekday: Weekday },
+
+    /// The first occurrence of a weekday on or after a day of a month,
+    /// such as the Wednesday on or after the 16th of November.
+    WeekdayOnOrAfter { month: Month, day: i8, weekday: Weekday },
+
+    /// A number of days after Western Easter Sunday, or before it if the
+    /// offset is negative, such as -2 for Good Friday or 50 for Whit
+    /// Monday.
+    Easter { offset: i64 },
+
+    /// A number of days after Orthodox Easter Sunday, or before it if the
+    /// offset is negative.
+    OrthodoxEaster { offset: i64 },
+
+    /// The day of the March equinox in Japan Standard Time. This uses an
+    /// approximation that’s only valid from 1900 to 2150, so the holiday
+    /// is skipped in other years.
+    MarchEquinox,
+
+    /// The day of the September equinox in Japan Standard Time, with the
+    /// same limits as the March equinox.
+    SeptemberEquinox,
+}
+
+/// What happens when a holiday falls on a weekend.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Observance {
+
+    /// The holiday stays on its actual date.
+    Actual,
+
+    /// A holiday on a Saturday or Sunday is observed on the Monday after.
+    NextMonday,
+
+    /// A holiday on a Saturday is observed on the Friday before, and a
+    /// holiday on a Sunday is observed on the Monday after.
+    NearestWeekday,
+
+    /// A holiday on the weekend is observed on the next day that isn’t on
+    /// the weekend or already a holiday, as with bank holidays in the UK.
+    /// The weekend is Saturday and Sunday, unless the holiday is part of a
+    /// business calendar with a different one.
+    NextFreeWeekday,
+
+    /// A holiday on a Sunday is observed on the next day that isn’t
+    /// already a holiday, as with substitute holidays in Japan.
+    SundayToNextFreeDay,
+}
+
+impl HolidayRule {
+
+    /// Creates a new rule for a holiday on the same date every year.
+    pub fn fixed(name: &str, month: Month, day: i8) -> HolidayRule {
+        HolidayRule::new(name, HolidayDate::Fixed { month, day })
+    }
+
+    /// Creates a new rule for a holiday on the nth occurrence of a weekday
+    /// in a month, with negative numbers counting back from the end.
+    pub fn nth_weekday(name: &str, n: i8, weekday: Weekday, month: Month) -> HolidayRule {
+        HolidayRule::new(name, HolidayDate::NthWeekday { month, n, weekday })
+    }
+
+    /// Creates a new rule for a holiday the given number of days after
+    /// Western Easter Sunday, with negative numbers counting back from it.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, Month};
+    /// use datetime::business::HolidayRule;
+    ///
+    /// let good_friday = HolidayRule::easter("Good Friday", -2);
+    /// assert_eq!(good_friday.date(2024), Some(LocalDate::ymd(2024, Month::March, 29).unwrap()));
+    /// ```
+    pub fn easter(name: &str, offset: i64) -> HolidayRule {
+        HolidayRule::new(name, HolidayDate::Easter { offset })
+    }
+
+    /// Creates a new rule for a holiday the given number of days after
+    /// Orthodox Easter Sunday, with negative numbers counting back from it.
+    pub fn orthodox_easter(name: &str, offset: i64) -> HolidayRule {
+        HolidayRule::new(name, HolidayDate::OrthodoxEaster { offset })
+    }
+
+    fn new(name: &str, date: HolidayDate) -> HolidayRule {
+        HolidayRule { name: name.to_owned(), date, observance: Observance::Actual, first_year: None, last_year: None }
+    }
+
+    /// Returns this rule with a different observance.
+    pub fn observed(self, observance: Observance) -> HolidayRule {
+        HolidayRule { observance, ..self }
+    }
+
+    /// Returns this rule, only happening from the given year onwards.
+    pub fn since(self, year: i64) -> HolidayRule {
+        HolidayRule { first_year: Some(year), ..self }
+    }
+
+    /// Returns this rule, only happening up to and including the given
+    /// year.
+    pub fn until(self, year: i64) -> HolidayRule {
+        HolidayRule { last_year: Some(year), ..self }
+    }
+
+    /// Returns the actual date of this holiday in the given year, if it
+    /// happens that year.
+    pub fn date(&self, year: i64) -> Option<LocalDate> {
+        if self.first_year.is_some_and(|first| year < first) || self.last_year.is_some_and(|last| year > last) {
+            return None;
+        }
+
+        match self.date {
+            HolidayDate::Fixed { month, day }             => LocalDate::ymd(year, month, day).ok(),
+            HolidayDate::NthWeekday { month, n, weekday } => Year(year).month(month).nth_weekday(n, weekday).ok(),
+            HolidayDate::Easter { offset }                => Some(Year(year).easter().add_days(offset)),
+            HolidayDate::OrthodoxEaster { offset }        => Some(Year(year).orthodox_easter().add_days(offset)),
+            HolidayDate::MarchEquinox                     => equinox(year, Month::March),
+            HolidayDate::SeptemberEquinox                 => equinox(year, Month::September),
+            HolidayDate::WeekdayOnOrAfter { month, day, weekday } => {
+                let date = LocalDate::ymd(year, month, day).ok()?;
+                Some(date.add_days(weekday.days_since(date.weekday()) as i64))
+            },
+        }
+    }
+
+    /// Returns the date this holiday is observed on in the given year, if
+    /// it happens that year.
+    ///
+    /// This looks at this rule on its own, so a holiday that gets moved
+    /// to the next free day is moved to the next weekday, or the day
+    /// after a Sunday, even if that’s another holiday. Use a `HolidaySet`
+    /// to move it past the other holidays in the set.
+    pub fn observed_date(&self, year: i64) -> Option<LocalDate> {
+        let weekend = weekend_days(&[ Weekday::Saturday, Weekday::Sunday ]);
+        self.date(year).map(|date| self.observe(date, &weekend, |_| false))
+    }
+
+    /// Returns the date a holiday on the given date is observed on, moving
+    /// it past any days for which `taken` returns true if the observance
+    /// looks for a free day, and past the given weekend if it looks for a
+    /// weekday.
+    fn observe<F: Fn(LocalDate) -> bool>(&self, date: LocalDate, weekend: &[bool; 7], taken: F) -> LocalDate {
+        let is_weekend = |d: LocalDate| weekend[d.weekday() as usize];
+
+        match (self.observance, date.weekday()) {
+            (Observance::NextMonday,          Weekday::Saturday) => date.add_days(2),
+            (Observance::NextMonday,          Weekday::Sunday)   => date.add_days(1),
+            (Observance::NearestWeekday,      Weekday::Saturday) => date.add_days(-1),
+            (Observance::NearestWeekday,      Weekday::Sunday)   => date.add_days(1),
+            (Observance::NextFreeWeekday,     _) if is_weekend(date) => next_day_where(date, |d| !is_weekend(d) && !taken(d)),
+            (Observance::SundayToNextFreeDay, Weekday::Sunday)   => next_day_where(date, |d| !taken(d)),
+            _                                                    => date,
+        }
+    }
+}
+
+
+/// The date of a holiday in a particular year, and the date it’s
+/// observed on, which is different if it’s been moved off a weekend.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Holiday {
+
+    /// The name of the holiday.
+    pub name: String,
+
+    /// The date the holiday actually falls on.
+    pub date: LocalDate,
+
+    /// The date the holiday is observed on.
+    pub observed: LocalDate,
+}
+
+/// A **holiday set** is a group of holiday rules that are observed
+/// together, such as the public holidays of a country.
+///
+/// Unlike a single rule, a set knows about all of its holidays at once,
+/// so a holiday that’s moved to the next free day doesn’t land on another
+/// holiday in the set.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::{LocalDate, Month, Year};
+/// use datetime::business::{HolidayRule, HolidaySet, Observance};
+///
+/// let mut set = HolidaySet::new();
+/// set.rules.push(HolidayRule::fixed("Christmas Day", Month::December, 25).observed(Observance::NextFreeWeekday));
+/// set.rules.push(HolidayRule::fixed("Boxing Day", Month::December, 26).observed(Observance::NextFreeWeekday));
+///
+/// // Christmas 2021 was a Saturday, and Boxing Day was a Sunday.
+/// let observed: Vec<_> = set.holidays(Year(2021)).into_iter().map(|h| h.observed).collect();
+/// assert_eq!(observed, vec![ LocalDate::ymd(2021, Month::December, 27).unwrap(),
+///                            LocalDate::ymd(2021, Month::December, 28).unwrap() ]);
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct HolidaySet {
+
+    /// The rules for the holidays in this set.
+    pub rules: Vec<HolidayRule>,
+
+    /// If present, any day that falls between two holidays, and isn’t a
+    /// holiday itself, becomes a holiday with this name. This is the
+    /// “Citizens’ Holiday” in Japan.
+    pub bridge: Option<String>,
+}
+
+impl HolidaySet {
+
+    /// Creates a new set with no holidays.
+    pub fn new() -> HolidaySet {
+        HolidaySet::default()
+    }
+
+    /// Adds the rules of another set to this one, also taking its bridge
+    /// holiday if this set doesn’t have one.
+    pub fn extend(&mut self, other: &HolidaySet) {
+        self.rules.extend(other.rules.iter().cloned());
+
+        if self.bridge.is_none() {
+            self.bridge = other.bridge.clone();
+        }
+    }
+
+    /// Returns the holidays in this set that happen in the given year, in
+    /// the order they’re observed in.
+    ///
+    /// Holidays are moved off weekends in the order their rules are in,
+    /// and a rule that gives the same name and date as one before it is
+    /// left out, so sets with some holidays in common can be combined.
+    /// An observed date can fall in the year before or after, such as New
+    /// Year’s Day being observed on the 31st of December.
+    pub fn holidays(&self, year: Year) -> Vec<Holiday> {
+        self.holidays_with_weekend(year, &weekend_days(&[ Weekday::Saturday, Weekday::Sunday ]))
+    }
+
+    /// Returns the holidays in this set that happen in the given year, in
+    /// the same way as `holidays`, but with the given days as the weekend.
+    fn holidays_with_weekend(&self, year: Year, weekend: &[bool; 7]) -> Vec<Holiday> {
+        let mut dates = Vec::<(&HolidayRule, LocalDate)>::new();
+        for rule in &self.rules {
+            if let Some(date) = rule.date(*year) {
+                if !dates.iter().any(|&(r, d)| d == date && r.name == rule.name) {
+                    dates.push((rule, date));
+                }
+            }
+        }
+
+        let actual: BTreeSet<LocalDate> = dates.iter().map(|&(_, date)| date).collect();
+        let mut taken = actual.clone();
+        let mut holidays = Vec::new();
+
+        for (rule, date) in dates {
+            let observed = rule.observe(date, weekend, |d| taken.contains(&d));
+            let _ = taken.insert(observed);
+            holidays.push(Holiday { name: rule.name.clone(), date, observed });
+        }
+
+        if let Some(ref name) = self.bridge {
+            for date in &actual {
+                let between = date.add_days(1);
+                if actual.contains(&between.add_days(1)) && !taken.contains(&between) {
+                    holidays.push(Holiday { name: name.clone(), date: between, observed: between });
+                }
+            }
+        }
+
+        holidays.sort_by_key(|h| (h.observed, h.date));
+        holidays
+    }
+}
+
+
+/// Returns which days of the week, indexed from Sunday, are in the given
+/// list of weekend days.
+fn weekend_days(weekend: &[Weekday]) -> [bool; 7] {
+    let mut days = [false; 7];
+    for weekday in weekend {
+        days[*weekday as usize] = true;
+    }
+    days
+}
+
+/// Returns the first day after the given date for which the predicate
+/// returns true.
+fn next_day_where<F: Fn(LocalDate) -> bool>(date: LocalDate, predicate: F) -> LocalDate {
+    let mut date = date.add_days(1);
+    while !predicate(date) {
+        date = date.add_days(1);
+    }
+    date
+}
+
+/// Returns the day of the March or September equinox in Japan Standard
+/// Time, using the approximation that’s used to work out the dates of
+/// the equinox holidays in advance, or `None` if the year is outside of
+/// the range it works for.
+fn equinox(year: i64, month: Month) -> Option<LocalDate> {
+    // The fractional day of the month, in millionths of a day, for the
+    // year 1980, and the year from which leap years are counted.
+    let (base, leap_base) = match (year, month) {
+        (1900 ..= 1979, Month::March) => (20_835_700, 1983),
+        (1900 ..= 1979, _)            => (23_258_800, 1983),
+        (1980 ..= 2099, Month::March) => (20_843_100, 1980),
+        (1980 ..= 2099, _)            => (23_248_800, 1980),
+        (2100 ..= 2150, Month::March) => (21_851_000, 1980),
+        (2100 ..= 2150, _)            => (24_248_800, 1980),
+        _                             => return None,
+    };
+
+    // The leap year term is truncated towards zero, as in the published
+    // formula, which only makes a difference before 1983.
+    let day = (base + 242_194 * (year - 1980)).div_euclid(1_000_000) - (year - leap_base) / 4;
+    LocalDate::ymd(year, month, day as i8).ok()
+}
@@ -0,0 +1,152 @@
+//! The Hebrew calendar.
+
+use std::fmt;
+
+use cal::calendar::{Calendar, check_fields};
+use cal::datetime::Error;
+
+
+/// The Julian Day Number of the 1st of Tishrei, year 1, which was the 7th
+/// of October, 3761 BCE, in the Julian calendar.
+const EPOCH_JDN: i64 = 347_998;
+
+/// The number of “parts” in a day, which is how the Hebrew calendar
+/// measures the time of the new moon. There are 1080 parts in an hour.
+const PARTS_IN_DAY: i64 = 25_920;
+
+/// The names of the months in a common year, starting with Tishrei.
+const MONTH_NAMES: [&str; 12] = [
+    "Tishrei", "Cheshvan", "Kislev", "Tevet", "Shevat", "Adar",
+    "Nisan", "Iyar", "Sivan", "Tammuz", "Av", "Elul",
+];
+
+/// The names of the months in a leap year, which has two months of Adar.
+const LEAP_MONTH_NAMES: [&str; 13] = [
+    "Tishrei", "Cheshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar II",
+    "Nisan", "Iyar", "Sivan", "Tammuz", "Av", "Elul",
+];
+
+
+/// A date in the **Hebrew calendar**, a lunisolar calendar whose years
+/// start in the autumn, at the month of Tishrei.
+///
+/// Common years have twelve months, and leap years have a thirteenth,
+/// which is added before Adar in seven years of every nineteen. Months
+/// are numbered from Tishrei, so Nisan is month 7 in a common year and
+/// month 8 in a leap year. The lengths of Cheshvan and Kislev vary so
+/// that the new year never falls on certain days of the week.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct HebrewDate {
+    year: i64,
+    month: i8,
+    day: i8,
+}
+
+impl Calendar for HebrewDate {
+    fn ymd(year: i64, month: i8, day: i8) -> Result<HebrewDate, Error> {
+        check_fields::<HebrewDate>(year, month, day)?;
+        Ok(HebrewDate { year, month, day })
+    }
+
+    fn from_julian_day_number(jdn: i64) -> HebrewDate {
+        // Start from an estimate using the average length of a year, then
+        // correct it using the actual new years.
+        let mut year = (jdn - EPOCH_JDN) * 98_496 / 35_975_351 + 1;
+        while new_year(year + 1) <= jdn {
+            year += 1;
+        }
+        while new_year(year) > jdn {
+            year -= 1;
+        }
+
+        let mut days = jdn - new_year(year);
+        let mut month = 1;
+        while days >= i64::from(HebrewDate::days_in_month(year, month)) {
+            days -= i64::from(HebrewDate::days_in_month(year, month));
+            month += 1;
+        }
+
+        HebrewDate { year, month, day: days as i8 + 1 }
+    }
+
+    fn julian_day_number(&self) -> i64 {
+        let months: i64 = (1 .. self.month).map(|m| i64::from(HebrewDate::days_in_month(self.year, m))).sum();
+        new_year(self.year) + months + i64::from(self.day) - 1
+    }
+
+    fn year(&self) -> i64 { self.year }
+    fn month(&self) -> i8 { self.month }
+    fn day(&self) -> i8 { self.day }
+
+    fn month_name(&self) -> &'static str {
+        if HebrewDate::is_leap_year(self.year) {
+            LEAP_MONTH_NAMES[self.month as usize - 1]
+        }
+        else {
+            MONTH_NAMES[self.month as usize - 1]
+        }
+    }
+
+    fn is_leap_year(year: i64) -> bool {
+        (7 * year + 1).rem_euclid(19) < 7
+    }
+
+    fn months_in_year(year: i64) -> i8 {
+        if HebrewDate::is_leap_year(year) { 13 } else { 12 }
+    }
+
+    fn days_in_month(year: i64, month: i8) -> i8 {
+        let leap = HebrewDate::is_leap_year(year);
+
+        // Skip over the extra month, so the rest can be matched as though
+        // this were a common year.
+        let month = match month {
+            6 if leap         => return 30,
+            7 ..= 13 if leap  => month - 1,
+            _                 => month,
+        };
+
+        match month {
+            2                    => if year_length(year) % 10 == 5 { 30 } else { 29 },
+            3                    => if year_length(year) % 10 == 3 { 29 } else { 30 },
+            1 | 5 | 7 | 9 | 11   => 30,
+            4 | 6 | 8 | 10 | 12  => 29,
+            _                    => 0,
+        }
+    }
+}
+
+/// Returns the number of days between the epoch and the day of the new
+/// moon (the molad) of Tishrei in the given year, moved a day later when
+/// the new moon falls on a Sunday, Wednesday, or Friday.
+fn elapsed_days(year: i64) -> i64 {
+    let months = (235 * year - 234).div_euclid(19);
+    let parts = 12_084 + 13_753 * months;
+    let days = 29 * months + parts.div_euclid(PARTS_IN_DAY);
+
+    if (3 * (days + 1)).rem_euclid(7) < 3 { days + 1 } else { days }
+}
+
+/// Returns the Julian Day Number of the 1st of Tishrei in the given year,
+/// which is sometimes postponed further to keep the lengths of it and the
+/// year before it within the allowed range.
+fn new_year(year: i64) -> i64 {
+    let (previous, this, next) = (elapsed_days(year - 1), elapsed_days(year), elapsed_days(year + 1));
+
+    let delay = if next - this == 356 { 2 }
+                else if this - previous == 382 { 1 }
+                else { 0 };
+
+    EPOCH_JDN + this + delay
+}
+
+/// Returns the number of days in the given year.
+fn year_length(year: i64) -> i64 {
+    new_year(year + 1) - new_year(year)
+}
+
+impl fmt::Debug for HebrewDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "HebrewDate({:04}-{:02}-{:02})", self.year, self.month, self.day)
+    }
+}
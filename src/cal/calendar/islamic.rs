@@ -0,0 +1,90 @@
+//! The tabular Islamic (Hijri) calendar.
+
+use std::fmt;
+
+use cal::calendar::{Calendar, check_fields};
+use cal::datetime::Error;
+
+
+/// The Julian Day Number of the 1st of Muharram, year 1, which was the
+/// 16th of July, 622, in the Julian calendar.
+const EPOCH_JDN: i64 = 1_948_440;
+
+/// The names of the months.
+const MONTH_NAMES: [&str; 12] = [
+    "Muharram", "Safar", "Rabi’ al-Awwal", "Rabi’ al-Thani",
+    "Jumada al-Awwal", "Jumada al-Thani", "Rajab", "Sha’ban",
+    "Ramadan", "Shawwal", "Dhu al-Qa’dah", "Dhu al-Hijjah",
+];
+
+
+/// A date in the **tabular Islamic calendar**, a purely lunar calendar of
+/// twelve months that alternate between 30 and 29 days.
+///
+/// The religious calendar starts each month when the new crescent moon is
+/// sighted, which this type can’t predict. Instead, it uses the civil
+/// arithmetic rule, where the last month gets an extra day in 11 years of
+/// every 30 (years 2, 5, 7, 10, 13, 16, 18, 21, 24, 26, and 29 of each
+/// cycle), and the epoch is Friday the 16th of July, 622 (Julian). This
+/// can differ from an observed date by a day or two.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct IslamicDate {
+    year: i64,
+    month: i8,
+    day: i8,
+}
+
+impl Calendar for IslamicDate {
+    fn ymd(year: i64, month: i8, day: i8) -> Result<IslamicDate, Error> {
+        check_fields::<IslamicDate>(year, month, day)?;
+        Ok(IslamicDate { year, month, day })
+    }
+
+    fn from_julian_day_number(jdn: i64) -> IslamicDate {
+        let year = (30 * (jdn - EPOCH_JDN) + 10_646).div_euclid(10_631);
+        let yearday = jdn - IslamicDate { year, month: 1, day: 1 }.julian_day_number();
+        let month = ((11 * yearday + 330) / 325) as i8;
+        let day = jdn - IslamicDate { year, month, day: 1 }.julian_day_number() + 1;
+
+        IslamicDate { year, month, day: day as i8 }
+    }
+
+    fn julian_day_number(&self) -> i64 {
+        let month = i64::from(self.month);
+
+        EPOCH_JDN - 1
+            + 354 * (self.year - 1) + (3 + 11 * self.year).div_euclid(30)
+            + 29 * (month - 1) + month / 2
+            + i64::from(self.day)
+    }
+
+    fn year(&self) -> i64 { self.year }
+    fn month(&self) -> i8 { self.month }
+    fn day(&self) -> i8 { self.day }
+
+    fn month_name(&self) -> &'static str {
+        MONTH_NAMES[self.month as usize - 1]
+    }
+
+    fn is_leap_year(year: i64) -> bool {
+        (14 + 11 * year).rem_euclid(30) < 11
+    }
+
+    fn months_in_year(_year: i64) -> i8 {
+        12
+    }
+
+    fn days_in_month(year: i64, month: i8) -> i8 {
+        match month {
+            12 if IslamicDate::is_leap_year(year) => 30,
+            1 ..= 12 => if month % 2 == 1 { 30 } else { 29 },
+            _        => 0,
+        }
+    }
+}
+
+impl fmt::Debug for IslamicDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "IslamicDate({:04}-{:02}-{:02})", self.year, self.month, self.day)
+    }
+}
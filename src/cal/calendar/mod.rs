@@ -0,0 +1,104 @@
+//! Calendar systems other than the Gregorian one.
+//!
+//! Each calendar has its own date type, which converts to and from
+//! `LocalDate` by way of the Julian Day Number, a count of days that every
+//! calendar can be measured against.
+//!
+//! ### Examples
+//!
+//! ```
+//! use datetime::{LocalDate, Month};
+//! use datetime::calendar::{Calendar, HebrewDate, IslamicDate, PersianDate};
+//!
+//! let date = LocalDate::ymd(2024, Month::March, 20).unwrap();
+//!
+//! let persian = PersianDate::from_local_date(date);
+//! assert_eq!((persian.year(), persian.month_name(), persian.day()), (1403, "Farvardin", 1));
+//!
+//! let hebrew = HebrewDate::from_local_date(date);
+//! assert_eq!((hebrew.year(), hebrew.month_name(), hebrew.day()), (5784, "Adar II", 10));
+//!
+//! let islamic = IslamicDate::from_local_date(date);
+//! assert_eq!((islamic.year(), islamic.month_name(), islamic.day()), (1445, "Ramadan", 10));
+//! ```
+
+use cal::DatePiece;
+use cal::datetime::{Error, LocalDate, Weekday};
+
+mod hebrew;
+mod islamic;
+mod persian;
+
+pub use self::hebrew::HebrewDate;
+pub use self::islamic::IslamicDate;
+pub use self::persian::PersianDate;
+
+
+/// The **calendar** trait is used for date types in calendar systems that
+/// have their own years, months, and days.
+///
+/// Months are numbered from 1 in the order they happen during the year,
+/// so in calendars where some years have an extra month, the same number
+/// can refer to different months in different years.
+pub trait Calendar: Sized {
+
+    /// Creates a new date from the given year, month, and day fields,
+    /// returning an error if the day doesn’t exist in this calendar.
+    fn ymd(year: i64, month: i8, day: i8) -> Result<Self, Error>;
+
+    /// Computes the date with the given Julian Day Number.
+    fn from_julian_day_number(jdn: i64) -> Self;
+
+    /// Returns the Julian Day Number of this date.
+    fn julian_day_number(&self) -> i64;
+
+    /// The year, counted from this calendar’s epoch.
+    fn year(&self) -> i64;
+
+    /// The month of the year, from 1.
+    fn month(&self) -> i8;
+
+    /// The day of the month, from 1.
+    fn day(&self) -> i8;
+
+    /// The name of this date’s month, transliterated into English.
+    fn month_name(&self) -> &'static str;
+
+    /// Returns whether the given year is a leap year in this calendar.
+    fn is_leap_year(year: i64) -> bool;
+
+    /// Returns the number of months in the given year.
+    fn months_in_year(year: i64) -> i8;
+
+    /// Returns the number of days in the given month of the given year,
+    /// or 0 if the year doesn’t have that month.
+    fn days_in_month(year: i64, month: i8) -> i8;
+
+    /// Returns the date in this calendar that falls on the same day as the
+    /// given Gregorian date.
+    fn from_local_date(date: LocalDate) -> Self {
+        Self::from_julian_day_number(date.julian_day_number())
+    }
+
+    /// Returns the Gregorian date that falls on the same day as this one.
+    fn to_local_date(&self) -> LocalDate {
+        LocalDate::from_julian_day_number(self.julian_day_number())
+    }
+
+    /// The day of the week.
+    fn weekday(&self) -> Weekday {
+        self.to_local_date().weekday()
+    }
+}
+
+
+/// Checks that the given month and day exist in the given year of a
+/// calendar.
+fn check_fields<C: Calendar>(year: i64, month: i8, day: i8) -> Result<(), Error> {
+    if (1 ..= C::months_in_year(year)).contains(&month) && (1 ..= C::days_in_month(year, month)).contains(&day) {
+        Ok(())
+    }
+    else {
+        Err(Error::OutOfRange)
+    }
+}
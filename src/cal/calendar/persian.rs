@@ -0,0 +1,102 @@
+//! The Persian (Solar Hijri) calendar.
+
+use std::fmt;
+
+use cal::calendar::{Calendar, check_fields};
+use cal::datetime::Error;
+
+
+/// The Julian Day Number of the 1st of Farvardin, year 1, which was the
+/// 19th of March, 622, in the Julian calendar.
+const EPOCH_JDN: i64 = 1_948_320;
+
+/// The names of the months, starting with the one that contains the
+/// March equinox.
+const MONTH_NAMES: [&str; 12] = [
+    "Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar",
+    "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand",
+];
+
+
+/// A date in the **Persian calendar**, also called the Solar Hijri
+/// calendar, which is the official calendar of Iran and Afghanistan.
+///
+/// Its years start at the March equinox, with six months of 31 days,
+/// five of 30, and a last month of 29 days, or 30 in a leap year.
+///
+/// The official calendar decides leap years by observing the equinox.
+/// This type uses the arithmetic rule of eight leap years in every 33,
+/// which gives the same results for every year from 1178 to 1633 (1799
+/// to 2254 CE).
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct PersianDate {
+    year: i64,
+    month: i8,
+    day: i8,
+}
+
+impl Calendar for PersianDate {
+    fn ymd(year: i64, month: i8, day: i8) -> Result<PersianDate, Error> {
+        check_fields::<PersianDate>(year, month, day)?;
+        Ok(PersianDate { year, month, day })
+    }
+
+    fn from_julian_day_number(jdn: i64) -> PersianDate {
+        let days = jdn - EPOCH_JDN;
+        let year = 1 + (33 * days + 3).div_euclid(12_053);
+        let yearday = days - days_before_year(year);
+
+        let month = if yearday < 216 { yearday / 31 } else { (yearday - 6) / 30 };
+        let day = yearday - days_before_month(month as i8 + 1) + 1;
+
+        PersianDate { year, month: month as i8 + 1, day: day as i8 }
+    }
+
+    fn julian_day_number(&self) -> i64 {
+        EPOCH_JDN + days_before_year(self.year) + days_before_month(self.month) + i64::from(self.day) - 1
+    }
+
+    fn year(&self) -> i64 { self.year }
+    fn month(&self) -> i8 { self.month }
+    fn day(&self) -> i8 { self.day }
+
+    fn month_name(&self) -> &'static str {
+        MONTH_NAMES[self.month as usize - 1]
+    }
+
+    fn is_leap_year(year: i64) -> bool {
+        (25 * year + 11).rem_euclid(33) < 8
+    }
+
+    fn months_in_year(_year: i64) -> i8 {
+        12
+    }
+
+    fn days_in_month(year: i64, month: i8) -> i8 {
+        match month {
+            1 ..= 6   => 31,
+            7 ..= 11  => 30,
+            12        => if PersianDate::is_leap_year(year) { 30 } else { 29 },
+            _         => 0,
+        }
+    }
+}
+
+/// Returns the number of days between the epoch and the start of the
+/// given year.
+fn days_before_year(year: i64) -> i64 {
+    365 * (year - 1) + (8 * year + 21).div_euclid(33)
+}
+
+/// Returns the number of days in a year before the start of the given
+/// month.
+fn days_before_month(month: i8) -> i64 {
+    let month = i64::from(month) - 1;
+    if month <= 6 { 31 * month } else { 30 * month + 6 }
+}
+
+impl fmt::Debug for PersianDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PersianDate({:04}-{:02}-{:02})", self.year, self.month, self.day)
+    }
+}
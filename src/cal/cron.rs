@@ -0,0 +1,482 @@
+//! Cron expressions, and working out when they next fire.
+//!
+//! A `Schedule` is parsed from a cron expression such as `0 9 * * MON-FRI`
+//! (nine o’clock every weekday). The standard five fields are minute,
+//! hour, day of the month, month, and day of the week; a sixth field can
+//! be added at the front for the second. Each field can be a `*`, a
+//! number, a range such as `1-5`, a step such as `*/15` or `0-30/10`, or a
+//! comma-separated list of these. Months and weekdays can also be given
+//! by their three-letter English names, and both 0 and 7 mean Sunday.
+//!
+//! The following extensions are supported:
+//!
+//! - `L` in the day-of-month field means the last day of the month, and
+//!   `LW` means the last weekday (Monday to Friday) of the month;
+//! - `15W` in the day-of-month field means the weekday nearest to the
+//!   15th, without leaving the month;
+//! - `5L` in the day-of-week field means the last Friday of the month;
+//! - `5#3` in the day-of-week field means the third Friday of the month;
+//! - `?` can be used instead of `*` in either day field.
+//!
+//! As in most cron implementations, when both day fields are restricted, a
+//! day matches if *either* of them matches. A day field that starts with
+//! `*`, such as `*/2`, doesn’t count as restricted for this, so `0 0 */2 *
+//! MON` only fires on Mondays that are odd days of the month, as it does
+//! in Vixie cron.
+//!
+//! The expressions `@yearly` (or `@annually`), `@monthly`, `@weekly`,
+//! `@daily` (or `@midnight`), and `@hourly` are also accepted.
+//!
+//! ### Examples
+//!
+//! ```
+//! use datetime::{LocalDate, LocalDateTime, LocalTime, Month};
+//! use datetime::cron::Schedule;
+//!
+//! let schedule: Schedule = "0 9 * * MON-FRI".parse().unwrap();
+//!
+//! let friday = LocalDate::ymd(2017, Month::June, 30).unwrap();
+//! let start = LocalDateTime::new(friday, LocalTime::hm(12, 0).unwrap());
+//!
+//! let monday = LocalDate::ymd(2017, Month::July, 3).unwrap();
+//! assert_eq!(schedule.after(start), Some(LocalDateTime::new(monday, LocalTime::hm(9, 0).unwrap())));
+//! ```
+
+use std::error::Error as ErrorTrait;
+use std::fmt;
+use std::str::FromStr;
+
+use cal::{DatePiece, TimePiece};
+use cal::datetime::{LocalDate, LocalTime, LocalDateTime, Month, Weekday, YearMonth};
+use cal::zone::{TimeZone, LocalTimes, ZonedDateTime};
+use instant::Instant;
+
+
+/// How many years ahead to look for the next firing time before giving
+/// up, so that expressions that can never fire, such as `0 0 30 2 *`,
+/// don’t search forever. This is a whole cycle of the Gregorian calendar.
+const SEARCH_YEARS: i64 = 400;
+
+const MONTH_NAMES: &[&str] = &[ "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC" ];
+const WEEKDAY_NAMES: &[&str] = &[ "SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT" ];
+
+
+/// A **schedule** is a parsed cron expression, which can work out the
+/// times that it fires at.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Schedule {
+    seconds: u64,
+    minutes: u64,
+    hours: u64,
+    months: u64,
+
+    days_of_month: u64,
+    last_day: bool,
+    last_weekday: bool,
+    nearest_weekdays: Vec<i8>,
+    day_of_month_restricted: bool,
+
+    days_of_week: u64,
+    last_of_weekdays: Vec<Weekday>,
+    nth_weekdays: Vec<(i8, Weekday)>,
+    day_of_week_restricted: bool,
+}
+
+/// What to do with firing times that don’t exist because the clocks go
+/// forward, such as 02:30 when the clocks jump from 02:00 to 03:00.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum GapPolicy {
+
+    /// Don’t fire at all.
+    Skip,
+
+    /// Fire once, at the moment the clocks go forward, no matter how many
+    /// firing times were skipped over.
+    RunOnce,
+}
+
+impl Schedule {
+
+    /// Returns the first time after the given datetime that this schedule
+    /// fires at, or `None` if it never fires again.
+    pub fn after(&self, datetime: LocalDateTime) -> Option<LocalDateTime> {
+        let start = LocalDateTime::at(datetime.to_instant().seconds() + 1);
+        let last_year = start.year() + SEARCH_YEARS;
+
+        let mut date = start.date();
+        let mut from = Some(start.time());
+
+        while date.year() <= last_year {
+            if !self.has_month(date.month()) {
+                date = date.year_month().add_months(1).day(1).unwrap();
+                from = None;
+                continue;
+            }
+
+            if self.matches_date(date) {
+                if let Some(time) = self.first_time(from) {
+                    return Some(LocalDateTime::new(date, time));
+                }
+            }
+
+            date = date.add_days(1);
+            from = None;
+        }
+
+        None
+    }
+
+    /// Returns an iterator over the times this schedule fires at after the
+    /// given datetime, which is in UTC or any other time zone that has no
+    /// daylight-saving transitions.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDateTime, TimePiece};
+    /// use datetime::cron::Schedule;
+    ///
+    /// let schedule: Schedule = "*/20 * * * *".parse().unwrap();
+    /// let start = LocalDateTime::at(0);
+    ///
+    /// let minutes: Vec<i8> = schedule.upcoming(start).take(4).map(|t| t.minute()).collect();
+    /// assert_eq!(minutes, vec![ 20, 40, 0, 20 ]);
+    /// ```
+    pub fn upcoming(&self, after: LocalDateTime) -> Upcoming<'_> {
+        Upcoming { schedule: self, last: after }
+    }
+
+    /// Returns an iterator over the times this schedule fires at after the
+    /// given instant, following the wall clock of the given time zone.
+    ///
+    /// When the clocks go back, times that happen twice only fire the
+    /// first time. When the clocks go forward, times that don’t exist are
+    /// handled according to the gap policy.
+    pub fn upcoming_in<'z>(&self, time_zone: &'z TimeZone, after: Instant, gaps: GapPolicy) -> ZonedUpcoming<'_, 'z> {
+        ZonedUpcoming { schedule: self, time_zone, last: after.seconds(), gaps }
+    }
+
+    fn has_month(&self, month: Month) -> bool {
+        self.months & (1 << (month.months_from_january() + 1)) != 0
+    }
+
+    /// Returns whether the day fields match the given date.
+    fn matches_date(&self, date: LocalDate) -> bool {
+        let ym = date.year_month();
+
+        let day_of_month = || {
+            self.days_of_month & (1 << date.day()) != 0
+            || (self.last_day && date.day() == ym.day_count())
+            || (self.last_weekday && last_weekday(ym) == date)
+            || self.nearest_weekdays.iter().any(|n| nearest_weekday(ym, *n) == Some(date))
+        };
+
+        let day_of_week = || {
+            self.days_of_week & (1 << date.weekday() as u8) != 0
+            || self.last_of_weekdays.iter().any(|w| ym.nth_weekday(-1, *w) == Ok(date))
+            || self.nth_weekdays.iter().any(|&(n, w)| ym.nth_weekday(n, w) == Ok(date))
+        };
+
+        // An unrestricted field can still have a step, such as `*/2`, so
+        // it has to match as well as the other field.
+        if self.day_of_month_restricted && self.day_of_week_restricted {
+            day_of_month() || day_of_week()
+        }
+        else {
+            day_of_month() && day_of_week()
+        }
+    }
+
+    /// Returns the first time of day on or after the given time that
+    /// matches the time fields, starting from midnight if no time is given.
+    fn first_time(&self, from: Option<LocalTime>) -> Option<LocalTime> {
+        let from = from.unwrap_or_else(LocalTime::midnight);
+
+        for hour in from.hour() .. 24 {
+            if self.hours & (1 << hour) == 0 {
+                continue;
+            }
+
+            let first_minute = if hour == from.hour() { from.minute() } else { 0 };
+            for minute in first_minute .. 60 {
+                if self.minutes & (1 << minute) == 0 {
+                    continue;
+                }
+
+                let first_second = if hour == from.hour() && minute == from.minute() { from.second() } else { 0 };
+                if let Some(second) = (first_second .. 60).find(|s| self.seconds & (1 << s) != 0) {
+                    return LocalTime::hms(hour, minute, second).ok();
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// Returns the last Monday-to-Friday day of a month.
+fn last_weekday(ym: YearMonth) -> LocalDate {
+    let last = ym.day(ym.day_count()).unwrap();
+    match last.weekday() {
+        Weekday::Saturday => last.add_days(-1),
+        Weekday::Sunday   => last.add_days(-2),
+        _                 => last,
+    }
+}
+
+/// Returns the Monday-to-Friday day nearest to the given day of a month,
+/// without leaving the month, or `None` if the month is too short.
+fn nearest_weekday(ym: YearMonth, day: i8) -> Option<LocalDate> {
+    let date = ym.day(day).ok()?;
+    let last = ym.day_count();
+
+    Some(match date.weekday() {
+        Weekday::Saturday if day == 1    => date.add_days(2),
+        Weekday::Saturday                => date.add_days(-1),
+        Weekday::Sunday   if day == last => date.add_days(-2),
+        Weekday::Sunday                  => date.add_days(1),
+        _                                => date,
+    })
+}
+
+
+/// An iterator over the times a `Schedule` fires at.
+#[derive(Debug, Clone)]
+pub struct Upcoming<'s> {
+    schedule: &'s Schedule,
+    last: LocalDateTime,
+}
+
+impl<'s> Iterator for Upcoming<'s> {
+    type Item = LocalDateTime;
+
+    fn next(&mut self) -> Option<LocalDateTime> {
+        let next = self.schedule.after(self.last)?;
+        self.last = next;
+        Some(next)
+    }
+}
+
+
+/// An iterator over the times a `Schedule` fires at in a time zone.
+#[derive(Debug)]
+pub struct ZonedUpcoming<'s, 'z> {
+    schedule: &'s Schedule,
+    time_zone: &'z TimeZone,
+    last: i64,
+    gaps: GapPolicy,
+}
+
+impl<'s, 'z> Iterator for ZonedUpcoming<'s, 'z> {
+    type Item = ZonedDateTime<'z>;
+
+    fn next(&mut self) -> Option<ZonedDateTime<'z>> {
+        let mut wall = self.time_zone.to_zoned(LocalDateTime::at(self.last));
+
+        loop {
+            wall = self.schedule.after(wall)?;
+
+            let zoned = match self.time_zone.convert_local(wall) {
+                LocalTimes::Precise(zoned)            => zoned,
+                LocalTimes::Ambiguous { earlier, .. } => earlier,
+                LocalTimes::Impossible => match self.gaps {
+                    GapPolicy::Skip    => continue,
+                    GapPolicy::RunOnce => self.time_zone.end_of_gap(wall),
+                },
+            };
+
+            // Anything at or before the last firing time has either fired
+            // already, or is the second run of a time that happens twice.
+            let seconds = zoned.to_instant().seconds();
+            if seconds > self.last {
+                self.last = seconds;
+                return Some(zoned);
+            }
+        }
+    }
+}
+
+
+impl FromStr for Schedule {
+    type Err = CronError;
+
+    fn from_str(input: &str) -> Result<Schedule, CronError> {
+        let input = input.trim();
+
+        let expanded = match input {
+            "@yearly" | "@annually"  => "0 0 1 1 *",
+            "@monthly"               => "0 0 1 * *",
+            "@weekly"                => "0 0 * * 0",
+            "@daily" | "@midnight"   => "0 0 * * *",
+            "@hourly"                => "0 * * * *",
+            _ if input.starts_with('@') => return Err(CronError::UnknownMacro(input.to_owned())),
+            _                        => input,
+        };
+
+        let fields: Vec<&str> = expanded.split_whitespace().collect();
+        let (second, rest) = match fields.len() {
+            5 => ("0", &fields[..]),
+            6 => (fields[0], &fields[1..]),
+            n => return Err(CronError::FieldCount(n)),
+        };
+
+        let mut schedule = Schedule {
+            seconds: parse_field(second,  "second", 0, 59, &[])?,
+            minutes: parse_field(rest[0], "minute", 0, 59, &[])?,
+            hours:   parse_field(rest[1], "hour",   0, 23, &[])?,
+            months:  parse_field(rest[3], "month",  1, 12, MONTH_NAMES)?,
+
+            days_of_month: 0,
+            last_day: false,
+            last_weekday: false,
+            nearest_weekdays: Vec::new(),
+            day_of_month_restricted: !is_wildcard(rest[2]),
+
+            days_of_week: 0,
+            last_of_weekdays: Vec::new(),
+            nth_weekdays: Vec::new(),
+            day_of_week_restricted: !is_wildcard(rest[4]),
+        };
+
+        schedule.parse_days_of_month(rest[2])?;
+        schedule.parse_days_of_week(rest[4])?;
+        Ok(schedule)
+    }
+}
+
+impl Schedule {
+    fn parse_days_of_month(&mut self, field: &str) -> Result<(), CronError> {
+        let invalid = || CronError::InvalidField { field: "day of month", value: field.to_owned() };
+
+        if field == "?" {
+            self.days_of_month = !0;
+            return Ok(());
+        }
+
+        for item in field.split(',') {
+            if item == "L" {
+                self.last_day = true;
+            }
+            else if item == "LW" {
+                self.last_weekday = true;
+            }
+            else if let Some(day) = item.strip_suffix('W') {
+                let day = day.parse().ok().filter(|d| *d >= 1 && *d <= 31).ok_or_else(invalid)?;
+                self.nearest_weekdays.push(day);
+            }
+            else {
+                self.days_of_month |= parse_field(item, "day of month", 1, 31, &[])?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn parse_days_of_week(&mut self, field: &str) -> Result<(), CronError> {
+        let invalid = || CronError::InvalidField { field: "day of week", value: field.to_owned() };
+        let weekday = |text: &str| parse_value(text, 0, 7, WEEKDAY_NAMES).map(|n| Weekday::from_zero((n % 7) as i8).unwrap()).ok_or_else(invalid);
+
+        if field == "?" {
+            self.days_of_week = !0;
+            return Ok(());
+        }
+
+        for item in field.split(',') {
+            if let Some(day) = item.strip_suffix('L') {
+                self.last_of_weekdays.push(weekday(day)?);
+            }
+            else if let Some(hash) = item.find('#') {
+                let n = item[hash + 1 ..].parse().ok().filter(|n| *n >= 1 && *n <= 5).ok_or_else(invalid)?;
+                self.nth_weekdays.push((n, weekday(&item[.. hash])?));
+            }
+            else {
+                let days = parse_field(item, "day of week", 0, 7, WEEKDAY_NAMES)?;
+
+                // Sunday can be either 0 or 7.
+                self.days_of_week |= (days | (days >> 7)) & 0x7F;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns whether a day field counts as unrestricted when deciding
+/// whether to match either day field, which is the case for any field
+/// starting with `*`, even if it has a step.
+fn is_wildcard(field: &str) -> bool {
+    field.starts_with('*') || field == "?"
+}
+
+/// Parses one field into a bit set, where bit n is set if the value n is
+/// included.
+fn parse_field(field: &str, name: &'static str, min: u32, max: u32, names: &[&str]) -> Result<u64, CronError> {
+    let invalid = || CronError::InvalidField { field: name, value: field.to_owned() };
+    let mut bits = 0;
+
+    for item in field.split(',') {
+        let (range, step) = match item.find('/') {
+            Some(slash) => (&item[.. slash], item[slash + 1 ..].parse().ok().filter(|s| *s > 0).ok_or_else(invalid)?),
+            None        => (item, 1),
+        };
+
+        let (first, last) = if range == "*" {
+            (min, max)
+        }
+        else if let Some(dash) = range.find('-') {
+            let first = parse_value(&range[.. dash], min, max, names).ok_or_else(invalid)?;
+            let last  = parse_value(&range[dash + 1 ..], min, max, names).ok_or_else(invalid)?;
+            if last < first { return Err(invalid()) }
+            (first, last)
+        }
+        else {
+            let first = parse_value(range, min, max, names).ok_or_else(invalid)?;
+
+            // A single value with a step, such as `5/15`, runs to the end.
+            (first, if item.contains('/') { max } else { first })
+        };
+
+        for value in (first ..= last).step_by(step) {
+            bits |= 1 << value;
+        }
+    }
+
+    Ok(bits)
+}
+
+/// Parses a single number or name, where the names start from `min`.
+fn parse_value(text: &str, min: u32, max: u32, names: &[&str]) -> Option<u32> {
+    let upper = text.to_uppercase();
+    if let Some(index) = names.iter().position(|n| *n == upper) {
+        return Some(index as u32 + min);
+    }
+
+    text.parse().ok().filter(|n| *n >= min && *n <= max)
+}
+
+
+/// An error that occurs when parsing a cron expression.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum CronError {
+
+    /// The expression doesn’t have five or six fields.
+    FieldCount(usize),
+
+    /// One of the fields isn’t valid.
+    InvalidField { field: &'static str, value: String },
+
+    /// The expression is a macro beginning with `@` that isn’t recognised.
+    UnknownMacro(String),
+}
+
+impl fmt::Display for CronError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CronError::FieldCount(count)                   => write!(f, "cron expression has {} fields instead of 5 or 6", count),
+            CronError::InvalidField { field, ref value }   => write!(f, "invalid {} field {:?}", field, value),
+            CronError::UnknownMacro(ref name)              => write!(f, "unknown cron macro {:?}", name),
+        }
+    }
+}
+
+impl ErrorTrait for CronError {}
@@ -10,6 +10,8 @@ use std::slice::Iter as SliceIter;
 
 use cal::{DatePiece, TimePiece};
 use cal::fmt::ISO;
+use cal::julian::JulianDate;
+use cal::range::DateRange;
 use duration::Duration;
 use instant::Instant;
 use system::sys_time;
@@ -41,6 +43,25 @@ impl Year {
         self.leap_year_calculations().1
     }
 
+    /// Returns the number of weeks in this year’s ISO 8601 week-numbering
+    /// year, which is 53 if the year starts or ends on a Thursday, and 52
+    /// otherwise.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::Year;
+    ///
+    /// assert_eq!(Year(2015).iso_week_count(), 53);
+    /// assert_eq!(Year(2016).iso_week_count(), 52);
+    /// ```
+    pub fn iso_week_count(&self) -> i64 {
+        // The weekday of the 31st of December, with Sunday as 0.
+        let dec_31 = |y: i64| (y + y.div_euclid(4) - y.div_euclid(100) + y.div_euclid(400)).rem_euclid(7);
+
+        if dec_31(self.0) == 4 || dec_31(self.0 - 1) == 3 { 53 } else { 52 }
+    }
+
     /// Returns an iterator over a continuous span of months in this year,
     /// returning year-month pairs.
     ///
@@ -94,6 +115,55 @@ impl Year {
         }
     }
 
+    /// Returns the date of Easter Sunday in this year, as worked out by
+    /// the Gregorian computus used by the Western churches.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, Month, Year};
+    ///
+    /// assert_eq!(Year(2024).easter(), LocalDate::ymd(2024, Month::March, 31).unwrap());
+    /// assert_eq!(Year(2025).easter(), LocalDate::ymd(2025, Month::April, 20).unwrap());
+    /// ```
+    pub fn easter(&self) -> LocalDate {
+        // This is the “anonymous Gregorian algorithm” given in Meeus’s
+        // Astronomical Algorithms, with floored division so it works for
+        // years before 0 as well.
+        let year = self.0;
+        let golden = year.rem_euclid(19);
+        let (century, c) = (year.div_euclid(100), year.rem_euclid(100));
+        let f = (century + 8).div_euclid(25);
+        let g = (century - f + 1).div_euclid(3);
+        let epact = (19 * golden + century - century.div_euclid(4) - g + 15).rem_euclid(30);
+        let weekday = (32 + 2 * century.rem_euclid(4) + 2 * (c / 4) - epact - c % 4).rem_euclid(7);
+        let m = (golden + 11 * epact + 22 * weekday) / 451;
+
+        // Easter falls this many days after the 22nd of March.
+        LocalDate::ymd(year, March, 22).unwrap().add_days(epact + weekday - 7 * m)
+    }
+
+    /// Returns the date of Easter Sunday in this year, as worked out by
+    /// the Julian computus used by the Eastern Orthodox churches. The
+    /// date is calculated in the Julian calendar, then returned as the
+    /// Gregorian date that falls on the same day.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, Month, Year};
+    ///
+    /// assert_eq!(Year(2024).orthodox_easter(), LocalDate::ymd(2024, Month::May, 5).unwrap());
+    /// assert_eq!(Year(2025).orthodox_easter(), LocalDate::ymd(2025, Month::April, 20).unwrap());
+    /// ```
+    pub fn orthodox_easter(&self) -> LocalDate {
+        let year = self.0;
+        let epact = (19 * year.rem_euclid(19) + 15) % 30;
+        let weekday = (2 * year.rem_euclid(4) + 4 * year.rem_euclid(7) - epact + 34) % 7;
+
+        JulianDate::ymd(year, March, 22).unwrap().to_local_date().add_days(epact + weekday)
+    }
+
     /// Performs two related calculations for leap years, returning the
     /// results as a two-part tuple:
     ///
@@ -257,6 +327,73 @@ impl YearMonth {
     pub fn day(&self, day: i8) -> Result<LocalDate, Error> {
         LocalDate::ymd(self.year.0, self.month, day)
     }
+
+    /// Returns the month that’s the given number of months after this one,
+    /// or before it if the number is negative.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::Year;
+    /// use datetime::Month::{November, February};
+    ///
+    /// let ym = Year(2016).month(November);
+    /// assert_eq!(ym.add_months(3), Year(2017).month(February));
+    /// assert_eq!(ym.add_months(-21), Year(2015).month(February));
+    /// ```
+    pub fn add_months(&self, months: i64) -> YearMonth {
+        let total = self.year.0 * 12 + self.month.months_from_january() as i64 + months;
+        let month = Month::from_zero(total.rem_euclid(12) as i8).unwrap();
+        Year(total.div_euclid(12)).month(month)
+    }
+
+    /// Returns the nth occurrence of the given weekday in this month, such
+    /// as the third Thursday. Negative numbers count back from the end of
+    /// the month, so -1 is the last one. Returns an error if the month
+    /// doesn’t have that many of the weekday, or if the number is zero.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{Year, LocalDate, Month, Weekday};
+    ///
+    /// let ym = Year(2017).month(Month::November);
+    ///
+    /// assert_eq!(ym.nth_weekday(4, Weekday::Thursday), LocalDate::ymd(2017, Month::November, 23));
+    /// assert_eq!(ym.nth_weekday(-1, Weekday::Friday), LocalDate::ymd(2017, Month::November, 24));
+    /// assert!(ym.nth_weekday(5, Weekday::Friday).is_err());
+    /// ```
+    pub fn nth_weekday(&self, n: i8, weekday: Weekday) -> Result<LocalDate, Error> {
+        let count = self.day_count() as i64;
+        let n = n as i64;
+
+        let day = if n > 0 {
+            let first = self.day(1)?;
+            1 + weekday.days_since(first.weekday()) as i64 + 7 * (n - 1)
+        }
+        else if n < 0 {
+            let last = self.day(self.day_count())?;
+            count - last.weekday().days_since(weekday) as i64 + 7 * (n + 1)
+        }
+        else {
+            return Err(Error::OutOfRange);
+        };
+
+        if day >= 1 && day <= count { self.day(day as i8) } else { Err(Error::OutOfRange) }
+    }
+
+    /// Returns the number of months from this month until the given one,
+    /// which is negative if the other month is earlier.
+    pub fn months_until(&self, other: YearMonth) -> i64 {
+        let months = |ym: &YearMonth| ym.year.0 * 12 + ym.month.months_from_january() as i64;
+        months(&other) - months(self)
+    }
+
+    /// Returns the same month the given number of years later, or earlier
+    /// if the number is negative.
+    pub fn add_years(&self, years: i64) -> YearMonth {
+        Year(self.year.0 + years).month(self.month)
+    }
 }
 
 
@@ -292,6 +429,144 @@ impl DaySpan for Range<i8> {
 }
 
 
+/// A week in the ISO 8601 week-numbering calendar, where weeks start on
+/// Monday and the first week of a year is the one with its first Thursday.
+///
+/// The year is the week-numbering year, which can differ from the calendar
+/// year of the days at the very start or end of the week.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct YearWeek {
+    pub year: i64,
+    pub week: i64,
+}
+
+impl YearWeek {
+
+    /// Creates a new year-week pair, returning an error if the year
+    /// doesn’t have that many weeks.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::YearWeek;
+    ///
+    /// assert!(YearWeek::new(2015, 53).is_ok());
+    /// assert!(YearWeek::new(2016, 53).is_err());
+    /// ```
+    pub fn new(year: i64, week: i64) -> Result<YearWeek, Error> {
+        if week >= 1 && week <= Year(year).iso_week_count() {
+            Ok(YearWeek { year, week })
+        }
+        else {
+            Err(Error::OutOfRange)
+        }
+    }
+
+    /// Returns a `LocalDate` based on the day of this week.
+    ///
+    /// This is just a short-cut for the `LocalDate::ywd` constructor.
+    pub fn day(&self, weekday: Weekday) -> Result<LocalDate, Error> {
+        LocalDate::ywd(self.year, self.week, weekday)
+    }
+
+    /// Returns an iterator over the seven days in this week, from Monday
+    /// to Sunday.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{YearWeek, LocalDate, Month};
+    ///
+    /// let week = YearWeek::new(2020, 53).unwrap();
+    /// let days: Vec<_> = week.days().collect();
+    ///
+    /// assert_eq!(days.len(), 7);
+    /// assert_eq!(days[0], LocalDate::ymd(2020, Month::December, 28).unwrap());
+    /// assert_eq!(days[6], LocalDate::ymd(2021, Month::January, 3).unwrap());
+    /// ```
+    pub fn days(&self) -> DateRange {
+        let monday = self.day(Monday).unwrap();
+        DateRange::new(monday, monday.add_weeks(1))
+    }
+}
+
+
+/// A quarter of a calendar year: January to March is the first quarter,
+/// and October to December is the fourth.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct YearQuarter {
+    pub year: i64,
+    pub quarter: i8,
+}
+
+impl YearQuarter {
+
+    /// Creates a new year-quarter pair, returning an error if the quarter
+    /// isn’t between 1 and 4.
+    pub fn new(year: i64, quarter: i8) -> Result<YearQuarter, Error> {
+        if (1 ..= 4).contains(&quarter) {
+            Ok(YearQuarter { year, quarter })
+        }
+        else {
+            Err(Error::OutOfRange)
+        }
+    }
+
+    /// Returns the first month of this quarter.
+    pub fn first_month(&self) -> YearMonth {
+        Year(self.year).month(MONTHS[(self.quarter as usize - 1) * 3])
+    }
+
+    /// Returns an iterator over the three months in this quarter.
+    pub fn months(&self) -> YearMonths {
+        let first = (self.quarter as usize - 1) * 3;
+        YearMonths {
+            year: Year(self.year),
+            iter: MONTHS[first .. first + 3].iter(),
+        }
+    }
+
+    /// Returns the number of days in this quarter, which is between 90
+    /// and 92.
+    pub fn day_count(&self) -> i64 {
+        self.months().map(|ym| ym.day_count() as i64).sum()
+    }
+
+    /// Returns an iterator over every day in this quarter.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{YearQuarter, LocalDate, Month};
+    ///
+    /// let quarter = YearQuarter::new(2016, 1).unwrap();
+    /// let days: Vec<_> = quarter.days().collect();
+    ///
+    /// assert_eq!(days.len(), 91);
+    /// assert_eq!(days[0], LocalDate::ymd(2016, Month::January, 1).unwrap());
+    /// assert_eq!(days[90], LocalDate::ymd(2016, Month::March, 31).unwrap());
+    /// ```
+    pub fn days(&self) -> DateRange {
+        let first = self.first_month().day(1).unwrap();
+        DateRange::new(first, self.add_quarters(1).first_month().day(1).unwrap())
+    }
+
+    /// Returns the quarter that’s the given number of quarters after this
+    /// one, or before it if the number is negative.
+    pub fn add_quarters(&self, quarters: i64) -> YearQuarter {
+        let total = self.year * 4 + (self.quarter as i64 - 1) + quarters;
+        YearQuarter { year: total.div_euclid(4), quarter: total.rem_euclid(4) as i8 + 1 }
+    }
+
+    /// Returns the number of quarters from this quarter until the given
+    /// one, which is negative if the other quarter is earlier.
+    pub fn quarters_until(&self, other: YearQuarter) -> i64 {
+        let quarters = |yq: &YearQuarter| yq.year * 4 + yq.quarter as i64;
+        quarters(&other) - quarters(self)
+    }
+}
+
+
 /// An iterator over a continuous span of days in a month.
 ///
 /// Use the `days` method on `YearMonth` to create instances of this iterator.
@@ -359,6 +634,19 @@ const EPOCH_DIFFERENCE: i64 = (30 * 365      // 30 years between 2000 and 1970..
                                + 7           // plus seven days for leap years...
                                + 31 + 29);   // plus all the days in January and February in 2000.
 
+/// The Julian Day Number of **1st January, 1970**: the number of days
+/// between it and the start of the Julian Period, which was noon on the
+/// 1st of January, 4713 BCE in the Julian calendar (the 24th of November,
+/// 4714 BCE in the proleptic Gregorian one).
+const UNIX_EPOCH_JDN: i64 = 2_440_588;
+
+/// The Modified Julian Day of **1st January, 1970**. Modified Julian Days
+/// start at midnight, counting from the 17th of November, 1858.
+const UNIX_EPOCH_MJD: i64 = 40_587;
+
+/// Number of milliseconds in a day, for working with fractional days.
+const MILLISECONDS_IN_DAY: f64 = 86_400_000.0;
+
 
 /// This rather strange triangle is an array of the number of days elapsed
 /// at the end of each month, starting at the beginning of March (the first
@@ -566,13 +854,17 @@ impl LocalDate {
         // Calculate the numbers of 100-year cycles, 4-year cycles, and
         // leftover years, continually reducing the number of days left to
         // think about.
-        let num_100y_cycles = remainder / DAYS_IN_100Y;
+        //
+        // The leap day at the very end of a cycle would otherwise get
+        // counted as the first day of an extra cycle, so the number of
+        // 100-year cycles and leftover years are capped.
+        let num_100y_cycles = (remainder / DAYS_IN_100Y).min(3);
         remainder -= num_100y_cycles * DAYS_IN_100Y;  // remainder is now days left in this 100-year cycle
 
         let num_4y_cycles = remainder / DAYS_IN_4Y;
         remainder -= num_4y_cycles * DAYS_IN_4Y;  // remainder is now days left in this 4-year cycle
 
-        let mut years = remainder / 365;
+        let mut years = (remainder / 365).min(3);
         remainder -= years * 365;  // remainder is now days left in this year
 
         // Leap year calculation goes thusly:
@@ -661,6 +953,380 @@ impl LocalDate {
 
     // I’m not 100% convinced on using `unsafe` for something that doesn’t
     // technically *need* to be unsafe, but I’ll stick with it for now.
+
+    /// Returns the number of days between **1st January, 1970** and this
+    /// date, which is negative for dates before then.
+    pub(crate) fn days_since_unix_epoch(&self) -> i64 {
+        // LocalDates are always valid, so this can never fail.
+        self.ymd.to_days_since_epoch().unwrap()
+    }
+
+    /// Computes the date that falls the given number of days after
+    /// **1st January, 1970**.
+    pub(crate) fn from_days_since_unix_epoch(days: i64) -> LocalDate {
+        LocalDate::from_days_since_epoch(days - EPOCH_DIFFERENCE)
+    }
+}
+
+/// What to do when adding months or years to a date lands on a day that
+/// doesn’t exist in the resulting month, such as adding one month to the
+/// 31st of January, or one year to the 29th of February.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum MonthOverflow {
+
+    /// Use the last day of the month instead, so the 31st of January plus
+    /// one month becomes the 28th (or 29th) of February.
+    Clamp,
+
+    /// Carry the extra days over into the next month, so the 31st of
+    /// January plus one month becomes the 3rd (or 2nd) of March.
+    RollOver,
+
+    /// Return an error.
+    Reject,
+}
+
+/// A unit of time to truncate or round a value to, with a count so that
+/// values can be truncated to multiples of the unit, such as fifteen
+/// minutes.
+///
+/// Multiples of units shorter than a day are counted from midnight, and
+/// multiples of months and years are counted from the start of the year
+/// and from year zero, so `Minutes(15)` truncates to the quarter hour,
+/// `Months(3)` to the quarter, and `Years(10)` to the decade. Multiples
+/// of days and weeks are counted from the Unix epoch, with weeks starting
+/// on Monday. The count should divide evenly into the next larger unit,
+/// or the last multiple of each hour, day, or year will be shorter than
+/// the rest.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum TimeUnit {
+    Milliseconds(i64),
+    Seconds(i64),
+    Minutes(i64),
+    Hours(i64),
+    Days(i64),
+    Weeks(i64),
+    Months(i64),
+    Years(i64),
+}
+
+impl TimeUnit {
+
+    /// Returns the length of this unit in milliseconds, if it’s shorter
+    /// than a day.
+    fn milliseconds(self) -> Option<i64> {
+        match self {
+            TimeUnit::Milliseconds(n) => Some(n),
+            TimeUnit::Seconds(n)      => Some(n * 1000),
+            TimeUnit::Minutes(n)      => Some(n * 60_000),
+            TimeUnit::Hours(n)        => Some(n * 3_600_000),
+            _                         => None,
+        }
+    }
+
+    /// Returns whether this unit is a day or longer.
+    pub(crate) fn is_date_based(self) -> bool {
+        self.milliseconds().is_none()
+    }
+
+    fn count(self) -> i64 {
+        match self {
+            TimeUnit::Milliseconds(n) | TimeUnit::Seconds(n) | TimeUnit::Minutes(n) | TimeUnit::Hours(n)
+            | TimeUnit::Days(n) | TimeUnit::Weeks(n) | TimeUnit::Months(n) | TimeUnit::Years(n) => n,
+        }
+    }
+}
+
+impl LocalDate {
+
+    /// Returns the date that’s the given number of days after this one, or
+    /// before it if the number is negative.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, Month};
+    ///
+    /// let date = LocalDate::ymd(2016, Month::December, 30).unwrap();
+    /// assert_eq!(date.add_days(3), LocalDate::ymd(2017, Month::January, 2).unwrap());
+    /// ```
+    pub fn add_days(&self, days: i64) -> LocalDate {
+        LocalDate::from_days_since_unix_epoch(self.days_since_unix_epoch() + days)
+    }
+
+    /// Returns the date that’s the given number of weeks after this one,
+    /// or before it if the number is negative.
+    pub fn add_weeks(&self, weeks: i64) -> LocalDate {
+        self.add_days(weeks * 7)
+    }
+
+    /// Returns the date that’s the given number of months after this one,
+    /// or before it if the number is negative, keeping the same day of the
+    /// month if possible, and using the given policy if that day doesn’t
+    /// exist in the resulting month.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, Month, MonthOverflow};
+    ///
+    /// let date = LocalDate::ymd(2017, Month::January, 31).unwrap();
+    ///
+    /// assert_eq!(date.add_months(1, MonthOverflow::Clamp),
+    ///            LocalDate::ymd(2017, Month::February, 28));
+    /// assert_eq!(date.add_months(1, MonthOverflow::RollOver),
+    ///            LocalDate::ymd(2017, Month::March, 3));
+    /// assert!(date.add_months(1, MonthOverflow::Reject).is_err());
+    /// ```
+    pub fn add_months(&self, months: i64, overflow: MonthOverflow) -> Result<LocalDate, Error> {
+        let ym = self.year_month().add_months(months);
+
+        let last_day = ym.day_count();
+        if self.day() <= last_day {
+            return ym.day(self.day());
+        }
+
+        match overflow {
+            MonthOverflow::Clamp     => ym.day(last_day),
+            MonthOverflow::RollOver  => Ok(ym.day(last_day)?.add_days((self.day() - last_day) as i64)),
+            MonthOverflow::Reject    => Err(Error::OutOfRange),
+        }
+    }
+
+    /// Returns the date that’s the given number of years after this one,
+    /// or before it if the number is negative, using the given policy if
+    /// this date is the 29th of February and the resulting year isn’t a
+    /// leap year.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, Month, MonthOverflow};
+    ///
+    /// let date = LocalDate::ymd(2016, Month::February, 29).unwrap();
+    ///
+    /// assert_eq!(date.add_years(1, MonthOverflow::Clamp),
+    ///            LocalDate::ymd(2017, Month::February, 28));
+    /// assert_eq!(date.add_years(1, MonthOverflow::RollOver),
+    ///            LocalDate::ymd(2017, Month::March, 1));
+    /// assert_eq!(date.add_years(4, MonthOverflow::Reject),
+    ///            LocalDate::ymd(2020, Month::February, 29));
+    /// ```
+    pub fn add_years(&self, years: i64, overflow: MonthOverflow) -> Result<LocalDate, Error> {
+        self.add_months(years * 12, overflow)
+    }
+
+    /// Returns the number of days from this date until the given one,
+    /// which is negative if the other date is earlier.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, Month};
+    ///
+    /// let today = LocalDate::ymd(2017, Month::December, 1).unwrap();
+    /// let christmas = LocalDate::ymd(2017, Month::December, 25).unwrap();
+    ///
+    /// assert_eq!(today.days_until(christmas), 24);
+    /// assert_eq!(christmas.days_until(today), -24);
+    /// ```
+    pub fn days_until(&self, other: LocalDate) -> i64 {
+        other.days_since_unix_epoch() - self.days_since_unix_epoch()
+    }
+
+    /// Returns the number of whole weeks from this date until the given
+    /// one, which is negative if the other date is earlier.
+    pub fn weeks_until(&self, other: LocalDate) -> i64 {
+        self.days_until(other) / 7
+    }
+
+    /// Returns the number of whole months from this date until the given
+    /// one, which is negative if the other date is earlier.
+    ///
+    /// A month counts as whole once adding it to this date doesn’t go past
+    /// the other date, with the day of the month clamped to the end of
+    /// shorter months. This means there’s one whole month between the
+    /// 31st of January and the 28th of February in a common year.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, Month};
+    ///
+    /// let start = LocalDate::ymd(2017, Month::January, 31).unwrap();
+    ///
+    /// assert_eq!(start.months_until(LocalDate::ymd(2017, Month::February, 27).unwrap()), 0);
+    /// assert_eq!(start.months_until(LocalDate::ymd(2017, Month::February, 28).unwrap()), 1);
+    /// assert_eq!(start.months_until(LocalDate::ymd(2016, Month::December, 31).unwrap()), -1);
+    /// ```
+    pub fn months_until(&self, other: LocalDate) -> i64 {
+        let mut months = self.year_month().months_until(other.year_month());
+
+        // Clamping can never fail, so the unwraps are safe.
+        let shifted = self.add_months(months, MonthOverflow::Clamp).unwrap();
+        if months > 0 && shifted > other {
+            months -= 1;
+        }
+        else if months < 0 && shifted < other {
+            months += 1;
+        }
+
+        months
+    }
+
+    /// Returns the number of whole years from this date until the given
+    /// one, which is negative if the other date is earlier. This is the
+    /// age of someone born on this date on the other date.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, Month};
+    ///
+    /// let birthday = LocalDate::ymd(1990, Month::June, 15).unwrap();
+    ///
+    /// assert_eq!(birthday.years_until(LocalDate::ymd(2017, Month::June, 14).unwrap()), 26);
+    /// assert_eq!(birthday.years_until(LocalDate::ymd(2017, Month::June, 15).unwrap()), 27);
+    /// ```
+    pub fn years_until(&self, other: LocalDate) -> i64 {
+        self.months_until(other) / 12
+    }
+
+    /// Returns the nth occurrence of the given weekday in the same month
+    /// as this date, with negative numbers counting back from the end of
+    /// the month. See `YearMonth::nth_weekday`.
+    pub fn nth_weekday(&self, n: i8, weekday: Weekday) -> Result<LocalDate, Error> {
+        self.year_month().nth_weekday(n, weekday)
+    }
+
+    /// Returns the first date after this one that falls on the given
+    /// weekday. If this date is already on that weekday, the result is a
+    /// week later.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, Month, Weekday};
+    ///
+    /// let friday = LocalDate::ymd(2017, Month::June, 30).unwrap();
+    ///
+    /// assert_eq!(friday.next_weekday(Weekday::Monday), LocalDate::ymd(2017, Month::July, 3).unwrap());
+    /// assert_eq!(friday.next_weekday(Weekday::Friday), LocalDate::ymd(2017, Month::July, 7).unwrap());
+    /// assert_eq!(friday.weekday_on_or_after(Weekday::Friday), friday);
+    /// ```
+    pub fn next_weekday(&self, weekday: Weekday) -> LocalDate {
+        self.add_days(1).weekday_on_or_after(weekday)
+    }
+
+    /// Returns the last date before this one that falls on the given
+    /// weekday. If this date is already on that weekday, the result is a
+    /// week earlier.
+    pub fn previous_weekday(&self, weekday: Weekday) -> LocalDate {
+        self.add_days(-1).weekday_on_or_before(weekday)
+    }
+
+    /// Returns the first date on or after this one that falls on the given
+    /// weekday.
+    pub fn weekday_on_or_after(&self, weekday: Weekday) -> LocalDate {
+        self.add_days(self.weekday.days_until(weekday) as i64)
+    }
+
+    /// Returns the last date on or before this one that falls on the given
+    /// weekday.
+    pub fn weekday_on_or_before(&self, weekday: Weekday) -> LocalDate {
+        self.add_days(-(self.weekday.days_since(weekday) as i64))
+    }
+
+    /// Returns the year and month that this date is in.
+    pub fn year_month(&self) -> YearMonth {
+        Year(self.ymd.year).month(self.ymd.month)
+    }
+
+    /// Returns the first day of the week this date is in, where weeks
+    /// start on the given day.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, Month, Weekday};
+    ///
+    /// let wednesday = LocalDate::ymd(2017, Month::March, 1).unwrap();
+    ///
+    /// assert_eq!(wednesday.start_of_week(Weekday::Monday), LocalDate::ymd(2017, Month::February, 27).unwrap());
+    /// assert_eq!(wednesday.start_of_week(Weekday::Sunday), LocalDate::ymd(2017, Month::February, 26).unwrap());
+    /// ```
+    pub fn start_of_week(&self, first_weekday: Weekday) -> LocalDate {
+        self.weekday_on_or_before(first_weekday)
+    }
+
+    /// Returns the last day of the week this date is in, where weeks
+    /// start on the given day.
+    pub fn end_of_week(&self, first_weekday: Weekday) -> LocalDate {
+        self.start_of_week(first_weekday).add_days(6)
+    }
+
+    /// Returns the first day of the month this date is in.
+    pub fn start_of_month(&self) -> LocalDate {
+        self.year_month().day(1).unwrap()
+    }
+
+    /// Returns the last day of the month this date is in.
+    pub fn end_of_month(&self) -> LocalDate {
+        let ym = self.year_month();
+        ym.day(ym.day_count()).unwrap()
+    }
+
+    /// Returns the first day of the quarter this date is in.
+    pub fn start_of_quarter(&self) -> LocalDate {
+        self.year_quarter().first_month().day(1).unwrap()
+    }
+
+    /// Returns the last day of the quarter this date is in.
+    pub fn end_of_quarter(&self) -> LocalDate {
+        self.year_quarter().add_quarters(1).first_month().day(1).unwrap().add_days(-1)
+    }
+
+    /// Returns the 1st of January of the year this date is in.
+    pub fn start_of_year(&self) -> LocalDate {
+        LocalDate::ymd(self.ymd.year, January, 1).unwrap()
+    }
+
+    /// Returns the 31st of December of the year this date is in.
+    pub fn end_of_year(&self) -> LocalDate {
+        LocalDate::ymd(self.ymd.year, December, 31).unwrap()
+    }
+
+    /// Returns the Julian Day Number of this date, which is the Julian
+    /// Date at noon on it.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, Month};
+    ///
+    /// let date = LocalDate::ymd(2000, Month::January, 1).unwrap();
+    /// assert_eq!(date.julian_day_number(), 2_451_545);
+    /// assert_eq!(LocalDate::from_julian_day_number(2_451_545), date);
+    /// ```
+    pub fn julian_day_number(&self) -> i64 {
+        self.days_since_unix_epoch() + UNIX_EPOCH_JDN
+    }
+
+    /// Computes the date with the given Julian Day Number.
+    pub fn from_julian_day_number(jdn: i64) -> LocalDate {
+        LocalDate::from_days_since_unix_epoch(jdn - UNIX_EPOCH_JDN)
+    }
+
+    /// Returns the Modified Julian Day of this date, which is the number
+    /// of days since the 17th of November, 1858.
+    pub fn modified_julian_day(&self) -> i64 {
+        self.days_since_unix_epoch() + UNIX_EPOCH_MJD
+    }
+
+    /// Computes the date with the given Modified Julian Day.
+    pub fn from_modified_julian_day(mjd: i64) -> LocalDate {
+        LocalDate::from_days_since_unix_epoch(mjd - UNIX_EPOCH_MJD)
+    }
 }
 
 impl DatePiece for LocalDate {
@@ -772,6 +1438,43 @@ impl LocalTime {
             + self.minute as i64 * 60
             + self.second as i64
     }
+
+    /// Returns how far through the day this time is, from 0 at midnight
+    /// up to but not including 1.
+    fn day_fraction(&self) -> f64 {
+        (self.to_seconds() * 1000 + i64::from(self.millisecond)) as f64 / MILLISECONDS_IN_DAY
+    }
+
+    /// Returns this time truncated to a multiple of the given unit. Units
+    /// of a day or longer give midnight, as a time on its own has no date.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if the unit’s count isn’t positive.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalTime, TimeUnit};
+    ///
+    /// let time = LocalTime::hms(14, 38, 20).unwrap();
+    /// assert_eq!(time.truncate_to(TimeUnit::Minutes(15)), LocalTime::hm(14, 30).unwrap());
+    /// assert_eq!(time.round_to(TimeUnit::Minutes(15)), LocalTime::hm(14, 45).unwrap());
+    /// ```
+    pub fn truncate_to(&self, unit: TimeUnit) -> LocalTime {
+        LocalDateTime::new(LocalDate::from_days_since_unix_epoch(0), *self).truncate_to(unit).time()
+    }
+
+    /// Returns this time rounded to the nearest multiple of the given unit,
+    /// with halfway values rounding up. Rounding up past the end of the
+    /// day wraps around to midnight.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if the unit’s count isn’t positive.
+    pub fn round_to(&self, unit: TimeUnit) -> LocalTime {
+        LocalDateTime::new(LocalDate::from_days_since_unix_epoch(0), *self).round_to(unit).time()
+    }
 }
 
 impl TimePiece for LocalTime {
@@ -851,6 +1554,146 @@ impl LocalDateTime {
     pub fn add_seconds(&self, seconds: i64) -> LocalDateTime {
         Self::from_instant(self.to_instant() + Duration::of(seconds))
     }
+
+    /// Returns this datetime truncated to a multiple of the given unit.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if the unit’s count isn’t positive.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, LocalDateTime, LocalTime, Month, TimeUnit};
+    ///
+    /// let datetime = LocalDateTime::new(LocalDate::ymd(2017, Month::August, 14).unwrap(),
+    ///                                   LocalTime::hm(16, 20).unwrap());
+    ///
+    /// let quarter = LocalDateTime::new(LocalDate::ymd(2017, Month::July, 1).unwrap(), LocalTime::midnight());
+    /// assert_eq!(datetime.truncate_to(TimeUnit::Months(3)), quarter);
+    /// ```
+    pub fn truncate_to(&self, unit: TimeUnit) -> LocalDateTime {
+        let count = unit.count();
+        assert!(count > 0, "truncation unit count must be positive");
+
+        if let Some(step) = unit.milliseconds() {
+            let ms = self.time.to_seconds() * 1000 + self.time.millisecond as i64;
+            let ms = ms - ms % step;
+            let time = LocalTime::from_seconds_and_milliseconds_since_midnight(ms / 1000, (ms % 1000) as i16);
+            return LocalDateTime::new(self.date, time);
+        }
+
+        let date = match unit {
+            TimeUnit::Days(n) => {
+                let days = self.date.days_since_unix_epoch();
+                LocalDate::from_days_since_unix_epoch(days - days.rem_euclid(n))
+            },
+            TimeUnit::Weeks(n) => {
+                // The Unix epoch was on a Thursday, so the Monday before
+                // it was three days earlier.
+                let weeks = (self.date.days_since_unix_epoch() + 3).div_euclid(7);
+                LocalDate::from_days_since_unix_epoch((weeks - weeks.rem_euclid(n)) * 7 - 3)
+            },
+            TimeUnit::Months(n) => {
+                let month = self.date.ymd.month.months_from_january() as i64;
+                let month = Month::from_zero((month - month % n) as i8).unwrap();
+                LocalDate::ymd(self.date.ymd.year, month, 1).unwrap()
+            },
+            TimeUnit::Years(n) => {
+                LocalDate::ymd(self.date.ymd.year - self.date.ymd.year.rem_euclid(n), January, 1).unwrap()
+            },
+            _ => unreachable!(),
+        };
+
+        LocalDateTime::new(date, LocalTime::midnight())
+    }
+
+    /// Returns this datetime rounded to the nearest multiple of the given
+    /// unit, with halfway values rounding up.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if the unit’s count isn’t positive.
+    pub fn round_to(&self, unit: TimeUnit) -> LocalDateTime {
+        let down = self.truncate_to(unit);
+        if down == *self {
+            return down;
+        }
+
+        let up = self.next_multiple(unit);
+
+        let milliseconds = |dt: &LocalDateTime| {
+            let instant = dt.to_instant();
+            instant.seconds() * 1000 + instant.milliseconds() as i64
+        };
+
+        if milliseconds(self) - milliseconds(&down) >= milliseconds(&up) - milliseconds(self) { up } else { down }
+    }
+
+    /// Returns the first multiple of the given unit after this datetime.
+    pub(crate) fn next_multiple(&self, unit: TimeUnit) -> LocalDateTime {
+        let down = self.truncate_to(unit);
+
+        // Adding a whole unit to the truncated value, then truncating again,
+        // gives the next multiple, even when the last multiple in a day or
+        // year is a short one.
+        match unit {
+            TimeUnit::Days(n)   => LocalDateTime::new(down.date.add_days(n), down.time),
+            TimeUnit::Weeks(n)  => LocalDateTime::new(down.date.add_weeks(n), down.time),
+            TimeUnit::Months(n) => LocalDateTime::new(down.date.add_months(n, MonthOverflow::Clamp).unwrap(), down.time),
+            TimeUnit::Years(n)  => LocalDateTime::new(down.date.add_years(n, MonthOverflow::Clamp).unwrap(), down.time),
+            _ => {
+                let step = unit.milliseconds().unwrap();
+                down + Duration::of_ms(step / 1000, (step % 1000) as i16)
+            },
+        }.truncate_to(unit)
+    }
+
+    /// Returns the Julian Date of this datetime: the number of days since
+    /// the start of the Julian Period, with the time since noon as a
+    /// fraction. Julian Dates start at noon, so midnight falls halfway
+    /// through one.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, LocalDateTime, LocalTime, Month};
+    ///
+    /// let date = LocalDate::ymd(2000, Month::January, 1).unwrap();
+    /// let noon = LocalDateTime::new(date, LocalTime::hm(12, 0).unwrap());
+    ///
+    /// assert_eq!(noon.julian_date(), 2_451_545.0);
+    /// assert_eq!(noon.modified_julian_date(), 51_544.5);
+    /// ```
+    pub fn julian_date(&self) -> f64 {
+        (self.date.julian_day_number() as f64 - 0.5) + self.time.day_fraction()
+    }
+
+    /// Computes the datetime at the given Julian Date, to the nearest
+    /// millisecond.
+    pub fn from_julian_date(jd: f64) -> LocalDateTime {
+        LocalDateTime::from_fractional_days(jd - (UNIX_EPOCH_JDN as f64 - 0.5))
+    }
+
+    /// Returns the Modified Julian Date of this datetime: the number of
+    /// days since midnight on the 17th of November, 1858, with the time of
+    /// day as a fraction.
+    pub fn modified_julian_date(&self) -> f64 {
+        self.date.modified_julian_day() as f64 + self.time.day_fraction()
+    }
+
+    /// Computes the datetime at the given Modified Julian Date, to the
+    /// nearest millisecond.
+    pub fn from_modified_julian_date(mjd: f64) -> LocalDateTime {
+        LocalDateTime::from_fractional_days(mjd - UNIX_EPOCH_MJD as f64)
+    }
+
+    /// Computes the datetime a fractional number of days after
+    /// **midnight, 1st January, 1970**, to the nearest millisecond.
+    fn from_fractional_days(days: f64) -> LocalDateTime {
+        let milliseconds = (days * MILLISECONDS_IN_DAY).round() as i64;
+        LocalDateTime::at_ms(milliseconds.div_euclid(1000), milliseconds.rem_euclid(1000) as i16)
+    }
 }
 
 impl DatePiece for LocalDateTime {
@@ -1124,6 +1967,59 @@ impl Weekday {
         }
     }
 
+    /// Returns the day after this one, wrapping around from Saturday to
+    /// Sunday.
+    ///
+    /// ```rust
+    /// use datetime::Weekday;
+    /// assert_eq!(Weekday::Saturday.succ(), Weekday::Sunday);
+    /// ```
+    pub fn succ(&self) -> Weekday {
+        self.add_days(1)
+    }
+
+    /// Returns the day before this one, wrapping around from Sunday to
+    /// Saturday.
+    ///
+    /// ```rust
+    /// use datetime::Weekday;
+    /// assert_eq!(Weekday::Sunday.pred(), Weekday::Saturday);
+    /// ```
+    pub fn pred(&self) -> Weekday {
+        self.add_days(-1)
+    }
+
+    /// Returns the weekday that’s the given number of days after this one,
+    /// or before it if the number is negative.
+    ///
+    /// ```rust
+    /// use datetime::Weekday;
+    /// assert_eq!(Weekday::Friday.add_days(3), Weekday::Monday);
+    /// assert_eq!(Weekday::Friday.add_days(-12), Weekday::Sunday);
+    /// ```
+    pub fn add_days(&self, days: i64) -> Weekday {
+        let weekday = (*self as i64 + days).rem_euclid(7);
+        Weekday::from_zero(weekday as i8).unwrap()
+    }
+
+    /// Returns the number of days from the given weekday forward to this
+    /// one, from 0 to 6.
+    ///
+    /// ```rust
+    /// use datetime::Weekday;
+    /// assert_eq!(Weekday::Monday.days_since(Weekday::Friday), 3);
+    /// assert_eq!(Weekday::Friday.days_since(Weekday::Monday), 4);
+    /// ```
+    pub fn days_since(&self, other: Weekday) -> i8 {
+        (*self as i8 - other as i8).rem_euclid(7)
+    }
+
+    /// Returns the number of days from this weekday forward to the given
+    /// one, from 0 to 6.
+    pub fn days_until(&self, other: Weekday) -> i8 {
+        other.days_since(*self)
+    }
+
     /// Return the weekday based on a number, with Sunday as Day 0, Monday as
     /// Day 1, and so on.
     ///
@@ -1169,6 +2065,14 @@ mod test {
         assert!(LocalDate::ymd(1602,Month::February,29).is_err());
     }
 
+    #[test]
+    fn leap_day_is_not_decoded_as_march_1() {
+        for year in [1424, 1600, 1996, 2000, 2004, 2016, 2400].iter() {
+            let date = LocalDate::ymd(*year, Month::February, 29).unwrap();
+            assert_eq!((date.year(), date.month(), date.day(), date.yearday()), (*year, Month::February, 29, 60));
+        }
+    }
+
     #[test]
     fn new() {
         for year in 1..3000 {
@@ -0,0 +1,359 @@
+//! Eras, for writing years the way people count them rather than the way
+//! astronomers do.
+//!
+//! The years in this library are astronomical, so the year before 1 CE is
+//! year 0, and the year before that is year -1. Historians have no year 0:
+//! the year before 1 CE is 1 BCE, so the year -43 is 44 BCE.
+//!
+//! Japan numbers its years from the start of each emperor’s reign, so
+//! 2026 is Reiwa 8, written as 令和8年. Eras are only supported from the
+//! Meiji era onwards, as the ones before it started on dates in the
+//! Japanese lunisolar calendar.
+//!
+//! ### Examples
+//!
+//! ```
+//! use datetime::{LocalDate, Month, DatePiece};
+//! use datetime::era::{Era, EraYear, JapaneseEra, JapaneseYear};
+//!
+//! let ides_of_march = LocalDate::ymd(-43, Month::March, 15).unwrap();
+//! assert_eq!(ides_of_march.era(), Era::BeforeCommonEra);
+//! assert_eq!(ides_of_march.year_of_era(), 44);
+//! assert_eq!("44 BC".parse::<EraYear>().unwrap().to_year(), -43);
+//!
+//! let date = LocalDate::ymd(2026, Month::October, 18).unwrap();
+//! let year = date.japanese_year().unwrap();
+//! assert_eq!(year, JapaneseYear { era: JapaneseEra::Reiwa, year: 8 });
+//! assert_eq!(year.to_string(), "令和8年");
+//! assert_eq!("令和8年".parse::<JapaneseYear>().unwrap().date(Month::October, 18), Ok(date));
+//! ```
+
+use std::error::Error as ErrorTrait;
+use std::fmt;
+use std::str::FromStr;
+
+use cal::DatePiece;
+use cal::datetime::{Error, LocalDate, Month};
+
+
+/// An era of the Gregorian calendar.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+pub enum Era {
+
+    /// The years before 1 CE, also called BC.
+    BeforeCommonEra,
+
+    /// The years from 1 CE onwards, also called AD.
+    CommonEra,
+}
+
+impl Era {
+
+    /// Returns the era that the given astronomical year is in.
+    pub fn of_year(year: i64) -> Era {
+        if year <= 0 { Era::BeforeCommonEra } else { Era::CommonEra }
+    }
+
+    /// Returns the traditional abbreviation for this era, “BC” or “AD”.
+    pub fn abbreviation(self) -> &'static str {
+        match self {
+            Era::BeforeCommonEra  => "BC",
+            Era::CommonEra        => "AD",
+        }
+    }
+
+    /// Returns the secular abbreviation for this era, “BCE” or “CE”.
+    pub fn secular_abbreviation(self) -> &'static str {
+        match self {
+            Era::BeforeCommonEra  => "BCE",
+            Era::CommonEra        => "CE",
+        }
+    }
+}
+
+impl FromStr for Era {
+    type Err = EraError;
+
+    /// Parses an era abbreviation, with or without full stops, in any
+    /// case: `BC`, `BCE`, `AD`, or `CE`.
+    fn from_str(input: &str) -> Result<Era, Self::Err> {
+        match &*input.replace('.', "").to_uppercase() {
+            "BC" | "BCE"  => Ok(Era::BeforeCommonEra),
+            "AD" | "CE"   => Ok(Era::CommonEra),
+            _             => Err(EraError::UnknownEra(input.to_owned())),
+        }
+    }
+}
+
+
+/// A year numbered within a Gregorian era, such as 44 BC.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct EraYear {
+
+    /// The era the year is in.
+    pub era: Era,
+
+    /// The year within the era, counting from 1.
+    pub year: i64,
+}
+
+impl EraYear {
+
+    /// Returns the era year of the given astronomical year.
+    pub fn of_year(year: i64) -> EraYear {
+        match Era::of_year(year) {
+            Era::BeforeCommonEra  => EraYear { era: Era::BeforeCommonEra, year: 1 - year },
+            Era::CommonEra        => EraYear { era: Era::CommonEra, year },
+        }
+    }
+
+    /// Returns the astronomical year number of this year, where 1 BCE is
+    /// year 0.
+    pub fn to_year(&self) -> i64 {
+        match self.era {
+            Era::BeforeCommonEra  => 1 - self.year,
+            Era::CommonEra        => self.year,
+        }
+    }
+}
+
+impl fmt::Display for EraYear {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.year, self.era.abbreviation())
+    }
+}
+
+impl FromStr for EraYear {
+    type Err = EraError;
+
+    /// Parses a year and an era abbreviation separated by a space, in
+    /// either order, such as `44 BC`, `AD 1066`, or `2017 CE`.
+    fn from_str(input: &str) -> Result<EraYear, Self::Err> {
+        let parts: Vec<&str> = input.split_whitespace().collect();
+
+        let (number, era) = match parts[..] {
+            [ first, second ] if first.starts_with(|c: char| c.is_ascii_digit())  => (first, second),
+            [ first, second ]  => (second, first),
+            _                  => return Err(EraError::InvalidYear(input.to_owned())),
+        };
+
+        let era = era.parse()?;
+        match number.parse() {
+            Ok(year) if year >= 1  => Ok(EraYear { era, year }),
+            _                      => Err(EraError::InvalidYear(number.to_owned())),
+        }
+    }
+}
+
+
+/// An era of the Japanese calendar, from the Meiji era onwards.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+pub enum JapaneseEra {
+    Meiji,
+    Taisho,
+    Showa,
+    Heisei,
+    Reiwa,
+}
+
+/// The Japanese eras, with the Gregorian date each one started on, and
+/// its name in kanji and in the Latin alphabet.
+///
+/// The Meiji era was proclaimed on the 23rd of October, 1868, and it was
+/// backdated to the start of that year in the old lunar calendar, but
+/// it’s taken to start on the day it was proclaimed here, so earlier
+/// dates in 1868 have no Japanese year. Japan switched to the Gregorian
+/// calendar at the start of Meiji 6.
+const JAPANESE_ERAS: [(JapaneseEra, i64, Month, i8, &str, &str); 5] = [
+    (JapaneseEra::Meiji,   1868, Month::October,  23, "明治", "Meiji"),
+    (JapaneseEra::Taisho,  1912, Month::July,     30, "大正", "Taishō"),
+    (JapaneseEra::Showa,   1926, Month::December, 25, "昭和", "Shōwa"),
+    (JapaneseEra::Heisei,  1989, Month::January,   8, "平成", "Heisei"),
+    (JapaneseEra::Reiwa,   2019, Month::May,       1, "令和", "Reiwa"),
+];
+
+impl JapaneseEra {
+
+    /// Returns the era that the given date is in, or `None` if it’s
+    /// before the Meiji era.
+    pub fn of_date(year: i64, month: Month, day: i8) -> Option<JapaneseEra> {
+        JAPANESE_ERAS.iter()
+                     .rev()
+                     .find(|e| (e.1, e.2, e.3) <= (year, month, day))
+                     .map(|e| e.0)
+    }
+
+    fn entry(self) -> &'static (JapaneseEra, i64, Month, i8, &'static str, &'static str) {
+        &JAPANESE_ERAS[self as usize]
+    }
+
+    /// Returns the first day of this era.
+    pub fn start(self) -> LocalDate {
+        let entry = self.entry();
+        LocalDate::ymd(entry.1, entry.2, entry.3).unwrap()
+    }
+
+    /// Returns the Gregorian year that year 1 of this era falls in.
+    pub fn first_year(self) -> i64 {
+        self.entry().1
+    }
+
+    /// Returns the number of the last year of this era, which is also the
+    /// first year of the next one, or `None` if it’s the current era.
+    pub fn last_year(self) -> Option<i64> {
+        JAPANESE_ERAS.get(self as usize + 1).map(|next| next.1 - self.first_year() + 1)
+    }
+
+    /// Returns the name of this era in kanji, such as 令和.
+    pub fn name(self) -> &'static str {
+        self.entry().4
+    }
+
+    /// Returns the name of this era in the Latin alphabet, such as Reiwa.
+    pub fn romanized_name(self) -> &'static str {
+        self.entry().5
+    }
+}
+
+impl FromStr for JapaneseEra {
+    type Err = EraError;
+
+    /// Parses the name of an era, in kanji, in the Latin alphabet with or
+    /// without macrons, or as its initial letter, such as `R` for Reiwa.
+    fn from_str(input: &str) -> Result<JapaneseEra, Self::Err> {
+        let latin = input.to_lowercase().replace('ō', "o");
+
+        JAPANESE_ERAS.iter()
+                     .find(|e| e.4 == input || e.5.to_lowercase().replace('ō', "o") == latin || e.5[.. 1].to_lowercase() == latin)
+                     .map(|e| e.0)
+                     .ok_or_else(|| EraError::UnknownEra(input.to_owned()))
+    }
+}
+
+
+/// A year numbered within a Japanese era, such as Reiwa 8.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct JapaneseYear {
+
+    /// The era the year is in.
+    pub era: JapaneseEra,
+
+    /// The year within the era, counting from 1.
+    pub year: i64,
+}
+
+impl JapaneseYear {
+
+    /// Creates a new Japanese year, returning an error if the era didn’t
+    /// last that long.
+    pub fn new(era: JapaneseEra, year: i64) -> Result<JapaneseYear, Error> {
+        if year >= 1 && era.last_year().is_none_or(|last| year <= last) {
+            Ok(JapaneseYear { era, year })
+        }
+        else {
+            Err(Error::OutOfRange)
+        }
+    }
+
+    /// Returns the Japanese year of the given date, or `None` if it’s
+    /// before the Meiji era.
+    pub fn of_date(year: i64, month: Month, day: i8) -> Option<JapaneseYear> {
+        JapaneseEra::of_date(year, month, day)
+            .map(|era| JapaneseYear { era, year: year - era.first_year() + 1 })
+    }
+
+    /// Returns the Gregorian year number of this year.
+    pub fn to_year(&self) -> i64 {
+        self.era.first_year() + self.year - 1
+    }
+
+    /// Returns the date with the given month and day in this year,
+    /// returning an error if it doesn’t exist, or if it falls outside of
+    /// this year’s era.
+    pub fn date(&self, month: Month, day: i8) -> Result<LocalDate, Error> {
+        let date = LocalDate::ymd(self.to_year(), month, day)?;
+
+        if date.japanese_year() == Some(*self) {
+            Ok(date)
+        }
+        else {
+            Err(Error::OutOfRange)
+        }
+    }
+}
+
+impl fmt::Display for JapaneseYear {
+
+    /// Writes this year the way it’s written in Japanese, such as 令和8年,
+    /// with the first year of an era written as 元年.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.year == 1 {
+            write!(f, "{}元年", self.era.name())
+        }
+        else {
+            write!(f, "{}{}年", self.era.name(), self.year)
+        }
+    }
+}
+
+impl FromStr for JapaneseYear {
+    type Err = EraError;
+
+    /// Parses an era name followed by a year, such as `令和8年`, `令和元年`,
+    /// `Reiwa 8`, or `R8`. The year can be written with full-width digits.
+    fn from_str(input: &str) -> Result<JapaneseYear, Self::Err> {
+        let input = input.trim();
+        let without_suffix = input.trim_end_matches('年');
+        let split = without_suffix.find(|c: char| c.is_ascii_digit() || ('０' ..= '９').contains(&c) || c == '元')
+                                  .ok_or_else(|| EraError::InvalidYear(input.to_owned()))?;
+
+        let era = without_suffix[.. split].trim().parse()?;
+        let number = &without_suffix[split ..];
+
+        let year = if number == "元" {
+            Ok(1)
+        }
+        else {
+            number.chars().map(to_ascii_digit).collect::<String>().parse()
+        };
+
+        year.ok()
+            .and_then(|year| JapaneseYear::new(era, year).ok())
+            .ok_or_else(|| EraError::InvalidYear(number.to_owned()))
+    }
+}
+
+/// Turns a full-width digit into its ASCII equivalent, leaving any other
+/// character alone.
+fn to_ascii_digit(c: char) -> char {
+    if ('０' ..= '９').contains(&c) {
+        (b'0' + (c as u32 - '０' as u32) as u8) as char
+    }
+    else {
+        c
+    }
+}
+
+
+/// An error encountered while parsing an era or a year in one.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum EraError {
+
+    /// The era wasn’t one that’s known.
+    UnknownEra(String),
+
+    /// The year wasn’t a number, or was out of range for its era.
+    InvalidYear(String),
+}
+
+impl fmt::Display for EraError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            EraError::UnknownEra(ref era)    => write!(f, "unknown era ‘{}’", era),
+            EraError::InvalidYear(ref year)  => write!(f, "invalid year ‘{}’", year),
+        }
+    }
+}
+
+impl ErrorTrait for EraError {
+}
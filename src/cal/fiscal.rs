@@ -0,0 +1,385 @@
+//! Fiscal years, which start in a month other than January, and retail
+//! calendars, which are made of whole weeks.
+
+use cal::DatePiece;
+use cal::datetime::{Error, LocalDate, Month, Weekday, Year, YearMonth};
+use cal::range::DateRange;
+
+
+/// A **fiscal calendar** divides time into fiscal years that start on the
+/// 1st of a given month, each of which has four quarters of three months
+/// and twelve periods of one month.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::{LocalDate, Month};
+/// use datetime::fiscal::{FiscalCalendar, FiscalDate, YearNaming};
+///
+/// // The UK government’s financial year starts in April.
+/// let calendar = FiscalCalendar { start: Month::April, naming: YearNaming::Starting };
+///
+/// let date = LocalDate::ymd(2018, Month::January, 15).unwrap();
+/// assert_eq!(calendar.fiscal_date(date), FiscalDate { year: 2017, quarter: 4, period: 10 });
+///
+/// let quarter = calendar.quarter_days(2017, 4).unwrap();
+/// assert_eq!(quarter.len(), 90);
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct FiscalCalendar {
+
+    /// The month that every fiscal year starts in.
+    pub start: Month,
+
+    /// Which calendar year a fiscal year is named after.
+    pub naming: YearNaming,
+}
+
+/// Which calendar year a fiscal year is named after, when it spans two.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum YearNaming {
+
+    /// Name the fiscal year after the calendar year it starts in, so the
+    /// year from April 2017 to March 2018 is fiscal year 2017.
+    Starting,
+
+    /// Name the fiscal year after the calendar year it ends in, so the
+    /// year from October 2017 to September 2018 is fiscal year 2018. This
+    /// is what the United States federal government does.
+    Ending,
+}
+
+/// A date’s position in a fiscal calendar.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct FiscalDate {
+
+    /// The fiscal year.
+    pub year: i64,
+
+    /// The quarter of the fiscal year, from 1 to 4.
+    pub quarter: i8,
+
+    /// The period of the fiscal year, from 1 to 12, which is the month
+    /// counting from the start of the fiscal year.
+    pub period: i8,
+}
+
+impl FiscalCalendar {
+
+    /// Creates a new fiscal calendar with years starting in the given month,
+    /// named after the calendar year they end in.
+    pub fn new(start: Month) -> FiscalCalendar {
+        FiscalCalendar { start, naming: YearNaming::Ending }
+    }
+
+    /// Returns the position of the given date in this fiscal calendar.
+    pub fn fiscal_date(&self, date: LocalDate) -> FiscalDate {
+        let start = self.start.months_from_january() as i64;
+        let month = date.month().months_from_january() as i64;
+
+        let period = (month - start).rem_euclid(12);
+        let starting_year = if month < start { date.year() - 1 } else { date.year() };
+
+        FiscalDate {
+            year: self.name_year(starting_year),
+            quarter: (period / 3 + 1) as i8,
+            period: (period + 1) as i8,
+        }
+    }
+
+    /// Returns the fiscal year that the given date falls in.
+    pub fn fiscal_year(&self, date: LocalDate) -> i64 {
+        self.fiscal_date(date).year
+    }
+
+    /// Returns the calendar month that a period of a fiscal year falls
+    /// in, or an error if the period isn’t between 1 and 12.
+    pub fn period_month(&self, year: i64, period: i8) -> Result<YearMonth, Error> {
+        if !(1 ..= 12).contains(&period) {
+            return Err(Error::OutOfRange);
+        }
+
+        Ok(self.first_month(year).add_months(period as i64 - 1))
+    }
+
+    /// Returns the date of the first day of the given fiscal year.
+    pub fn year_start(&self, year: i64) -> LocalDate {
+        self.first_month(year).day(1).unwrap()
+    }
+
+    /// Returns an iterator over every day in the given fiscal year.
+    pub fn year_days(&self, year: i64) -> DateRange {
+        DateRange::new(self.year_start(year), self.year_start(year + 1))
+    }
+
+    /// Returns an iterator over every day in a quarter of the given fiscal
+    /// year, or an error if the quarter isn’t between 1 and 4.
+    pub fn quarter_days(&self, year: i64, quarter: i8) -> Result<DateRange, Error> {
+        if !(1 ..= 4).contains(&quarter) {
+            return Err(Error::OutOfRange);
+        }
+
+        let first = self.period_month(year, (quarter - 1) * 3 + 1)?;
+        Ok(DateRange::new(first.day(1)?, first.add_months(3).day(1)?))
+    }
+
+    /// Returns an iterator over every day in a period of the given fiscal
+    /// year, or an error if the period isn’t between 1 and 12.
+    pub fn period_days(&self, year: i64, period: i8) -> Result<DateRange, Error> {
+        let month = self.period_month(year, period)?;
+        Ok(DateRange::new(month.day(1)?, month.add_months(1).day(1)?))
+    }
+
+    /// Returns the first month of the given fiscal year.
+    fn first_month(&self, year: i64) -> YearMonth {
+        let starting_year = match self.naming {
+            YearNaming::Ending if self.start != Month::January => year - 1,
+            _                                                  => year,
+        };
+
+        Year(starting_year).month(self.start)
+    }
+
+    /// Returns the name of the fiscal year that starts in the given
+    /// calendar year.
+    fn name_year(&self, starting_year: i64) -> i64 {
+        match self.naming {
+            YearNaming::Ending if self.start != Month::January => starting_year + 1,
+            _                                                  => starting_year,
+        }
+    }
+}
+
+impl Default for FiscalCalendar {
+    fn default() -> FiscalCalendar {
+        FiscalCalendar::new(Month::January)
+    }
+}
+
+
+/// A **retail calendar** divides time into fiscal years made of whole
+/// weeks, so that every year, quarter, and period starts on the same day
+/// of the week. This makes sales figures comparable from one year to the
+/// next.
+///
+/// Each year ends on a given weekday near the end of a given month, so
+/// most years have 52 weeks, but some have 53. Each quarter has 13 weeks,
+/// split into three periods using a pattern such as 4-5-4, and the extra
+/// week in a 53-week year is added to the last period.
+///
+/// The default calendar is the one published by the National Retail
+/// Federation: a 4-5-4 pattern, with years ending on the Saturday nearest
+/// to the end of January, named after the calendar year they start in.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::{LocalDate, Month};
+/// use datetime::fiscal::{RetailCalendar, RetailDate};
+///
+/// let calendar = RetailCalendar::default();
+/// let date = LocalDate::ymd(2017, Month::March, 8).unwrap();
+///
+/// let retail = calendar.retail_date(date);
+/// assert_eq!(retail, RetailDate { year: 2017, period: 2, week: 2, day: 4 });
+/// assert_eq!(calendar.date(retail), Ok(date));
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct RetailCalendar {
+
+    /// How the weeks of each quarter are split into periods.
+    pub pattern: WeekPattern,
+
+    /// The day of the week that every year ends on.
+    pub end_weekday: Weekday,
+
+    /// The month that every year ends in or near.
+    pub end_month: Month,
+
+    /// How the last day of the year is picked.
+    pub year_end: YearEnd,
+
+    /// Which calendar year a fiscal year is named after. A year is said
+    /// to end in the calendar year of the month it ends near, even if its
+    /// last day is a few days into the next month.
+    pub naming: YearNaming,
+}
+
+/// How the thirteen weeks of a quarter are split into three periods.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum WeekPattern {
+
+    /// Four weeks, four weeks, then five weeks.
+    FourFourFive,
+
+    /// Four weeks, five weeks, then four weeks.
+    FourFiveFour,
+
+    /// Five weeks, four weeks, then four weeks.
+    FiveFourFour,
+}
+
+impl WeekPattern {
+
+    /// Returns the number of weeks in each period of a quarter.
+    fn weeks(self) -> [i64; 3] {
+        match self {
+            WeekPattern::FourFourFive => [ 4, 4, 5 ],
+            WeekPattern::FourFiveFour => [ 4, 5, 4 ],
+            WeekPattern::FiveFourFour => [ 5, 4, 4 ],
+        }
+    }
+}
+
+/// How a retail calendar picks the last day of its year.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum YearEnd {
+
+    /// The year ends on the last occurrence of the weekday in the month.
+    LastWeekday,
+
+    /// The year ends on the occurrence of the weekday nearest to the last
+    /// day of the month, which can be up to three days into the next
+    /// month.
+    NearestWeekday,
+}
+
+/// A date’s position in a retail calendar.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct RetailDate {
+
+    /// The fiscal year.
+    pub year: i64,
+
+    /// The period of the fiscal year, from 1 to 12.
+    pub period: i8,
+
+    /// The week of the period, from 1 to 5, or 6 for the last period of a
+    /// 53-week year that has five weeks already.
+    pub week: i8,
+
+    /// The day of the week, from 1 to 7, counting from the first day of
+    /// the fiscal week.
+    pub day: i8,
+}
+
+impl RetailCalendar {
+
+    /// Returns the last day of the given fiscal year.
+    pub fn year_end(&self, year: i64) -> LocalDate {
+        let anchor_year = match self.naming {
+            YearNaming::Starting if self.end_month != Month::December => year + 1,
+            _                                                         => year,
+        };
+
+        let month = Year(anchor_year).month(self.end_month);
+        let last = month.day(month.day_count()).unwrap();
+
+        match self.year_end {
+            YearEnd::LastWeekday    => last.weekday_on_or_before(self.end_weekday),
+            YearEnd::NearestWeekday => {
+                let back = last.weekday().days_since(self.end_weekday) as i64;
+                if back <= 3 { last.add_days(-back) } else { last.add_days(7 - back) }
+            },
+        }
+    }
+
+    /// Returns the first day of the given fiscal year.
+    pub fn year_start(&self, year: i64) -> LocalDate {
+        self.year_end(year - 1).add_days(1)
+    }
+
+    /// Returns the number of weeks in the given fiscal year, which is
+    /// either 52 or 53.
+    pub fn week_count(&self, year: i64) -> i64 {
+        self.year_start(year).days_until(self.year_end(year)) / 7 + 1
+    }
+
+    /// Returns the number of weeks in a period of the given fiscal year.
+    fn period_weeks(&self, year: i64, period: i8) -> i64 {
+        let weeks = self.pattern.weeks()[(period as usize - 1) % 3];
+        if period == 12 { weeks + self.week_count(year) - 52 } else { weeks }
+    }
+
+    /// Returns the number of weeks from the start of the fiscal year to
+    /// the start of the given period.
+    fn weeks_before_period(&self, period: i8) -> i64 {
+        (1 .. period).map(|p| self.pattern.weeks()[(p as usize - 1) % 3]).sum()
+    }
+
+    /// Returns the position of the given date in this retail calendar.
+    pub fn retail_date(&self, date: LocalDate) -> RetailDate {
+        let mut year = date.year() - 1;
+        while self.year_end(year) < date {
+            year += 1;
+        }
+
+        let days = self.year_start(year).days_until(date);
+        let weeks = days / 7;
+
+        let mut period = 1;
+        while period < 12 && self.weeks_before_period(period + 1) <= weeks {
+            period += 1;
+        }
+
+        RetailDate {
+            year,
+            period,
+            week: (weeks - self.weeks_before_period(period) + 1) as i8,
+            day: (days % 7 + 1) as i8,
+        }
+    }
+
+    /// Returns the date at the given position in this retail calendar, or
+    /// an error if the period, week, or day are out of range.
+    pub fn date(&self, retail: RetailDate) -> Result<LocalDate, Error> {
+        if !(1 ..= 12).contains(&retail.period) || !(1 ..= 7).contains(&retail.day)
+        || retail.week < 1 || retail.week as i64 > self.period_weeks(retail.year, retail.period) {
+            return Err(Error::OutOfRange);
+        }
+
+        let weeks = self.weeks_before_period(retail.period) + retail.week as i64 - 1;
+        Ok(self.year_start(retail.year).add_days(weeks * 7 + retail.day as i64 - 1))
+    }
+
+    /// Returns an iterator over every day in the given fiscal year.
+    pub fn year_days(&self, year: i64) -> DateRange {
+        DateRange::inclusive(self.year_start(year), self.year_end(year))
+    }
+
+    /// Returns an iterator over every day in a quarter of the given fiscal
+    /// year, or an error if the quarter isn’t between 1 and 4.
+    pub fn quarter_days(&self, year: i64, quarter: i8) -> Result<DateRange, Error> {
+        if !(1 ..= 4).contains(&quarter) {
+            return Err(Error::OutOfRange);
+        }
+
+        let last_period = quarter * 3;
+        let start = self.year_start(year).add_weeks(self.weeks_before_period(last_period - 2));
+        let end   = self.year_start(year).add_weeks(self.weeks_before_period(last_period) + self.period_weeks(year, last_period));
+        Ok(DateRange::new(start, end))
+    }
+
+    /// Returns an iterator over every day in a period of the given fiscal
+    /// year, or an error if the period isn’t between 1 and 12.
+    pub fn period_days(&self, year: i64, period: i8) -> Result<DateRange, Error> {
+        if !(1 ..= 12).contains(&period) {
+            return Err(Error::OutOfRange);
+        }
+
+        let start = self.year_start(year).add_weeks(self.weeks_before_period(period));
+        Ok(DateRange::new(start, start.add_weeks(self.period_weeks(year, period))))
+    }
+}
+
+impl Default for RetailCalendar {
+    fn default() -> RetailCalendar {
+        RetailCalendar {
+            pattern: WeekPattern::FourFiveFour,
+            end_weekday: Weekday::Saturday,
+            end_month: Month::January,
+            year_end: YearEnd::NearestWeekday,
+            naming: YearNaming::Starting,
+        }
+    }
+}
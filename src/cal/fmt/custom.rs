@@ -19,6 +19,16 @@ pub enum Field<'a> {
     Year(NumArguments),
     YearOfCentury(NumArguments),
 
+    // The short era names are “BC” and “AD”, and the long ones are the
+    // secular “BCE” and “CE”.
+    EraName(bool, TextArguments),
+    YearOfEra(NumArguments),
+
+    // Japanese eras are named in kanji, or in the Latin alphabet when
+    // long. Dates before the Meiji era have neither a name nor a year.
+    JapaneseEraName(bool, TextArguments),
+    JapaneseYear(NumArguments),
+
     MonthName(bool, TextArguments),
 
     Day(NumArguments),
@@ -35,6 +45,18 @@ impl<'a> Field<'a> {
             Field::Literal(s)             => w.write_all(s.as_bytes()),
             Field::Year(a)                => a.format(w, when.year()),
             Field::YearOfCentury(a)       => a.format(w, when.year_of_century()),
+            Field::EraName(true, a)       => a.format(w, when.era().secular_abbreviation()),
+            Field::EraName(false, a)      => a.format(w, when.era().abbreviation()),
+            Field::YearOfEra(a)           => a.format(w, when.year_of_era()),
+            Field::JapaneseEraName(long, a) => match when.japanese_year() {
+                Some(year) if long        => a.format(w, year.era.romanized_name()),
+                Some(year)                => a.format(w, year.era.name()),
+                None                      => Ok(()),
+            },
+            Field::JapaneseYear(a)        => match when.japanese_year() {
+                Some(year)                => a.format(w, year.year),
+                None                      => Ok(()),
+            },
             Field::MonthName(true, a)     => a.format(w, &locale.long_month_name(when.month() as usize - 1)[..]),
             Field::MonthName(false, a)    => a.format(w, &locale.short_month_name(when.month() as usize - 1)[..]),
             Field::Day(a)                 => a.format(w, when.day()),
@@ -310,6 +332,10 @@ impl<'a> FormatParser<'a> {
                     let bitlet = match self.next() {
                         Some((_, 'Y')) => Field::Year(NumArguments(args)),
                         Some((_, 'y')) => Field::YearOfCentury(NumArguments(args)),
+                        Some((_, 'G')) => Field::EraName(long, TextArguments(args)),
+                        Some((_, 'e')) => Field::YearOfEra(NumArguments(args)),
+                        Some((_, 'J')) => Field::JapaneseEraName(long, TextArguments(args)),
+                        Some((_, 'j')) => Field::JapaneseYear(NumArguments(args)),
                         Some((_, 'M')) => Field::MonthName(long, TextArguments(args)),
                         Some((_, 'D')) => Field::Day(NumArguments(args)),
                         Some((_, 'E')) => Field::WeekdayName(long, TextArguments(args)),
@@ -363,6 +389,8 @@ mod test {
         test!(two_long_years: "{:Y}{:Y}"            => Ok(DateFormat { fields: vec![ Year(NumArguments::empty()), Year(NumArguments::empty()) ] }));
         test!(surrounded: "({:D})"                  => Ok(DateFormat { fields: vec![ Literal("("), Day(NumArguments::empty()), Literal(")") ] }));
         test!(a_bunch_of_elements: "{:Y}-{:M}-{:D}" => Ok(DateFormat { fields: vec![ Year(NumArguments::empty()), Literal("-"), MonthName(false, TextArguments::empty()), Literal("-"), Day(NumArguments::empty()) ] }));
+        test!(era_year: "{:e} {_:G}"                => Ok(DateFormat { fields: vec![ YearOfEra(NumArguments::empty()), Literal(" "), EraName(true, TextArguments::empty()) ] }));
+        test!(japanese_year: "{:J}{:j}年"           => Ok(DateFormat { fields: vec![ JapaneseEraName(false, TextArguments::empty()), JapaneseYear(NumArguments::empty()), Literal("年") ] }));
 
         test!(missing_field: "{}"                              => Err(FormatError::MissingField { open_pos: 0, close_pos: 1 }));
         test!(invalid_char: "{a}"                              => Err(FormatError::InvalidChar { c: 'a', colon: false, pos: 1 }));
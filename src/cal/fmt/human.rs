@@ -0,0 +1,489 @@
+//! Human-friendly duration strings, such as `1h 30m` or `2 days`.
+
+use std::error::Error as ErrorTrait;
+use std::fmt;
+
+use duration::Duration;
+
+
+/// A unit of time with a fixed length, which a human-friendly duration is
+/// broken down into.
+///
+/// Months and years are missing because they vary in length, so they can’t
+/// be part of a `Duration`.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+pub enum Unit {
+    Millisecond,
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+}
+
+/// All the units, from largest to smallest.
+static UNITS: &[Unit] = &[
+    Unit::Week, Unit::Day, Unit::Hour, Unit::Minute, Unit::Second, Unit::Millisecond,
+];
+
+impl Unit {
+
+    /// Returns the number of milliseconds in one of this unit. As
+    /// everywhere in this library, a day is always 24 hours long.
+    pub fn milliseconds(self) -> i64 {
+        match self {
+            Unit::Millisecond => 1,
+            Unit::Second      => 1000,
+            Unit::Minute      => 60 * 1000,
+            Unit::Hour        => 60 * 60 * 1000,
+            Unit::Day         => 24 * 60 * 60 * 1000,
+            Unit::Week        => 7 * 24 * 60 * 60 * 1000,
+        }
+    }
+
+    /// Returns the short symbol used for this unit when formatting, such
+    /// as `h` for hours.
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Unit::Millisecond => "ms",
+            Unit::Second      => "s",
+            Unit::Minute      => "m",
+            Unit::Hour        => "h",
+            Unit::Day         => "d",
+            Unit::Week        => "w",
+        }
+    }
+}
+
+
+/// How to round a duration that doesn’t fit exactly into the smallest
+/// unit being displayed.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Rounding {
+
+    /// Round towards zero.
+    Down,
+
+    /// Round away from zero.
+    Up,
+
+    /// Round to the nearest value, with halves rounding away from zero.
+    Nearest,
+}
+
+/// A **duration format** displays a `Duration` compactly, as a list of
+/// units such as `1h 30m 5.2s`.
+///
+/// The duration gets broken down into every unit between the largest and
+/// smallest ones, skipping any that are zero. Whatever is left over that’s
+/// too small for the smallest unit is shown as a decimal fraction of it,
+/// with up to `decimals` digits (and trailing zeroes removed).
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::Duration;
+/// use datetime::human::{DurationFormat, Unit, Rounding};
+///
+/// let duration = Duration::of_ms(5405, 200);
+/// assert_eq!(DurationFormat::default().format(&duration), "1h 30m 5.2s");
+///
+/// let format = DurationFormat { smallest: Unit::Minute, decimals: 0, rounding: Rounding::Down, ..DurationFormat::default() };
+/// assert_eq!(format.format(&duration), "1h 30m");
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct DurationFormat {
+
+    /// The largest unit to break the duration down into. Anything larger
+    /// gets counted in this unit, so a largest unit of hours displays
+    /// two days as `48h`.
+    pub largest: Unit,
+
+    /// The smallest unit to break the duration down into.
+    pub smallest: Unit,
+
+    /// The maximum number of decimal places to show for the smallest unit.
+    /// Anything above nine is treated as nine, which is already more than
+    /// a millisecond needs.
+    pub decimals: u32,
+
+    /// How to round the duration to fit the smallest unit and decimals.
+    pub rounding: Rounding,
+
+    /// The text to put between each unit.
+    pub separator: &'static str,
+}
+
+impl Default for DurationFormat {
+    fn default() -> DurationFormat {
+        DurationFormat {
+            largest:   Unit::Day,
+            smallest:  Unit::Second,
+            decimals:  3,
+            rounding:  Rounding::Nearest,
+            separator: " ",
+        }
+    }
+}
+
+impl DurationFormat {
+
+    /// Formats the given duration according to this format.
+    pub fn format(&self, duration: &Duration) -> String {
+        let (seconds, milliseconds) = duration.lengths();
+        let total = seconds * 1000 + milliseconds as i64;
+
+        let smallest_ms = self.smallest.milliseconds();
+        let decimals = self.decimals.min(9);
+        let scale = 10_i64.pow(decimals);
+        let quantum = (smallest_ms / scale).max(1);
+
+        let rounded = round(total.abs(), quantum, self.rounding);
+        let mut remaining = rounded;
+        let mut parts = Vec::new();
+
+        for unit in UNITS.iter().filter(|u| **u > self.smallest && **u <= self.largest) {
+            let count = remaining / unit.milliseconds();
+            remaining -= count * unit.milliseconds();
+
+            if count != 0 {
+                parts.push(format!("{}{}", count, unit.symbol()));
+            }
+        }
+
+        let count = remaining / smallest_ms;
+        let fraction = remaining % smallest_ms * scale / smallest_ms;
+
+        if fraction != 0 {
+            let digits = format!("{:0width$}", fraction, width = decimals as usize);
+            parts.push(format!("{}.{}{}", count, digits.trim_end_matches('0'), self.smallest.symbol()));
+        }
+        else if count != 0 || parts.is_empty() {
+            parts.push(format!("{}{}", count, self.smallest.symbol()));
+        }
+
+        let sign = if total < 0 && rounded != 0 { "-" } else { "" };
+        format!("{}{}", sign, parts.join(self.separator))
+    }
+}
+
+/// Rounds a non-negative number to a multiple of the quantum.
+fn round(number: i64, quantum: i64, rounding: Rounding) -> i64 {
+    let remainder = number % quantum;
+    let floor = number - remainder;
+
+    match rounding {
+        Rounding::Down                                   => floor,
+        Rounding::Up if remainder != 0                   => floor + quantum,
+        Rounding::Nearest if remainder * 2 >= quantum    => floor + quantum,
+        Rounding::Up | Rounding::Nearest                 => floor,
+    }
+}
+
+
+/// A **duration parser** reads the kinds of durations that people type,
+/// such as `1h30m`, `90s`, `2 days`, `1.5h`, or `1 hour and 5 minutes`.
+///
+/// The input is a list of numbers, each followed by a unit name. Numbers
+/// can have a decimal fraction, and the whole duration can have a leading
+/// minus sign. Unit names are matched case-insensitively, and spaces and
+/// commas (or the word “and”) can appear between any of the parts.
+///
+/// The unit names that get recognised can be configured: a new parser
+/// comes with English names and abbreviations for every `Unit`, such as
+/// `h`, `hr`, `hrs`, `hour`, and `hours`.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::Duration;
+/// use datetime::human::{DurationParser, Unit};
+///
+/// let mut parser = DurationParser::new();
+/// assert_eq!(parser.parse("1h30m"), Ok(Duration::of(5400)));
+/// assert_eq!(parser.parse("2 days"), Ok(Duration::of(172800)));
+///
+/// parser.add_unit("Wochen", Unit::Week);
+/// parser.default_unit = Some(Unit::Second);
+/// assert_eq!(parser.parse("90"), Ok(Duration::of(90)));
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+pub struct DurationParser {
+    units: Vec<(String, Unit)>,
+
+    /// The unit to use for a number on its own, such as `90`. If this is
+    /// `None`, numbers without units are an error.
+    pub default_unit: Option<Unit>,
+}
+
+static DEFAULT_NAMES: &[(&str, Unit)] = &[
+    ("ms", Unit::Millisecond), ("msec", Unit::Millisecond), ("msecs", Unit::Millisecond),
+    ("millisecond", Unit::Millisecond), ("milliseconds", Unit::Millisecond),
+    ("s", Unit::Second), ("sec", Unit::Second), ("secs", Unit::Second),
+    ("second", Unit::Second), ("seconds", Unit::Second),
+    ("m", Unit::Minute), ("min", Unit::Minute), ("mins", Unit::Minute),
+    ("minute", Unit::Minute), ("minutes", Unit::Minute),
+    ("h", Unit::Hour), ("hr", Unit::Hour), ("hrs", Unit::Hour),
+    ("hour", Unit::Hour), ("hours", Unit::Hour),
+    ("d", Unit::Day), ("day", Unit::Day), ("days", Unit::Day),
+    ("w", Unit::Week), ("wk", Unit::Week), ("wks", Unit::Week),
+    ("week", Unit::Week), ("weeks", Unit::Week),
+];
+
+impl Default for DurationParser {
+    fn default() -> DurationParser {
+        DurationParser::new()
+    }
+}
+
+impl DurationParser {
+
+    /// Creates a new parser that recognises the default English unit names.
+    pub fn new() -> DurationParser {
+        let mut parser = DurationParser::empty();
+        for &(name, unit) in DEFAULT_NAMES {
+            parser.add_unit(name, unit);
+        }
+        parser
+    }
+
+    /// Creates a new parser that doesn’t recognise any unit names.
+    pub fn empty() -> DurationParser {
+        DurationParser { units: Vec::new(), default_unit: None }
+    }
+
+    /// Adds a unit name to this parser, replacing any unit with the same
+    /// name (ignoring case).
+    pub fn add_unit(&mut self, name: &str, unit: Unit) {
+        self.remove_unit(name);
+        self.units.push((name.to_lowercase(), unit));
+    }
+
+    /// Stops this parser from recognising the given unit name.
+    pub fn remove_unit(&mut self, name: &str) {
+        let name = name.to_lowercase();
+        self.units.retain(|u| u.0 != name);
+    }
+
+    /// Returns the unit a name refers to, if this parser recognises it.
+    pub fn unit(&self, name: &str) -> Option<Unit> {
+        let name = name.to_lowercase();
+        self.units.iter().find(|u| u.0 == name).map(|u| u.1)
+    }
+
+    /// Parses a human-friendly duration.
+    pub fn parse(&self, input: &str) -> Result<Duration, ParseError> {
+        let mut tokens = Tokens { input, pos: 0 };
+        tokens.skip_separators();
+
+        let negative = match tokens.peek() {
+            Some('-') => { tokens.pos += 1; true },
+            Some('+') => { tokens.pos += 1; false },
+            _         => false,
+        };
+
+        let mut total: i64 = 0;
+        let mut components = 0;
+
+        loop {
+            tokens.skip_separators();
+            let start = tokens.pos;
+
+            let (whole, fraction) = match tokens.number() {
+                Some(n) => n,
+                None if tokens.peek().is_none() => break,
+                None => return Err(ParseError::MissingNumber { pos: start }),
+            };
+
+            tokens.skip_spaces();
+            let name_pos = tokens.pos;
+            let name = tokens.word();
+
+            let unit = if name.is_empty() {
+                match self.default_unit {
+                    Some(unit) if tokens.peek().is_none() && components == 0 => unit,
+                    _ => return Err(ParseError::MissingUnit { pos: name_pos }),
+                }
+            }
+            else {
+                match self.unit(name) {
+                    Some(unit) => unit,
+                    None       => return Err(ParseError::UnknownUnit { name: name.to_string(), pos: name_pos }),
+                }
+            };
+
+            let unit_ms = unit.milliseconds();
+
+            let ms = whole.parse::<i64>().ok()
+                          .and_then(|w| w.checked_mul(unit_ms))
+                          .and_then(|w| w.checked_add(fraction_of(fraction, unit_ms)))
+                          .and_then(|ms| total.checked_add(ms));
+
+            match ms {
+                Some(ms) => total = ms,
+                None     => return Err(ParseError::Overflow { pos: start }),
+            }
+
+            components += 1;
+        }
+
+        if components == 0 {
+            return Err(ParseError::Empty);
+        }
+
+        if negative {
+            total = -total;
+        }
+
+        Ok(Duration::of_ms(total.div_euclid(1000), total.rem_euclid(1000) as i16))
+    }
+}
+
+/// Parses a human-friendly duration using the default unit names. See
+/// `DurationParser` for what gets accepted.
+pub fn parse(input: &str) -> Result<Duration, ParseError> {
+    DurationParser::new().parse(input)
+}
+
+/// Returns the number of milliseconds in a decimal fraction of a unit,
+/// given the fraction’s digits, truncating to the millisecond.
+fn fraction_of(digits: &str, unit_ms: i64) -> i64 {
+    let digits = &digits[.. digits.len().min(9)];
+    match digits.parse::<i64>() {
+        Ok(n)  => n * unit_ms / 10_i64.pow(digits.len() as u32),
+        Err(_) => 0,
+    }
+}
+
+/// A cursor over the input to a duration parser.
+struct Tokens<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Tokens<'a> {
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos ..].chars().next()
+    }
+
+    fn take_while<F: Fn(char) -> bool>(&mut self, predicate: F) -> &'a str {
+        let rest = &self.input[self.pos ..];
+        let length = rest.find(|c| !predicate(c)).unwrap_or(rest.len());
+        self.pos += length;
+        &rest[.. length]
+    }
+
+    fn skip_spaces(&mut self) {
+        let _ = self.take_while(char::is_whitespace);
+    }
+
+    /// Skips over whitespace, commas, and the word “and”.
+    fn skip_separators(&mut self) {
+        loop {
+            let _ = self.take_while(|c| c.is_whitespace() || c == ',');
+            let rest = &self.input[self.pos ..];
+
+            let is_and = rest.len() >= 3 && rest[.. 3].eq_ignore_ascii_case("and")
+                      && !rest[3 ..].starts_with(char::is_alphabetic);
+
+            if is_and { self.pos += 3 } else { break }
+        }
+    }
+
+    /// Reads a number with an optional decimal fraction, returning the
+    /// digits before and after the point.
+    fn number(&mut self) -> Option<(&'a str, &'a str)> {
+        let start = self.pos;
+        let whole = self.take_while(|c| c.is_ascii_digit());
+
+        let fraction = if self.peek() == Some('.') {
+            self.pos += 1;
+            self.take_while(|c| c.is_ascii_digit())
+        }
+        else {
+            ""
+        };
+
+        if whole.is_empty() && fraction.is_empty() {
+            self.pos = start;
+            None
+        }
+        else if whole.is_empty() {
+            Some(("0", fraction))
+        }
+        else {
+            Some((whole, fraction))
+        }
+    }
+
+    fn word(&mut self) -> &'a str {
+        self.take_while(char::is_alphabetic)
+    }
+}
+
+
+/// An error encountered while parsing a human-friendly duration.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum ParseError {
+
+    /// The input didn’t have any durations in it.
+    Empty,
+
+    /// Something other than a number was found where a number should be.
+    MissingNumber { pos: usize },
+
+    /// A number wasn’t followed by a unit.
+    MissingUnit { pos: usize },
+
+    /// A unit name wasn’t recognised.
+    UnknownUnit { name: String, pos: usize },
+
+    /// A number was too large to be represented.
+    Overflow { pos: usize },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseError::Empty                        => write!(f, "no duration given"),
+            ParseError::MissingNumber { pos }        => write!(f, "expected a number at position {}", pos),
+            ParseError::MissingUnit { pos }          => write!(f, "expected a unit at position {}", pos),
+            ParseError::UnknownUnit { ref name, pos } => write!(f, "unknown unit ‘{}’ at position {}", name, pos),
+            ParseError::Overflow { pos }             => write!(f, "number too large at position {}", pos),
+        }
+    }
+}
+
+impl ErrorTrait for ParseError {
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::{DurationFormat, Unit, Rounding, round};
+    use duration::Duration;
+
+    #[test]
+    fn rounding_nearest() {
+        assert_eq!(round(1499, 1000, Rounding::Nearest), 1000);
+        assert_eq!(round(1500, 1000, Rounding::Nearest), 2000);
+    }
+
+    #[test]
+    fn rounding_up() {
+        assert_eq!(round(1001, 1000, Rounding::Up), 2000);
+        assert_eq!(round(1000, 1000, Rounding::Up), 1000);
+    }
+
+    #[test]
+    fn largest_unit() {
+        let format = DurationFormat { largest: Unit::Hour, ..DurationFormat::default() };
+        assert_eq!(format.format(&Duration::of(2 * 86400 + 60)), "48h 1m");
+    }
+
+    #[test]
+    fn rounding_carries() {
+        let format = DurationFormat { smallest: Unit::Minute, decimals: 0, ..DurationFormat::default() };
+        assert_eq!(format.format(&Duration::of(3599)), "1h");
+    }
+}
@@ -1,6 +1,9 @@
 use std::fmt;
 use cal::{LocalDate, LocalTime, LocalDateTime, DatePiece, TimePiece};
 use cal::{Offset, OffsetDateTime};
+use cal::interval::{IsoInterval, RepeatingInterval};
+use cal::period::Period;
+use duration::Duration;
 use util::RangeExt;
 
 
@@ -70,3 +73,123 @@ impl ISO for OffsetDateTime {
         write!(f, "{}{}", self.local.iso(), self.offset.iso())
     }
 }
+
+impl ISO for Duration {
+
+    /// Writes this duration in the ISO 8601 `PnDTnHnMn.nS` form, counting
+    /// a day as exactly 24 hours, such as `P1DT2H30M` or `-PT0.5S`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (seconds, milliseconds) = self.lengths();
+        let total_ms = seconds * 1000 + milliseconds as i64;
+
+        if total_ms < 0 {
+            f.write_str("-")?;
+        }
+
+        let total_ms = total_ms.abs();
+        let seconds = total_ms / 1000;
+        let days = seconds / 86400;
+
+        f.write_str("P")?;
+        if days != 0 {
+            write!(f, "{}D", days)?;
+        }
+
+        let ms = total_ms % 1000;
+        let (h, m, s) = (seconds / 3600 % 24, seconds / 60 % 60, seconds % 60);
+        if total_ms == 0 || h != 0 || m != 0 || s != 0 || ms != 0 {
+            write_time_part(f, h, m, s, ms)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ISO for Period {
+
+    /// Writes this period in the ISO 8601 `PnYnMnDTnHnMn.nS` form, such as
+    /// `P1Y2M10DT2H30M`, or as `PnW` if it only has weeks in it.
+    ///
+    /// Weeks get folded into days when there are other fields, as the two
+    /// can’t be combined in ISO 8601. When every field is negative or zero,
+    /// the period is written with a leading minus sign; otherwise, any
+    /// negative fields get a minus sign of their own.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let fields = [ self.years, self.months, self.weeks, self.days,
+                       self.hours, self.minutes, self.seconds, self.milliseconds ];
+
+        let period = if fields.iter().all(|n| *n <= 0) && fields.iter().any(|n| *n < 0) {
+            f.write_str("-")?;
+            -*self
+        }
+        else {
+            *self
+        };
+
+        f.write_str("P")?;
+
+        if period == Period::weeks(period.weeks) && period.weeks != 0 {
+            return write!(f, "{}W", period.weeks);
+        }
+
+        let days = period.total_days();
+        if period.years != 0 { write!(f, "{}Y", period.years)?; }
+        if period.months != 0 { write!(f, "{}M", period.months)?; }
+        if days != 0 { write!(f, "{}D", days)?; }
+
+        if period.has_time() {
+            let ms = period.seconds * 1000 + period.milliseconds;
+            write_time_part(f, period.hours, period.minutes, ms / 1000, ms % 1000)?;
+        }
+        else if period.years == 0 && period.months == 0 && days == 0 {
+            f.write_str("0D")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ISO for IsoInterval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            IsoInterval::StartEnd(start, end)       => write!(f, "{}/{}", start.iso(), end.iso()),
+            IsoInterval::StartPeriod(start, period) => write!(f, "{}/{}", start.iso(), period.iso()),
+            IsoInterval::PeriodEnd(period, end)     => write!(f, "{}/{}", period.iso(), end.iso()),
+            IsoInterval::Period(period)             => write!(f, "{}", period.iso()),
+        }
+    }
+}
+
+impl ISO for RepeatingInterval {
+
+    /// Writes this interval with the number of repetitions in front, such
+    /// as `R5/2008-03-01T13:00:00.000Z/P1D`, or with just `R` if it repeats
+    /// forever.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.repetitions {
+            Some(count) => write!(f, "R{}/{}", count, self.interval.iso()),
+            None        => write!(f, "R/{}", self.interval.iso()),
+        }
+    }
+}
+
+/// Writes the `TnHnMn.nS` part of a duration, leaving out any fields that
+/// are zero unless they all are. Trailing zeroes in the milliseconds are
+/// left out too.
+fn write_time_part(f: &mut fmt::Formatter, hours: i64, minutes: i64, seconds: i64, milliseconds: i64) -> fmt::Result {
+    f.write_str("T")?;
+    if hours != 0 { write!(f, "{}H", hours)?; }
+    if minutes != 0 { write!(f, "{}M", minutes)?; }
+
+    if milliseconds != 0 {
+        let sign = if seconds < 0 || milliseconds < 0 { "-" } else { "" };
+        let fraction = format!("{:03}", milliseconds.abs());
+        write!(f, "{}{}.{}S", sign, seconds.abs(), fraction.trim_end_matches('0'))
+    }
+    else if seconds != 0 || (hours == 0 && minutes == 0) {
+        write!(f, "{}S", seconds)
+    }
+    else {
+        Ok(())
+    }
+}
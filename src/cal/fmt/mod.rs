@@ -1,4 +1,6 @@
 pub mod iso;
 pub mod custom;
+pub mod human;
+pub mod relative;
 
 pub use cal::fmt::iso::ISO;
\ No newline at end of file
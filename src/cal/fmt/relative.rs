@@ -0,0 +1,456 @@
+//! Relative time phrases, such as “5 minutes ago” or “in 3 weeks”.
+
+use locale;
+
+use duration::Duration;
+use instant::Instant;
+
+
+/// A language that relative time phrases can be written in.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Language {
+    English,
+    French,
+    German,
+    Japanese,
+    Polish,
+    Russian,
+    Spanish,
+}
+
+/// A CLDR plural category, which decides which form of a word goes with a
+/// number: English only has “one” (1 day) and “other” (2 days), but other
+/// languages have more forms.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl Language {
+
+    /// Tries to work out which language a locale is in, based on the
+    /// names of its months, returning `None` if it isn’t one of the
+    /// languages with built-in phrases.
+    pub fn from_locale(locale: &locale::Time) -> Option<Language> {
+        let january = locale.long_month_name(0).to_lowercase();
+        LANGUAGES.iter()
+                 .find(|l| l.january.iter().any(|name| *name == january))
+                 .map(|l| l.language)
+    }
+
+    /// Returns the plural category that a whole number falls into in this
+    /// language, following the CLDR plural rules.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::relative::{Language, PluralCategory};
+    ///
+    /// assert_eq!(Language::English.plural_category(1), PluralCategory::One);
+    /// assert_eq!(Language::Russian.plural_category(22), PluralCategory::Few);
+    /// assert_eq!(Language::Russian.plural_category(12), PluralCategory::Many);
+    /// ```
+    pub fn plural_category(self, number: u64) -> PluralCategory {
+        let (mod10, mod100) = (number % 10, number % 100);
+
+        match self {
+            Language::English | Language::German | Language::Spanish => {
+                if number == 1 { PluralCategory::One } else { PluralCategory::Other }
+            },
+            Language::French => {
+                if number <= 1 { PluralCategory::One } else { PluralCategory::Other }
+            },
+            Language::Japanese => PluralCategory::Other,
+            Language::Russian => {
+                if mod10 == 1 && mod100 != 11 { PluralCategory::One }
+                else if (2..5).contains(&mod10) && !(12..15).contains(&mod100) { PluralCategory::Few }
+                else { PluralCategory::Many }
+            },
+            Language::Polish => {
+                if number == 1 { PluralCategory::One }
+                else if (2..5).contains(&mod10) && !(12..15).contains(&mod100) { PluralCategory::Few }
+                else { PluralCategory::Many }
+            },
+        }
+    }
+
+    fn phrases(self) -> &'static Phrases {
+        LANGUAGES.iter().find(|l| l.language == self).unwrap()
+    }
+}
+
+
+/// A unit that a relative time phrase can be counted in.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+pub enum RelativeUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+/// The points at which a relative time phrase switches to a larger unit.
+///
+/// Each threshold is the count of a unit at which the next unit takes over,
+/// with the count being rounded to the nearest whole number first. With
+/// the defaults, 44 seconds is “44 seconds ago” but 45 seconds is “1 minute
+/// ago”, and 21 hours is “21 hours ago” but 22 hours is “1 day ago”.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct Thresholds {
+
+    /// Anything shorter than this many seconds is described as “now”.
+    pub now: i64,
+
+    /// The number of seconds at which minutes take over.
+    pub seconds: i64,
+
+    /// The number of minutes at which hours take over.
+    pub minutes: i64,
+
+    /// The number of hours at which days take over.
+    pub hours: i64,
+
+    /// The number of days at which weeks take over.
+    pub days: i64,
+
+    /// The number of weeks at which months take over.
+    pub weeks: i64,
+
+    /// The number of months at which years take over.
+    pub months: i64,
+}
+
+impl Default for Thresholds {
+    fn default() -> Thresholds {
+        Thresholds {
+            now:     1,
+            seconds: 45,
+            minutes: 45,
+            hours:   22,
+            days:    7,
+            weeks:   4,
+            months:  11,
+        }
+    }
+}
+
+/// Average number of seconds in a Gregorian month.
+const SECONDS_IN_MONTH: i64 = 2_629_746;
+
+/// Average number of seconds in a Gregorian year.
+const SECONDS_IN_YEAR: i64 = 31_556_952;
+
+impl Thresholds {
+
+    /// Picks the unit and count that best describe the given number of
+    /// seconds, or `None` if it’s short enough to count as “now”.
+    pub fn select(&self, seconds: i64) -> Option<(RelativeUnit, u64)> {
+        let seconds = seconds.abs();
+        let rounded = |unit: i64| ((seconds + unit / 2) / unit).max(1);
+
+        if seconds < self.now {
+            return None;
+        }
+
+        let (unit, count) = if seconds < self.seconds {
+            (RelativeUnit::Second, seconds)
+        }
+        else if rounded(60) < self.minutes {
+            (RelativeUnit::Minute, rounded(60))
+        }
+        else if rounded(3600) < self.hours {
+            (RelativeUnit::Hour, rounded(3600))
+        }
+        else if rounded(86400) < self.days {
+            (RelativeUnit::Day, rounded(86400))
+        }
+        else if rounded(7 * 86400) < self.weeks {
+            (RelativeUnit::Week, rounded(7 * 86400))
+        }
+        else if rounded(SECONDS_IN_MONTH) < self.months {
+            (RelativeUnit::Month, rounded(SECONDS_IN_MONTH))
+        }
+        else {
+            (RelativeUnit::Year, rounded(SECONDS_IN_YEAR))
+        };
+
+        Some((unit, count as u64))
+    }
+}
+
+
+/// A **relative format** describes how far away a point in time is, in
+/// words, such as “yesterday”, “in 3 weeks”, or “5 minutes ago”.
+///
+/// Like `DateFormat::format`, the formatting methods take a `locale::Time`
+/// value, which is used to pick the language: if the locale’s language
+/// can’t be recognised, the phrases fall back to English. The `language`
+/// field can be set to override this.
+///
+/// ### Examples
+///
+/// ```
+/// extern crate datetime;
+/// extern crate locale;
+///
+/// use datetime::{Instant, Duration};
+/// use datetime::relative::{RelativeFormat, Language};
+///
+/// # fn main() {
+/// let now = Instant::at(1_500_000_000);
+/// let format = RelativeFormat::default();
+/// let english = locale::Time::english();
+///
+/// assert_eq!(format.format(now - Duration::of(300), now, &english), "5 minutes ago");
+/// assert_eq!(format.format(now + Duration::of(86400), now, &english), "tomorrow");
+///
+/// let german = RelativeFormat { language: Some(Language::German), ..RelativeFormat::default() };
+/// assert_eq!(german.format(now + Duration::of(3 * 7 * 86400), now, &english), "in 3 Wochen");
+/// # }
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct RelativeFormat {
+
+    /// When to switch from one unit to the next.
+    pub thresholds: Thresholds,
+
+    /// Whether to use words such as “yesterday” and “tomorrow” instead of
+    /// “1 day ago” and “in 1 day”.
+    pub idiomatic: bool,
+
+    /// The language to use, overriding the one from the locale.
+    pub language: Option<Language>,
+}
+
+impl Default for RelativeFormat {
+    fn default() -> RelativeFormat {
+        RelativeFormat {
+            thresholds: Thresholds::default(),
+            idiomatic:  true,
+            language:   None,
+        }
+    }
+}
+
+impl RelativeFormat {
+
+    /// Describes the instant `then` relative to the instant `now`.
+    pub fn format(&self, then: Instant, now: Instant, locale: &locale::Time) -> String {
+        let ms = i64::from(then.milliseconds()) - i64::from(now.milliseconds());
+        let seconds = then.seconds() - now.seconds() + ms.div_euclid(1000);
+        self.format_seconds(seconds, self.pick_language(locale))
+    }
+
+    /// Describes a point in time that is the given duration away from now:
+    /// a positive duration is in the future, and a negative one is in the
+    /// past.
+    pub fn format_duration(&self, offset: &Duration, locale: &locale::Time) -> String {
+        self.format_seconds(offset.lengths().0, self.pick_language(locale))
+    }
+
+    fn pick_language(&self, locale: &locale::Time) -> Language {
+        self.language.or_else(|| Language::from_locale(locale)).unwrap_or(Language::English)
+    }
+
+    fn format_seconds(&self, seconds: i64, language: Language) -> String {
+        let phrases = language.phrases();
+        let future = seconds > 0;
+
+        let (unit, count) = match self.thresholds.select(seconds) {
+            Some(unit_count) => unit_count,
+            None             => return phrases.now.to_string(),
+        };
+
+        if self.idiomatic && unit == RelativeUnit::Day && count == 1 {
+            return if future { phrases.tomorrow } else { phrases.yesterday }.to_string();
+        }
+
+        let forms = phrases.units[unit as usize];
+        let forms = if future { forms.0 } else { forms.1 };
+        let pattern = match language.plural_category(count) {
+            PluralCategory::One  => forms[0],
+            PluralCategory::Few  => forms[1],
+            PluralCategory::Many => forms[2],
+            _                    => forms[3],
+        };
+
+        pattern.replace("{0}", &count.to_string())
+    }
+}
+
+
+/// The future and past forms of a unit’s phrase, each in the plural
+/// categories one, few, many, and other.
+type UnitForms = ([&'static str; 4], [&'static str; 4]);
+
+/// The phrases for one language.
+struct Phrases {
+    language: Language,
+    january: &'static [&'static str],
+    now: &'static str,
+    yesterday: &'static str,
+    tomorrow: &'static str,
+    units: [UnitForms; 7],
+}
+
+/// Forms for languages that only distinguish between one and other.
+macro_rules! two_forms {
+    ($future_one: expr, $future_other: expr, $past_one: expr, $past_other: expr) => {
+        ([ $future_one, $future_other, $future_other, $future_other ],
+         [ $past_one,   $past_other,   $past_other,   $past_other ])
+    };
+}
+
+// This data comes from the CLDR, using the long forms of each unit.
+static LANGUAGES: &[Phrases] = &[
+    Phrases {
+        language: Language::English,
+        january: &[ "january" ],
+        now: "now", yesterday: "yesterday", tomorrow: "tomorrow",
+        units: [
+            two_forms!("in {0} second", "in {0} seconds", "{0} second ago", "{0} seconds ago"),
+            two_forms!("in {0} minute", "in {0} minutes", "{0} minute ago", "{0} minutes ago"),
+            two_forms!("in {0} hour",   "in {0} hours",   "{0} hour ago",   "{0} hours ago"),
+            two_forms!("in {0} day",    "in {0} days",    "{0} day ago",    "{0} days ago"),
+            two_forms!("in {0} week",   "in {0} weeks",   "{0} week ago",   "{0} weeks ago"),
+            two_forms!("in {0} month",  "in {0} months",  "{0} month ago",  "{0} months ago"),
+            two_forms!("in {0} year",   "in {0} years",   "{0} year ago",   "{0} years ago"),
+        ],
+    },
+    Phrases {
+        language: Language::French,
+        january: &[ "janvier" ],
+        now: "maintenant", yesterday: "hier", tomorrow: "demain",
+        units: [
+            two_forms!("dans {0} seconde", "dans {0} secondes", "il y a {0} seconde", "il y a {0} secondes"),
+            two_forms!("dans {0} minute",  "dans {0} minutes",  "il y a {0} minute",  "il y a {0} minutes"),
+            two_forms!("dans {0} heure",   "dans {0} heures",   "il y a {0} heure",   "il y a {0} heures"),
+            two_forms!("dans {0} jour",    "dans {0} jours",    "il y a {0} jour",    "il y a {0} jours"),
+            two_forms!("dans {0} semaine", "dans {0} semaines", "il y a {0} semaine", "il y a {0} semaines"),
+            two_forms!("dans {0} mois",    "dans {0} mois",     "il y a {0} mois",    "il y a {0} mois"),
+            two_forms!("dans {0} an",      "dans {0} ans",      "il y a {0} an",      "il y a {0} ans"),
+        ],
+    },
+    Phrases {
+        language: Language::German,
+        january: &[ "januar" ],
+        now: "jetzt", yesterday: "gestern", tomorrow: "morgen",
+        units: [
+            two_forms!("in {0} Sekunde", "in {0} Sekunden", "vor {0} Sekunde", "vor {0} Sekunden"),
+            two_forms!("in {0} Minute",  "in {0} Minuten",  "vor {0} Minute",  "vor {0} Minuten"),
+            two_forms!("in {0} Stunde",  "in {0} Stunden",  "vor {0} Stunde",  "vor {0} Stunden"),
+            two_forms!("in {0} Tag",     "in {0} Tagen",    "vor {0} Tag",     "vor {0} Tagen"),
+            two_forms!("in {0} Woche",   "in {0} Wochen",   "vor {0} Woche",   "vor {0} Wochen"),
+            two_forms!("in {0} Monat",   "in {0} Monaten",  "vor {0} Monat",   "vor {0} Monaten"),
+            two_forms!("in {0} Jahr",    "in {0} Jahren",   "vor {0} Jahr",    "vor {0} Jahren"),
+        ],
+    },
+    Phrases {
+        language: Language::Japanese,
+        january: &[ "1月", "一月" ],
+        now: "今", yesterday: "昨日", tomorrow: "明日",
+        units: [
+            two_forms!("{0} 秒後",   "{0} 秒後",   "{0} 秒前",   "{0} 秒前"),
+            two_forms!("{0} 分後",   "{0} 分後",   "{0} 分前",   "{0} 分前"),
+            two_forms!("{0} 時間後", "{0} 時間後", "{0} 時間前", "{0} 時間前"),
+            two_forms!("{0} 日後",   "{0} 日後",   "{0} 日前",   "{0} 日前"),
+            two_forms!("{0} 週間後", "{0} 週間後", "{0} 週間前", "{0} 週間前"),
+            two_forms!("{0} か月後", "{0} か月後", "{0} か月前", "{0} か月前"),
+            two_forms!("{0} 年後",   "{0} 年後",   "{0} 年前",   "{0} 年前"),
+        ],
+    },
+    Phrases {
+        language: Language::Polish,
+        january: &[ "styczeń", "stycznia" ],
+        now: "teraz", yesterday: "wczoraj", tomorrow: "jutro",
+        units: [
+            ([ "za {0} sekundę", "za {0} sekundy", "za {0} sekund", "za {0} sekundy" ],
+             [ "{0} sekundę temu", "{0} sekundy temu", "{0} sekund temu", "{0} sekundy temu" ]),
+            ([ "za {0} minutę", "za {0} minuty", "za {0} minut", "za {0} minuty" ],
+             [ "{0} minutę temu", "{0} minuty temu", "{0} minut temu", "{0} minuty temu" ]),
+            ([ "za {0} godzinę", "za {0} godziny", "za {0} godzin", "za {0} godziny" ],
+             [ "{0} godzinę temu", "{0} godziny temu", "{0} godzin temu", "{0} godziny temu" ]),
+            ([ "za {0} dzień", "za {0} dni", "za {0} dni", "za {0} dnia" ],
+             [ "{0} dzień temu", "{0} dni temu", "{0} dni temu", "{0} dnia temu" ]),
+            ([ "za {0} tydzień", "za {0} tygodnie", "za {0} tygodni", "za {0} tygodnia" ],
+             [ "{0} tydzień temu", "{0} tygodnie temu", "{0} tygodni temu", "{0} tygodnia temu" ]),
+            ([ "za {0} miesiąc", "za {0} miesiące", "za {0} miesięcy", "za {0} miesiąca" ],
+             [ "{0} miesiąc temu", "{0} miesiące temu", "{0} miesięcy temu", "{0} miesiąca temu" ]),
+            ([ "za {0} rok", "za {0} lata", "za {0} lat", "za {0} roku" ],
+             [ "{0} rok temu", "{0} lata temu", "{0} lat temu", "{0} roku temu" ]),
+        ],
+    },
+    Phrases {
+        language: Language::Russian,
+        january: &[ "январь", "января" ],
+        now: "сейчас", yesterday: "вчера", tomorrow: "завтра",
+        units: [
+            ([ "через {0} секунду", "через {0} секунды", "через {0} секунд", "через {0} секунды" ],
+             [ "{0} секунду назад", "{0} секунды назад", "{0} секунд назад", "{0} секунды назад" ]),
+            ([ "через {0} минуту", "через {0} минуты", "через {0} минут", "через {0} минуты" ],
+             [ "{0} минуту назад", "{0} минуты назад", "{0} минут назад", "{0} минуты назад" ]),
+            ([ "через {0} час", "через {0} часа", "через {0} часов", "через {0} часа" ],
+             [ "{0} час назад", "{0} часа назад", "{0} часов назад", "{0} часа назад" ]),
+            ([ "через {0} день", "через {0} дня", "через {0} дней", "через {0} дня" ],
+             [ "{0} день назад", "{0} дня назад", "{0} дней назад", "{0} дня назад" ]),
+            ([ "через {0} неделю", "через {0} недели", "через {0} недель", "через {0} недели" ],
+             [ "{0} неделю назад", "{0} недели назад", "{0} недель назад", "{0} недели назад" ]),
+            ([ "через {0} месяц", "через {0} месяца", "через {0} месяцев", "через {0} месяца" ],
+             [ "{0} месяц назад", "{0} месяца назад", "{0} месяцев назад", "{0} месяца назад" ]),
+            ([ "через {0} год", "через {0} года", "через {0} лет", "через {0} года" ],
+             [ "{0} год назад", "{0} года назад", "{0} лет назад", "{0} года назад" ]),
+        ],
+    },
+    Phrases {
+        language: Language::Spanish,
+        january: &[ "enero" ],
+        now: "ahora", yesterday: "ayer", tomorrow: "mañana",
+        units: [
+            two_forms!("dentro de {0} segundo", "dentro de {0} segundos", "hace {0} segundo", "hace {0} segundos"),
+            two_forms!("dentro de {0} minuto",  "dentro de {0} minutos",  "hace {0} minuto",  "hace {0} minutos"),
+            two_forms!("dentro de {0} hora",    "dentro de {0} horas",    "hace {0} hora",    "hace {0} horas"),
+            two_forms!("dentro de {0} día",     "dentro de {0} días",     "hace {0} día",     "hace {0} días"),
+            two_forms!("dentro de {0} semana",  "dentro de {0} semanas",  "hace {0} semana",  "hace {0} semanas"),
+            two_forms!("dentro de {0} mes",     "dentro de {0} meses",    "hace {0} mes",     "hace {0} meses"),
+            two_forms!("dentro de {0} año",     "dentro de {0} años",     "hace {0} año",     "hace {0} años"),
+        ],
+    },
+];
+
+
+#[cfg(test)]
+mod test {
+    use super::{Language, PluralCategory, Thresholds, RelativeUnit};
+
+    #[test]
+    fn polish_plurals() {
+        assert_eq!(Language::Polish.plural_category(1), PluralCategory::One);
+        assert_eq!(Language::Polish.plural_category(3), PluralCategory::Few);
+        assert_eq!(Language::Polish.plural_category(5), PluralCategory::Many);
+        assert_eq!(Language::Polish.plural_category(21), PluralCategory::Many);
+        assert_eq!(Language::Polish.plural_category(24), PluralCategory::Few);
+    }
+
+    #[test]
+    fn french_zero_is_singular() {
+        assert_eq!(Language::French.plural_category(0), PluralCategory::One);
+    }
+
+    #[test]
+    fn thresholds() {
+        let thresholds = Thresholds::default();
+        assert_eq!(thresholds.select(0), None);
+        assert_eq!(thresholds.select(44), Some((RelativeUnit::Second, 44)));
+        assert_eq!(thresholds.select(45), Some((RelativeUnit::Minute, 1)));
+        assert_eq!(thresholds.select(-22 * 3600), Some((RelativeUnit::Day, 1)));
+        assert_eq!(thresholds.select(400 * 86400), Some((RelativeUnit::Year, 1)));
+    }
+}
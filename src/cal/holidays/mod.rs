@@ -0,0 +1,361 @@
+//! Public holidays for some common regions, and a text format for
+//! defining more of them.
+//!
+//! Holidays are grouped into named sets, each of which becomes a
+//! `HolidaySet` from the `business` module. The built-in sets are:
+//!
+//! - `us`: United States federal holidays;
+//! - `gb-eng`, `gb-sct`, and `gb-nir`: bank holidays in England and
+//!   Wales, Scotland, and Northern Ireland;
+//! - `de`: public holidays throughout Germany, and `de-bw`, `de-by`,
+//!   `de-be`, `de-bb`, `de-hb`, `de-hh`, `de-he`, `de-mv`, `de-ni`,
+//!   `de-nw`, `de-rp`, `de-sl`, `de-sn`, `de-st`, `de-sh`, and `de-th` for
+//!   each of its states, including holidays only observed in parts of a
+//!   state when they’re observed in most of it;
+//! - `fr`: public holidays in France;
+//! - `jp`: national holidays in Japan.
+//!
+//! The built-in sets are meant to be accurate from the year 2000
+//! onwards, including holidays that were only held once.
+//!
+//! ### The text format
+//!
+//! The built-in sets are written in a text format that’s read when they
+//! are loaded, and more sets can be read from text at runtime, so a
+//! company can add its own holidays without recompiling anything.
+//!
+//! Each line is one of the following, and blank lines and lines starting
+//! with `#` are ignored:
+//!
+//! - `[name]` starts a set with the given name. If a set with that name
+//!   has already been read, the lines that follow add to it.
+//! - `include: name` adds every holiday in a set that’s already been
+//!   read to the current set.
+//! - `observed: rule` says what happens when the holidays on the lines
+//!   after it fall on a weekend: one of `actual` (the default), `next
+//!   monday`, `nearest weekday`, `next free weekday`, or `sunday to next
+//!   free day`. These match the variants of `Observance`.
+//! - `bridge: name` makes any day between two holidays in the set a
+//!   holiday with the given name.
+//! - `date: name` adds a holiday with the given name.
+//!
+//! The date of a holiday can be written as:
+//!
+//! - a day of a month, such as `December 25`;
+//! - an ISO 8601 date, such as `2022-09-19`, for a holiday that only
+//!   happens in that year;
+//! - an occurrence of a weekday in a month, such as `fourth Thursday in
+//!   November` or `last Monday in May`, counting with `first` to `fifth`,
+//!   or `last`;
+//! - the first occurrence of a weekday on or after a day of a month, such
+//!   as `Wednesday on or after November 16`;
+//! - a number of days before or after Easter Sunday, such as `Easter -2`
+//!   or `Orthodox Easter +1`;
+//! - `March equinox` or `September equinox`, in Japan Standard Time.
+//!
+//! Names of months and weekdays are in English, and case doesn’t matter.
+//! The date can be followed by the years the holiday happens in, between
+//! square brackets: `[2021-]` means from 2021 onwards, `[-2018]` means
+//! up to and including 2018, `[2003-2019]` means between those two
+//! years, and several of these can be separated by commas.
+//!
+//! ### Examples
+//!
+//! ```
+//! use datetime::{LocalDate, Month, Year};
+//! use datetime::holidays::HolidaySets;
+//!
+//! let mut sets = HolidaySets::builtin();
+//! sets.load("
+//!     [acme]
+//!     include: us
+//!     Friday on or after November 23: Day after Thanksgiving
+//!     December 24: Christmas Eve
+//! ").unwrap();
+//!
+//! let holidays = sets.get("acme").unwrap().holidays(Year(2026));
+//! assert_eq!(holidays.len(), 13);
+//! assert_eq!(holidays[10].name, "Day after Thanksgiving");
+//! assert_eq!(holidays[10].date, LocalDate::ymd(2026, Month::November, 27).unwrap());
+//! ```
+
+use std::collections::BTreeMap;
+use std::error::Error as ErrorTrait;
+use std::fmt;
+use std::str::FromStr;
+
+use cal::business::{HolidayDate, HolidayRule, HolidaySet, Observance};
+use cal::datetime::{LocalDate, Month, Weekday};
+use cal::DatePiece;
+
+
+/// The text of the built-in holiday sets.
+const BUILTIN: &[&str] = &[
+    include_str!("us.txt"),
+    include_str!("gb.txt"),
+    include_str!("de.txt"),
+    include_str!("fr.txt"),
+    include_str!("jp.txt"),
+];
+
+const MONTH_NAMES: &[&str] = &[ "january", "february", "march", "april", "may", "june", "july", "august", "september", "october", "november", "december" ];
+const WEEKDAY_NAMES: &[&str] = &[ "sunday", "monday", "tuesday", "wednesday", "thursday", "friday", "saturday" ];
+const ORDINALS: &[&str] = &[ "first", "second", "third", "fourth", "fifth" ];
+
+
+/// A collection of named holiday sets, read from the text format.
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct HolidaySets {
+    sets: BTreeMap<String, HolidaySet>,
+}
+
+impl HolidaySets {
+
+    /// Creates a new collection with no sets in it.
+    pub fn new() -> HolidaySets {
+        HolidaySets::default()
+    }
+
+    /// Creates a new collection containing the built-in sets.
+    pub fn builtin() -> HolidaySets {
+        let mut sets = HolidaySets::new();
+        for text in BUILTIN {
+            sets.load(text).expect("built-in holiday data is invalid");
+        }
+        sets
+    }
+
+    /// Reads sets of holidays from the given text, adding them to this
+    /// collection. Sets in the text can include the sets already in it.
+    ///
+    /// If there’s an error, the collection is left as it was.
+    pub fn load(&mut self, text: &str) -> Result<(), HolidayError> {
+        let mut sets = self.sets.clone();
+        let mut current: Option<(String, Observance)> = None;
+
+        for (index, line) in text.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.trim();
+            let invalid = || HolidayError::InvalidLine { line: line_number, text: line.to_owned() };
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if line.starts_with('[') && line.ends_with(']') {
+                let name = line[1 .. line.len() - 1].trim();
+                if name.is_empty() {
+                    return Err(invalid());
+                }
+
+                let _ = sets.entry(name.to_owned()).or_default();
+                current = Some((name.to_owned(), Observance::Actual));
+                continue;
+            }
+
+            let (name, observance) = match current {
+                Some((ref name, ref mut observance)) => (name, observance),
+                None => return Err(HolidayError::NoSet { line: line_number }),
+            };
+
+            let colon = line.find(':').ok_or_else(invalid)?;
+            let (key, value) = (line[.. colon].trim(), line[colon + 1 ..].trim());
+            if value.is_empty() {
+                return Err(invalid());
+            }
+
+            match &*key.to_lowercase() {
+                "include" => {
+                    let other = sets.get(value).cloned().ok_or_else(|| HolidayError::UnknownSet { line: line_number, name: value.to_owned() })?;
+                    sets.get_mut(name).unwrap().extend(&other);
+                },
+                "observed" => {
+                    *observance = parse_observance(value).ok_or_else(invalid)?;
+                },
+                "bridge" => {
+                    sets.get_mut(name).unwrap().bridge = Some(value.to_owned());
+                },
+                _ => {
+                    let rules = parse_rules(key, value, *observance).ok_or_else(invalid)?;
+                    sets.get_mut(name).unwrap().rules.extend(rules);
+                },
+            }
+        }
+
+        self.sets = sets;
+        Ok(())
+    }
+
+    /// Returns the set with the given name, if there is one.
+    pub fn get(&self, name: &str) -> Option<&HolidaySet> {
+        self.sets.get(name)
+    }
+
+    /// Returns an iterator over the names of the sets in this collection,
+    /// in alphabetical order.
+    pub fn names(&self) -> impl Iterator<Item=&str> + '_ {
+        self.sets.keys().map(|name| &name[..])
+    }
+}
+
+impl FromStr for HolidaySets {
+    type Err = HolidayError;
+
+    fn from_str(input: &str) -> Result<HolidaySets, HolidayError> {
+        let mut sets = HolidaySets::new();
+        sets.load(input)?;
+        Ok(sets)
+    }
+}
+
+/// Returns the built-in holiday set with the given name, if there is one.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::{LocalDate, Month, Year};
+/// use datetime::holidays;
+///
+/// let england = holidays::builtin("gb-eng").unwrap();
+/// let holidays = england.holidays(Year(2022));
+/// let christmas = holidays.iter().find(|h| h.name == "Christmas Day").unwrap();
+///
+/// // Christmas Day 2022 was a Sunday, and Boxing Day was on the Monday.
+/// assert_eq!(christmas.observed, LocalDate::ymd(2022, Month::December, 27).unwrap());
+/// ```
+pub fn builtin(name: &str) -> Option<HolidaySet> {
+    HolidaySets::builtin().get(name).cloned()
+}
+
+
+/// Parses the value of an `observed` line.
+fn parse_observance(text: &str) -> Option<Observance> {
+    let words: Vec<String> = text.split_whitespace().map(str::to_lowercase).collect();
+
+    Some(match &*words.join(" ") {
+        "actual"                    => Observance::Actual,
+        "next monday"               => Observance::NextMonday,
+        "nearest weekday"           => Observance::NearestWeekday,
+        "next free weekday"         => Observance::NextFreeWeekday,
+        "sunday to next free day"   => Observance::SundayToNextFreeDay,
+        _                           => return None,
+    })
+}
+
+/// Parses a holiday line into rules, one for each span of years.
+fn parse_rules(when: &str, name: &str, observance: Observance) -> Option<Vec<HolidayRule>> {
+    let (when, years) = match when.find('[') {
+        Some(bracket) if when.ends_with(']') => (when[.. bracket].trim(), Some(&when[bracket + 1 .. when.len() - 1])),
+        Some(_) => return None,
+        None    => (when, None),
+    };
+
+    // A single date is a fixed holiday that only happens in its year.
+    if let Ok(date) = when.parse::<LocalDate>() {
+        if years.is_some() {
+            return None;
+        }
+
+        let rule = HolidayRule::fixed(name, date.month(), date.day()).observed(observance);
+        return Some(vec![ rule.since(date.year()).until(date.year()) ]);
+    }
+
+    let rule = HolidayRule { name: name.to_owned(), date: parse_date(when)?, observance, first_year: None, last_year: None };
+
+    match years {
+        None => Some(vec![ rule ]),
+        Some(years) => years.split(',').map(|span| {
+            let (first, last) = parse_years(span.trim())?;
+            Some(HolidayRule { first_year: first, last_year: last, ..rule.clone() })
+        }).collect(),
+    }
+}
+
+/// Parses a span of years, such as `2003-2019`, `2021-`, or `2022`.
+fn parse_years(text: &str) -> Option<(Option<i64>, Option<i64>)> {
+    let year = |text: &str| if text.is_empty() { Some(None) } else { text.parse().ok().map(Some) };
+
+    match text.find('-') {
+        Some(dash) => {
+            let (first, last) = (year(text[.. dash].trim())?, year(text[dash + 1 ..].trim())?);
+            if first.is_none() && last.is_none() { None } else { Some((first, last)) }
+        },
+        None => {
+            let only = text.parse().ok()?;
+            Some((Some(only), Some(only)))
+        },
+    }
+}
+
+/// Parses the date of a holiday.
+fn parse_date(text: &str) -> Option<HolidayDate> {
+    let words: Vec<String> = text.split_whitespace().map(str::to_lowercase).collect();
+    let words: Vec<&str> = words.iter().map(|w| &w[..]).collect();
+
+    Some(match words[..] {
+        [ "easter" ]                        => HolidayDate::Easter { offset: 0 },
+        [ "easter", offset ]                => HolidayDate::Easter { offset: parse_offset(offset)? },
+        [ "orthodox", "easter" ]            => HolidayDate::OrthodoxEaster { offset: 0 },
+        [ "orthodox", "easter", offset ]    => HolidayDate::OrthodoxEaster { offset: parse_offset(offset)? },
+        [ "march", "equinox" ]              => HolidayDate::MarchEquinox,
+        [ "september", "equinox" ]          => HolidayDate::SeptemberEquinox,
+        [ month, day ] => {
+            HolidayDate::Fixed { month: parse_month(month)?, day: day.parse().ok()? }
+        },
+        [ nth, weekday, "in", month ] => {
+            let n = if nth == "last" { -1 } else { ORDINALS.iter().position(|o| *o == nth)? as i8 + 1 };
+            HolidayDate::NthWeekday { month: parse_month(month)?, n, weekday: parse_weekday(weekday)? }
+        },
+        [ weekday, "on", "or", "after", month, day ] => {
+            HolidayDate::WeekdayOnOrAfter { month: parse_month(month)?, day: day.parse().ok()?, weekday: parse_weekday(weekday)? }
+        },
+        _ => return None,
+    })
+}
+
+/// Parses a number of days with a leading `+` or `-`.
+fn parse_offset(text: &str) -> Option<i64> {
+    if text.starts_with('+') || text.starts_with('-') {
+        text.trim_start_matches('+').parse().ok()
+    }
+    else {
+        None
+    }
+}
+
+fn parse_month(name: &str) -> Option<Month> {
+    let index = MONTH_NAMES.iter().position(|m| *m == name)?;
+    Month::from_zero(index as i8).ok()
+}
+
+fn parse_weekday(name: &str) -> Option<Weekday> {
+    let index = WEEKDAY_NAMES.iter().position(|w| *w == name)?;
+    Weekday::from_zero(index as i8).ok()
+}
+
+
+/// An error that occurs when reading holiday sets from text.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum HolidayError {
+
+    /// A line that isn’t a set name came before the first set name.
+    NoSet { line: usize },
+
+    /// An `include` line named a set that hasn’t been read.
+    UnknownSet { line: usize, name: String },
+
+    /// A line couldn’t be understood.
+    InvalidLine { line: usize, text: String },
+}
+
+impl fmt::Display for HolidayError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            HolidayError::NoSet { line }                   => write!(f, "line {}: holiday comes before any set name", line),
+            HolidayError::UnknownSet { line, ref name }    => write!(f, "line {}: unknown holiday set {:?}", line, name),
+            HolidayError::InvalidLine { line, ref text }   => write!(f, "line {}: invalid holiday line {:?}", line, text),
+        }
+    }
+}
+
+impl ErrorTrait for HolidayError {}
@@ -0,0 +1,624 @@
+//! Half-open intervals of dates, datetimes, and instants, sets of them,
+//! and the time intervals and repeating intervals of ISO 8601.
+
+use std::iter::{FromIterator, FusedIterator};
+use std::slice::Iter as SliceIter;
+
+use cal::datetime::{Error, LocalDate, LocalDateTime, LocalTime, MonthOverflow, TimeUnit};
+use cal::offset::OffsetDateTime;
+use cal::period::Period;
+use duration::Duration;
+use instant::Instant;
+
+
+/// An **interval** is a span from a start value up to but not including
+/// an end value, such as the nights of a booking or the hours of a shift.
+///
+/// Because the end is never part of the interval, two intervals where one
+/// ends at the same value the other starts at don’t overlap, and can be
+/// joined without a gap.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::{LocalDate, Month};
+/// use datetime::interval::Interval;
+///
+/// let date = |d| LocalDate::ymd(2017, Month::June, d).unwrap();
+///
+/// let first  = Interval::new(date(1), date(5)).unwrap();
+/// let second = Interval::new(date(3), date(8)).unwrap();
+///
+/// assert!(first.overlaps(&second));
+/// assert_eq!(first.intersection(&second), Some(Interval::new(date(3), date(5)).unwrap()));
+/// assert_eq!(first.union(&second), Some(Interval::new(date(1), date(8)).unwrap()));
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct Interval<T> {
+    start: T,
+    end: T,
+}
+
+impl<T: Ord + Copy> Interval<T> {
+
+    /// Creates a new interval from the start value up to but not including
+    /// the end value, returning an error if the end is before the start.
+    /// An interval where the start and end are the same is empty.
+    pub fn new(start: T, end: T) -> Result<Interval<T>, Error> {
+        if end < start {
+            Err(Error::OutOfRange)
+        }
+        else {
+            Ok(Interval { start, end })
+        }
+    }
+
+    /// Returns the first value in this interval.
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    /// Returns the value just after this interval.
+    pub fn end(&self) -> T {
+        self.end
+    }
+
+    /// Returns whether this interval has nothing in it.
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns whether the given value is in this interval.
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Returns whether every value in the other interval is also in this
+    /// one.
+    pub fn encloses(&self, other: &Interval<T>) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// Returns whether this interval and the other one have any values in
+    /// common.
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+
+    /// Returns whether this interval and the other one overlap, or one
+    /// ends where the other starts.
+    fn touches(&self, other: &Interval<T>) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /// Returns the values this interval and the other one have in common,
+    /// or `None` if they don’t overlap.
+    pub fn intersection(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        if self.overlaps(other) {
+            Some(Interval { start: self.start.max(other.start), end: self.end.min(other.end) })
+        }
+        else {
+            None
+        }
+    }
+
+    /// Returns the interval covering the values in both this interval and
+    /// the other one, or `None` if there would be a gap between them.
+    pub fn union(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        if self.is_empty() {
+            Some(*other)
+        }
+        else if other.is_empty() {
+            Some(*self)
+        }
+        else if self.touches(other) {
+            Some(Interval { start: self.start.min(other.start), end: self.end.max(other.end) })
+        }
+        else {
+            None
+        }
+    }
+
+    /// Returns the interval between this interval and the other one, or
+    /// `None` if they overlap or one ends where the other starts.
+    pub fn gap(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        if self.end < other.start {
+            Some(Interval { start: self.end, end: other.start })
+        }
+        else if other.end < self.start {
+            Some(Interval { start: other.end, end: self.start })
+        }
+        else {
+            None
+        }
+    }
+
+    /// Splits this interval into pieces, using the given function to find
+    /// where the piece starting at a value should end.
+    fn split_with<F: Fn(T) -> T>(&self, next: F) -> Vec<Interval<T>> {
+        let mut pieces = Vec::new();
+        let mut start = self.start;
+
+        while start < self.end {
+            let end = next(start).min(self.end);
+            pieces.push(Interval { start, end });
+            start = end;
+        }
+
+        pieces
+    }
+}
+
+impl Interval<LocalDate> {
+
+    /// Returns the number of days in this interval.
+    pub fn days(&self) -> i64 {
+        self.start.days_until(self.end)
+    }
+
+    /// Splits this interval at every multiple of the given unit, such as
+    /// at the start of every month. Units shorter than a day split the
+    /// interval into single days.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if the unit’s count isn’t positive.
+    pub fn split(&self, unit: TimeUnit) -> Vec<Interval<LocalDate>> {
+        let unit = if unit.is_date_based() { unit } else { TimeUnit::Days(1) };
+        self.split_with(|date| LocalDateTime::new(date, LocalTime::midnight()).next_multiple(unit).date())
+    }
+}
+
+impl Interval<LocalDateTime> {
+
+    /// Returns the length of this interval.
+    pub fn duration(&self) -> Duration {
+        milliseconds_between(self.start.to_instant(), self.end.to_instant())
+    }
+
+    /// Splits this interval at every multiple of the given unit, such as a
+    /// shift that crosses midnight being split into one piece per day.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if the unit’s count isn’t positive.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, LocalDateTime, LocalTime, Month, TimeUnit};
+    /// use datetime::interval::Interval;
+    ///
+    /// let start = LocalDateTime::new(LocalDate::ymd(2017, Month::June, 1).unwrap(), LocalTime::hm(22, 0).unwrap());
+    /// let end   = LocalDateTime::new(LocalDate::ymd(2017, Month::June, 2).unwrap(), LocalTime::hm(6, 0).unwrap());
+    ///
+    /// let shift = Interval::new(start, end).unwrap();
+    /// let pieces = shift.split(TimeUnit::Days(1));
+    ///
+    /// assert_eq!(pieces.len(), 2);
+    /// assert_eq!(pieces[0].end(), LocalDateTime::new(LocalDate::ymd(2017, Month::June, 2).unwrap(), LocalTime::midnight()));
+    /// ```
+    pub fn split(&self, unit: TimeUnit) -> Vec<Interval<LocalDateTime>> {
+        self.split_with(|datetime| datetime.next_multiple(unit))
+    }
+}
+
+impl Interval<Instant> {
+
+    /// Returns the length of this interval.
+    pub fn duration(&self) -> Duration {
+        milliseconds_between(self.start, self.end)
+    }
+
+    /// Splits this interval at every multiple of the given unit, with
+    /// days, months, and years worked out in UTC.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if the unit’s count isn’t positive.
+    pub fn split(&self, unit: TimeUnit) -> Vec<Interval<Instant>> {
+        self.split_with(|instant| LocalDateTime::from_instant(instant).next_multiple(unit).to_instant())
+    }
+}
+
+fn milliseconds_between(start: Instant, end: Instant) -> Duration {
+    let ms = |i: Instant| i.seconds() * 1000 + i64::from(i.milliseconds());
+    let length = ms(end) - ms(start);
+    Duration::of_ms(length.div_euclid(1000), length.rem_euclid(1000) as i16)
+}
+
+
+/// An **interval set** is a set of values made up of intervals, which are
+/// merged together whenever they overlap or touch.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::{LocalDate, LocalDateTime, LocalTime, Month};
+/// use datetime::interval::{Interval, IntervalSet};
+///
+/// let at = |h, m| LocalDateTime::new(LocalDate::ymd(2017, Month::June, 1).unwrap(), LocalTime::hm(h, m).unwrap());
+/// let span = |a, b| Interval::new(a, b).unwrap();
+///
+/// let mut busy = IntervalSet::new();
+/// busy.insert(span(at(10, 0), at(11, 0)));
+/// busy.insert(span(at(10, 30), at(12, 0)));
+/// busy.insert(span(at(14, 0), at(15, 0)));
+///
+/// let mut free: IntervalSet<_> = vec![ span(at(9, 0), at(17, 0)) ].into_iter().collect();
+/// free.remove_set(&busy);
+///
+/// let free: Vec<_> = free.iter().cloned().collect();
+/// assert_eq!(free, vec![ span(at(9, 0), at(10, 0)), span(at(12, 0), at(14, 0)), span(at(15, 0), at(17, 0)) ]);
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct IntervalSet<T> {
+
+    /// The intervals in this set, which are never empty, and are kept in
+    /// order with a gap between each one.
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Ord + Copy> IntervalSet<T> {
+
+    /// Creates a new empty set.
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet { intervals: Vec::new() }
+    }
+
+    /// Returns whether this set has nothing in it.
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Returns whether the given value is in this set.
+    pub fn contains(&self, value: T) -> bool {
+        self.intervals.iter().any(|i| i.contains(value))
+    }
+
+    /// Returns an iterator over the intervals in this set, in order.
+    pub fn iter(&self) -> SliceIter<'_, Interval<T>> {
+        self.intervals.iter()
+    }
+
+    /// Adds every value in the given interval to this set, merging it
+    /// with any intervals it overlaps or touches.
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+
+        // The intervals are in order with gaps between them, so widening
+        // the new interval can only make it touch intervals that haven’t
+        // been checked yet.
+        let mut merged = interval;
+        self.intervals.retain(|i| {
+            if i.touches(&merged) {
+                merged = i.union(&merged).unwrap();
+                false
+            }
+            else {
+                true
+            }
+        });
+
+        let index = self.intervals.iter().position(|i| i.start > merged.start).unwrap_or(self.intervals.len());
+        self.intervals.insert(index, merged);
+    }
+
+    /// Removes every value in the given interval from this set, splitting
+    /// any interval that has values either side of it.
+    pub fn remove(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+
+        let mut remaining = Vec::with_capacity(self.intervals.len() + 1);
+        for i in &self.intervals {
+            if !i.overlaps(&interval) {
+                remaining.push(*i);
+                continue;
+            }
+
+            if i.start < interval.start {
+                remaining.push(Interval { start: i.start, end: interval.start });
+            }
+            if interval.end < i.end {
+                remaining.push(Interval { start: interval.end, end: i.end });
+            }
+        }
+
+        self.intervals = remaining;
+    }
+
+    /// Adds every value in the other set to this one.
+    pub fn insert_set(&mut self, other: &IntervalSet<T>) {
+        for interval in &other.intervals {
+            self.insert(*interval);
+        }
+    }
+
+    /// Removes every value in the other set from this one.
+    pub fn remove_set(&mut self, other: &IntervalSet<T>) {
+        for interval in &other.intervals {
+            self.remove(*interval);
+        }
+    }
+
+    /// Returns a new set with the values in both this set and the other
+    /// one.
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut set = self.clone();
+        set.insert_set(other);
+        set
+    }
+
+    /// Returns a new set with the values that are in this set, but not in
+    /// the other one.
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut set = self.clone();
+        set.remove_set(other);
+        set
+    }
+
+    /// Returns a new set with the values that are in this set and in the
+    /// other one.
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        self.intervals.iter()
+            .flat_map(|a| other.intervals.iter().filter_map(move |b| a.intersection(b)))
+            .collect()
+    }
+
+    /// Returns the intervals between the intervals in this set.
+    pub fn gaps(&self) -> IntervalSet<T> {
+        let intervals = self.intervals.windows(2)
+                            .map(|pair| Interval { start: pair[0].end, end: pair[1].start })
+                            .collect();
+
+        IntervalSet { intervals }
+    }
+
+    /// Returns the interval from the start of the first interval in this
+    /// set to the end of the last one, or `None` if the set is empty.
+    pub fn span(&self) -> Option<Interval<T>> {
+        match (self.intervals.first(), self.intervals.last()) {
+            (Some(first), Some(last)) => Some(Interval { start: first.start, end: last.end }),
+            _                         => None,
+        }
+    }
+}
+
+impl<T: Ord + Copy> Default for IntervalSet<T> {
+    fn default() -> IntervalSet<T> {
+        IntervalSet::new()
+    }
+}
+
+impl<T: Ord + Copy> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item=Interval<T>>>(iter: I) -> IntervalSet<T> {
+        let mut set = IntervalSet::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+impl<'a, T: Ord + Copy> IntoIterator for &'a IntervalSet<T> {
+    type Item = &'a Interval<T>;
+    type IntoIter = SliceIter<'a, Interval<T>>;
+
+    fn into_iter(self) -> SliceIter<'a, Interval<T>> {
+        self.intervals.iter()
+    }
+}
+
+
+/// An ISO 8601 **time interval**, written down as its two ends, as one end
+/// and a duration, or as just a duration.
+///
+/// The ends are datetimes with UTC offsets, and the durations are periods,
+/// which get added in the local time of the end they’re measured from. So
+/// a one-month interval starting on the 31st of January ends on the last
+/// day of February.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::{Instant, ISO};
+/// use datetime::interval::IsoInterval;
+///
+/// let interval: IsoInterval = "2007-03-01T13:00:00+01:00/P1DT2H".parse().unwrap();
+/// let span = interval.to_interval().unwrap();
+///
+/// assert_eq!(span.start(), Instant::at(1_172_750_400));
+/// assert_eq!(span.end(), Instant::at(1_172_750_400 + 26 * 3600));
+/// assert_eq!(interval.iso().to_string(), "2007-03-01T13:00:00.000+01/P1DT2H");
+/// ```
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum IsoInterval {
+
+    /// An interval between two datetimes, such as
+    /// `2007-03-01T13:00:00Z/2008-05-11T15:30:00Z`.
+    StartEnd(OffsetDateTime, OffsetDateTime),
+
+    /// An interval lasting a period from a datetime, such as
+    /// `2007-03-01T13:00:00Z/P1Y2M10DT2H30M`.
+    StartPeriod(OffsetDateTime, Period),
+
+    /// An interval lasting a period up to a datetime, such as
+    /// `P1Y2M10DT2H30M/2008-05-11T15:30:00Z`.
+    PeriodEnd(Period, OffsetDateTime),
+
+    /// A period of time that isn’t tied to any datetime, such as
+    /// `P1Y2M10DT2H30M`.
+    Period(Period),
+}
+
+impl IsoInterval {
+
+    /// Returns the datetime this interval starts at, or `None` if it’s
+    /// only a period, or the start would be out of range.
+    pub fn start(&self) -> Option<OffsetDateTime> {
+        match *self {
+            IsoInterval::StartEnd(start, _)
+            | IsoInterval::StartPeriod(start, _)  => Some(start),
+            IsoInterval::PeriodEnd(period, end)   => shift(end, -period),
+            IsoInterval::Period(_)                => None,
+        }
+    }
+
+    /// Returns the datetime this interval ends at, or `None` if it’s only
+    /// a period, or the end would be out of range.
+    pub fn end(&self) -> Option<OffsetDateTime> {
+        match *self {
+            IsoInterval::StartEnd(_, end)
+            | IsoInterval::PeriodEnd(_, end)       => Some(end),
+            IsoInterval::StartPeriod(start, period) => shift(start, period),
+            IsoInterval::Period(_)                  => None,
+        }
+    }
+
+    /// Returns the span of time this interval covers, or `None` if it’s
+    /// only a period, or it ends before it starts.
+    pub fn to_interval(&self) -> Option<Interval<Instant>> {
+        Interval::new(self.start()?.to_instant(), self.end()?.to_instant()).ok()
+    }
+
+    /// Returns the `n`th interval in a run of these intervals following on
+    /// from each other, counting away from whichever end is given, or
+    /// `None` if it would be out of range.
+    fn nth_occurrence(&self, n: i64) -> Option<Interval<Instant>> {
+        let (start, end) = match *self {
+            IsoInterval::StartEnd(start, end) => {
+                let start = milliseconds(start.to_instant());
+                let length = milliseconds(end.to_instant()) - start;
+                let first = length.checked_mul(n)?.checked_add(start)?;
+                (instant(first), instant(first.checked_add(length)?))
+            },
+            IsoInterval::StartPeriod(start, period) => {
+                (shift(start, times(period, n)?)?.to_instant(), shift(start, times(period, n.checked_add(1)?)?)?.to_instant())
+            },
+            IsoInterval::PeriodEnd(period, end) => {
+                (shift(end, -times(period, n.checked_add(1)?)?)?.to_instant(), shift(end, -times(period, n)?)?.to_instant())
+            },
+            IsoInterval::Period(_) => return None,
+        };
+
+        Interval::new(start, end).ok()
+    }
+}
+
+/// Adds a period to the local time of a datetime, keeping its offset.
+fn shift(datetime: OffsetDateTime, period: Period) -> Option<OffsetDateTime> {
+    let local = datetime.local.add_period(period, MonthOverflow::Clamp).ok()?;
+    Some(OffsetDateTime { local, offset: datetime.offset })
+}
+
+/// Multiplies every field of a period, or returns `None` if one overflows.
+fn times(period: Period, n: i64) -> Option<Period> {
+    Some(Period {
+        years:        period.years.checked_mul(n)?,
+        months:       period.months.checked_mul(n)?,
+        weeks:        period.weeks.checked_mul(n)?,
+        days:         period.days.checked_mul(n)?,
+        hours:        period.hours.checked_mul(n)?,
+        minutes:      period.minutes.checked_mul(n)?,
+        seconds:      period.seconds.checked_mul(n)?,
+        milliseconds: period.milliseconds.checked_mul(n)?,
+    })
+}
+
+fn milliseconds(instant: Instant) -> i64 {
+    instant.seconds() * 1000 + i64::from(instant.milliseconds())
+}
+
+fn instant(milliseconds: i64) -> Instant {
+    Instant::at_ms(milliseconds.div_euclid(1000), milliseconds.rem_euclid(1000) as i16)
+}
+
+
+/// An ISO 8601 **repeating interval**, such as
+/// `R5/2008-03-01T13:00:00Z/P1Y2M10DT2H30M`, which is an interval that
+/// happens again straight after it ends, either a number of times or
+/// forever.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::{LocalDate, LocalDateTime, LocalTime, Month};
+/// use datetime::interval::RepeatingInterval;
+///
+/// let monthly: RepeatingInterval = "R3/2017-01-31T09:00:00Z/P1M".parse().unwrap();
+/// let starts: Vec<_> = monthly.occurrences().unwrap()
+///                             .map(|i| LocalDateTime::from_instant(i.start()).date())
+///                             .collect();
+///
+/// assert_eq!(starts, vec![ LocalDate::ymd(2017, Month::January, 31).unwrap(),
+///                          LocalDate::ymd(2017, Month::February, 28).unwrap(),
+///                          LocalDate::ymd(2017, Month::March, 31).unwrap() ]);
+/// ```
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct RepeatingInterval {
+
+    /// The number of times the interval happens, or `None` if it keeps
+    /// happening forever.
+    pub repetitions: Option<u64>,
+
+    /// The interval that gets repeated.
+    pub interval: IsoInterval,
+}
+
+impl RepeatingInterval {
+
+    /// Returns an iterator over each time the interval happens, or `None`
+    /// if it’s only a period, or it ends before it starts.
+    ///
+    /// Occurrences count away from whichever end of the interval is given.
+    /// When it has a start, they run forwards in time, with each period
+    /// measured from that start, so monthly intervals starting on the
+    /// 31st go back to the 31st whenever a month is long enough. When it
+    /// only has an end, they run backwards in time from that end, latest
+    /// first.
+    pub fn occurrences(&self) -> Option<Occurrences> {
+        let _ = self.interval.to_interval()?;
+        Some(Occurrences { interval: self.interval, index: 0, remaining: self.repetitions })
+    }
+}
+
+
+/// An iterator over the occurrences of a `RepeatingInterval`.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Occurrences {
+    interval: IsoInterval,
+    index: i64,
+    remaining: Option<u64>,
+}
+
+impl Iterator for Occurrences {
+    type Item = Interval<Instant>;
+
+    fn next(&mut self) -> Option<Interval<Instant>> {
+        if self.remaining == Some(0) {
+            return None;
+        }
+
+        match self.interval.nth_occurrence(self.index) {
+            Some(occurrence) => {
+                self.index += 1;
+                self.remaining = self.remaining.map(|r| r - 1);
+                Some(occurrence)
+            },
+            None => {
+                self.remaining = Some(0);
+                None
+            },
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.remaining.map(|r| r as usize))
+    }
+}
+
+impl FusedIterator for Occurrences {}
@@ -0,0 +1,296 @@
+//! Dates in the proleptic Julian calendar, and in calendars that switch
+//! from the Julian calendar to the Gregorian one on a given date.
+//!
+//! The Julian calendar has a leap year every four years without
+//! exception, so it drifts away from the Gregorian calendar by three days
+//! every four hundred years. Different countries switched from one to the
+//! other at different times, skipping however many days they’d drifted by:
+//! most Catholic countries went from the 4th to the 15th of October, 1582,
+//! whereas Britain and its colonies went from the 2nd to the 14th of
+//! September, 1752.
+//!
+//! Years are numbered astronomically, so the year before 1 CE is year 0.
+
+use cal::DatePiece;
+use cal::datetime::{Error, LocalDate, Month, Weekday, Year};
+use cal::era::JapaneseYear;
+
+
+/// Returns whether the given year is a leap year in the Julian calendar,
+/// which is whenever it’s divisible by four.
+pub fn is_leap_year(year: i64) -> bool {
+    year.rem_euclid(4) == 0
+}
+
+/// The Julian Day Number of the 1st of March, year 0, in the Julian
+/// calendar, minus one. Counting years from March puts the leap day at
+/// the end of the year, which keeps the arithmetic simple.
+const MARCH_EPOCH_JDN: i64 = 1_721_117;
+
+/// Number of days in four Julian years.
+const DAYS_IN_4Y: i64 = 365 * 4 + 1;
+
+
+/// A **Julian date** is a day in the proleptic Julian calendar, which is
+/// the Julian calendar extended back before it was introduced, and forward
+/// after it was replaced.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::{LocalDate, Month, DatePiece};
+/// use datetime::julian::JulianDate;
+///
+/// // The day Britain switched to the Gregorian calendar, which it would
+/// // have called the 3rd of September.
+/// let date = LocalDate::ymd(1752, Month::September, 14).unwrap();
+/// let julian = JulianDate::from_local_date(date);
+///
+/// assert_eq!(julian, JulianDate::ymd(1752, Month::September, 3).unwrap());
+/// assert_eq!(julian.to_local_date(), date);
+/// assert_eq!(julian.day(), 3);
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+pub struct JulianDate {
+    year: i64,
+    month: Month,
+    day: i8,
+}
+
+impl JulianDate {
+
+    /// Creates a new Julian date from the given year, month, and day
+    /// fields, returning an error if the day doesn’t exist in the Julian
+    /// calendar.
+    pub fn ymd(year: i64, month: Month, day: i8) -> Result<JulianDate, Error> {
+        if (1 ..= month.days_in_month(is_leap_year(year))).contains(&day) {
+            Ok(JulianDate { year, month, day })
+        }
+        else {
+            Err(Error::OutOfRange)
+        }
+    }
+
+    /// Returns the Julian date that falls on the same day as the given
+    /// Gregorian date.
+    pub fn from_local_date(date: LocalDate) -> JulianDate {
+        JulianDate::from_julian_day_number(date.julian_day_number())
+    }
+
+    /// Returns the Gregorian date that falls on the same day as this one.
+    pub fn to_local_date(&self) -> LocalDate {
+        LocalDate::from_julian_day_number(self.julian_day_number())
+    }
+
+    /// Returns the Julian Day Number of this date.
+    pub fn julian_day_number(&self) -> i64 {
+        let month = self.month.months_from_january() as i64;
+        let (year, month) = if month < 2 { (self.year - 1, month + 10) } else { (self.year, month - 2) };
+
+        MARCH_EPOCH_JDN + i64::from(self.day) + (153 * month + 2) / 5 + 365 * year + year.div_euclid(4)
+    }
+
+    /// Computes the Julian date with the given Julian Day Number.
+    pub fn from_julian_day_number(jdn: i64) -> JulianDate {
+        let days = jdn - MARCH_EPOCH_JDN - 1;
+        let (cycles, days) = (days.div_euclid(DAYS_IN_4Y), days.rem_euclid(DAYS_IN_4Y));
+
+        // The leap day is the last day of the fourth year of each cycle.
+        let years = (days / 365).min(3);
+        let days = days - 365 * years;
+
+        let month = (5 * days + 2) / 153;
+        let day = days - (153 * month + 2) / 5 + 1;
+        let year = 4 * cycles + years + if month >= 10 { 1 } else { 0 };
+        let month = Month::from_zero(((month + 2) % 12) as i8).unwrap();
+
+        JulianDate { year, month, day: day as i8 }
+    }
+
+    /// Returns whether this date is in a leap year.
+    pub fn is_leap_year(&self) -> bool {
+        is_leap_year(self.year)
+    }
+}
+
+impl DatePiece for JulianDate {
+    fn year(&self) -> i64 { self.year }
+    fn month(&self) -> Month { self.month }
+    fn day(&self) -> i8 { self.day }
+
+    fn yearday(&self) -> i16 {
+        let january_1 = JulianDate { year: self.year, month: Month::January, day: 1 };
+        (self.julian_day_number() - january_1.julian_day_number() + 1) as i16
+    }
+
+    fn weekday(&self) -> Weekday {
+        self.to_local_date().weekday()
+    }
+
+    fn japanese_year(&self) -> Option<JapaneseYear> {
+        self.to_local_date().japanese_year()
+    }
+
+    fn iso_week(&self) -> (i64, i64, Weekday) {
+        self.to_local_date().iso_week()
+    }
+}
+
+
+/// A **mixed calendar** uses the Julian calendar for dates before a
+/// cutover, and the Gregorian calendar from the cutover onwards, as
+/// countries did when they adopted the Gregorian calendar.
+///
+/// The default cutover is the 15th of October, 1582, when the Gregorian
+/// calendar was first introduced.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::{LocalDate, Month, DatePiece};
+/// use datetime::julian::MixedCalendar;
+///
+/// let britain = MixedCalendar::new(LocalDate::ymd(1752, Month::September, 14).unwrap());
+///
+/// let before = britain.date(1752, Month::September, 2).unwrap();
+/// let after = britain.date(1752, Month::September, 14).unwrap();
+/// assert_eq!(before.days_until(after), 1);
+///
+/// // The days in between were skipped.
+/// assert!(britain.date(1752, Month::September, 10).is_err());
+/// assert_eq!(britain.mixed_date(before).day(), 2);
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct MixedCalendar {
+
+    /// The first day of the Gregorian calendar.
+    pub cutover: LocalDate,
+}
+
+impl MixedCalendar {
+
+    /// Creates a mixed calendar that switches to the Gregorian calendar on
+    /// the given date.
+    pub fn new(cutover: LocalDate) -> MixedCalendar {
+        MixedCalendar { cutover }
+    }
+
+    /// Returns the date with the given fields in this calendar, reading
+    /// them as a Julian date if they’re before the cutover.
+    ///
+    /// Returns an error if the day doesn’t exist in whichever calendar was
+    /// in use, including the days that were skipped at the cutover.
+    pub fn date(&self, year: i64, month: Month, day: i8) -> Result<LocalDate, Error> {
+        let fields = (year, month, day);
+        let cutover = (self.cutover.year(), self.cutover.month(), self.cutover.day());
+
+        if fields >= cutover {
+            return LocalDate::ymd(year, month, day);
+        }
+
+        let date = JulianDate::ymd(year, month, day)?.to_local_date();
+        if date < self.cutover {
+            Ok(date)
+        }
+        else {
+            Err(Error::OutOfRange)
+        }
+    }
+
+    /// Returns the fields of the given date in this calendar.
+    pub fn mixed_date(&self, date: LocalDate) -> MixedDate {
+        if date < self.cutover {
+            MixedDate::Julian(JulianDate::from_local_date(date))
+        }
+        else {
+            MixedDate::Gregorian(date)
+        }
+    }
+
+    /// Returns whether February has 29 days in the given year of this
+    /// calendar. In the year of the cutover, this follows whichever
+    /// calendar was in use at the end of February.
+    pub fn is_leap_year(&self, year: i64) -> bool {
+        let (cutover_year, cutover_month) = (self.cutover.year(), self.cutover.month());
+        let gregorian = year > cutover_year || (year == cutover_year && cutover_month <= Month::February);
+
+        if gregorian {
+            Year(year).is_leap_year()
+        }
+        else {
+            is_leap_year(year)
+        }
+    }
+}
+
+impl Default for MixedCalendar {
+    fn default() -> MixedCalendar {
+        MixedCalendar::new(LocalDate::ymd(1582, Month::October, 15).unwrap())
+    }
+}
+
+
+/// A date in a mixed calendar, which is either a Julian date or a
+/// Gregorian one, depending on which side of the cutover it’s on. Its
+/// day of the year is counted in that calendar too, without taking any
+/// skipped days into account.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum MixedDate {
+
+    /// A date before the cutover.
+    Julian(JulianDate),
+
+    /// A date on or after the cutover.
+    Gregorian(LocalDate),
+}
+
+impl DatePiece for MixedDate {
+    fn year(&self) -> i64 {
+        match *self {
+            MixedDate::Julian(ref date)    => date.year(),
+            MixedDate::Gregorian(ref date) => date.year(),
+        }
+    }
+
+    fn month(&self) -> Month {
+        match *self {
+            MixedDate::Julian(ref date)    => date.month(),
+            MixedDate::Gregorian(ref date) => date.month(),
+        }
+    }
+
+    fn day(&self) -> i8 {
+        match *self {
+            MixedDate::Julian(ref date)    => date.day(),
+            MixedDate::Gregorian(ref date) => date.day(),
+        }
+    }
+
+    fn yearday(&self) -> i16 {
+        match *self {
+            MixedDate::Julian(ref date)    => date.yearday(),
+            MixedDate::Gregorian(ref date) => date.yearday(),
+        }
+    }
+
+    fn weekday(&self) -> Weekday {
+        match *self {
+            MixedDate::Julian(ref date)    => date.weekday(),
+            MixedDate::Gregorian(ref date) => date.weekday(),
+        }
+    }
+
+    fn japanese_year(&self) -> Option<JapaneseYear> {
+        match *self {
+            MixedDate::Julian(ref date)    => date.japanese_year(),
+            MixedDate::Gregorian(ref date) => date.japanese_year(),
+        }
+    }
+
+    fn iso_week(&self) -> (i64, i64, Weekday) {
+        match *self {
+            MixedDate::Julian(ref date)    => date.iso_week(),
+            MixedDate::Gregorian(ref date) => date.iso_week(),
+        }
+    }
+}
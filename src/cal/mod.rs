@@ -5,13 +5,27 @@ pub mod datetime;
 pub mod fmt;
 pub mod offset;
 pub mod parse;
+pub mod period;
+pub mod range;
+pub mod rrule;
 pub mod zone;
 pub mod convenience;
+pub mod business;
+pub mod holidays;
+pub mod era;
+pub mod calendar;
+pub mod cron;
+pub mod window;
+pub mod fiscal;
+pub mod interval;
+pub mod julian;
 
 pub use self::datetime::{LocalDate, LocalTime, LocalDateTime, Weekday, Month};
-pub use self::datetime::{YearMonth, Year};
+pub use self::datetime::{YearMonth, YearQuarter, YearWeek, Year};
 pub use self::offset::{Offset, OffsetDateTime};
 
+use self::era::{Era, JapaneseYear};
+
 /// The **date piece** trait is used for date and time values that have
 /// date components of years, months, and days.
 pub trait DatePiece {
@@ -42,6 +56,122 @@ pub trait DatePiece {
     /// rather than the year 0 (well, 1 BCE).
     fn years_from_2000(&self) -> i64 { self.year() - 2000 }
 
+    /// The era that this date’s year is in. The Julian and Gregorian
+    /// calendars number their years in the same way, so this is the same
+    /// for both, going by the year in the date’s own calendar.
+    fn era(&self) -> Era {
+        Era::of_year(self.year())
+    }
+
+    /// The year within the era, which counts up from 1 in both eras, so
+    /// the year 0 is 1 BCE, and the year -43 is 44 BCE.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, Month, DatePiece};
+    /// use datetime::era::Era;
+    ///
+    /// let date = LocalDate::ymd(-43, Month::March, 15).unwrap();
+    /// assert_eq!((date.year_of_era(), date.era()), (44, Era::BeforeCommonEra));
+    /// ```
+    fn year_of_era(&self) -> i64 {
+        if self.year() <= 0 { 1 - self.year() } else { self.year() }
+    }
+
+    /// The year of the Japanese era that this date is in, or `None` if
+    /// it’s before the Meiji era. The eras start on Gregorian dates, so
+    /// types in other calendars convert to Gregorian first.
+    fn japanese_year(&self) -> Option<JapaneseYear> {
+        JapaneseYear::of_date(self.year(), self.month(), self.day())
+    }
+
+    /// The quarter of the year, from 1 to 4, where the first quarter is
+    /// January to March.
+    fn quarter(&self) -> i8 {
+        self.month().months_from_january() as i8 / 3 + 1
+    }
+
+    /// The quarter of the year that this date falls in.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, Month, YearQuarter, DatePiece};
+    ///
+    /// let date = LocalDate::ymd(2017, Month::August, 14).unwrap();
+    /// assert_eq!(date.quarter(), 3);
+    /// assert_eq!(date.year_quarter(), YearQuarter::new(2017, 3).unwrap());
+    /// ```
+    fn year_quarter(&self) -> YearQuarter {
+        YearQuarter { year: self.year(), quarter: self.quarter() }
+    }
+
+    /// The ISO 8601 week date: the week-numbering year, the week number
+    /// from 1 to 53, and the day of the week.
+    ///
+    /// ISO weeks start on Monday, and the first week of a year is the one
+    /// containing its first Thursday, so the first few days of January
+    /// can fall in the last week of the previous year, and the last few
+    /// days of December in the first week of the next one. Week dates are
+    /// based on the Gregorian calendar, so types in other calendars
+    /// convert to Gregorian first.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, Month, Weekday, DatePiece};
+    ///
+    /// let date = LocalDate::ymd(2021, Month::January, 1).unwrap();
+    /// assert_eq!(date.iso_week(), (2020, 53, Weekday::Friday));
+    /// ```
+    fn iso_week(&self) -> (i64, i64, Weekday) {
+        let weekday = self.weekday();
+        let week = (self.yearday() as i64 - weekday.days_since(Weekday::Monday) as i64 + 9) / 7;
+
+        if week < 1 {
+            (self.year() - 1, Year(self.year() - 1).iso_week_count(), weekday)
+        }
+        else if week > Year(self.year()).iso_week_count() {
+            (self.year() + 1, 1, weekday)
+        }
+        else {
+            (self.year(), week, weekday)
+        }
+    }
+
+    /// The ISO 8601 week that this date falls in.
+    fn year_week(&self) -> YearWeek {
+        let (year, week, _) = self.iso_week();
+        YearWeek { year, week }
+    }
+
+    /// The week of the calendar year, from 1 to 54, where weeks start on
+    /// the given day and the first week is the one containing the 1st of
+    /// January.
+    ///
+    /// Passing `Weekday::Sunday` gives the week numbers commonly used in
+    /// the United States.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, Month, Weekday, DatePiece};
+    ///
+    /// let date = LocalDate::ymd(2017, Month::January, 2).unwrap();
+    /// assert_eq!(date.week_of_year(Weekday::Sunday), 1);
+    /// assert_eq!(date.week_of_year(Weekday::Monday), 2);
+    /// ```
+    fn week_of_year(&self, week_start: Weekday) -> i64 {
+        week_containing(self.yearday() as i64, self.weekday(), week_start)
+    }
+
+    /// The week of the month, from 1 to 6, where weeks start on the given
+    /// day and the first week is the one containing the 1st of the month.
+    fn week_of_month(&self, week_start: Weekday) -> i64 {
+        week_containing(self.day() as i64, self.weekday(), week_start)
+    }
+
     // I’d ideally like to include “century” here, but there’s some
     // discrepancy over what the result should be: the Gregorian
     // calendar calls the span from 2000 to 2099 the “21st Century”, but
@@ -52,6 +182,15 @@ pub trait DatePiece {
 }
 
 
+/// Returns which week a day falls in, counting from 1, given its position
+/// in the year or month and its weekday.
+fn week_containing(day: i64, weekday: Weekday, week_start: Weekday) -> i64 {
+    let into_week = weekday.days_since(week_start) as i64;
+    let first_into_week = (into_week - (day - 1)).rem_euclid(7);
+    (day - 1 + first_into_week) / 7 + 1
+}
+
+
 /// The **time piece** trait is used for date and time values that have
 /// time components of hours, minutes, and seconds.
 pub trait TimePiece {
@@ -4,6 +4,7 @@ use std::error::Error as ErrorTrait;
 use std::fmt;
 
 use duration::Duration;
+use instant::Instant;
 use cal::{DatePiece, TimePiece};
 use cal::datetime::{LocalDateTime, Month, Weekday, Error as DateTimeError};
 use cal::fmt::ISO;
@@ -136,6 +137,16 @@ pub struct OffsetDateTime {
     pub offset: Offset,
 }
 
+impl OffsetDateTime {
+
+    /// Returns the instant that this datetime refers to, taking its local
+    /// time and moving it back by the offset.
+    pub fn to_instant(&self) -> Instant {
+        let seconds = i64::from(self.offset.offset_seconds.unwrap_or(0));
+        (self.local - Duration::of(seconds)).to_instant()
+    }
+}
+
 impl DatePiece for OffsetDateTime {
     fn year(&self) -> i64 {
         self.offset.adjust(self.local).year()
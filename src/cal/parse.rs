@@ -5,7 +5,10 @@ use std::str::FromStr;
 use iso8601;
 
 use cal::datetime::{LocalDate, LocalTime, LocalDateTime, Month, Weekday, Error as DateTimeError};
+use cal::interval::{IsoInterval, RepeatingInterval};
 use cal::offset::{Offset, OffsetDateTime, Error as OffsetError};
+use cal::period::Period;
+use duration::Duration;
 
 
 impl FromStr for LocalDate {
@@ -119,3 +122,463 @@ impl<E: ErrorTrait> ErrorTrait for Error<E> {
         }
     }
 }
+
+
+
+impl FromStr for Duration {
+    type Err = DurationError;
+
+    /// Parses an ISO 8601 duration, such as `PT1H30M`, `P2DT12H`, `PT0.5S`,
+    /// `P3W`, or `-PT10S`.
+    ///
+    /// A day is taken to be exactly 24 hours long, and a week 7 days long.
+    /// Years and months have no fixed length, so durations containing them
+    /// are rejected: parse those as a `Period` instead.
+    fn from_str(input: &str) -> Result<Duration, Self::Err> {
+        let (negative, components) = parse_iso_duration(input, false)?;
+        let mut milliseconds: i64 = 0;
+
+        for component in components {
+            let unit_seconds = match (component.designator, component.in_time) {
+                ('W', false) => 7 * 86400,
+                ('D', false) => 86400,
+                ('H', true)  => 3600,
+                ('M', true)  => 60,
+                ('S', true)  => 1,
+                (c, _)       => return Err(DurationError::NotExact { c, pos: component.pos }),
+            };
+
+            let ms = component.milliseconds(unit_seconds)
+                              .and_then(|ms| milliseconds.checked_add(ms));
+
+            match ms {
+                Some(ms) => milliseconds = ms,
+                None     => return Err(DurationError::Overflow { pos: component.pos }),
+            }
+        }
+
+        if negative {
+            milliseconds = -milliseconds;
+        }
+
+        Ok(Duration::of_ms(milliseconds.div_euclid(1000), milliseconds.rem_euclid(1000) as i16))
+    }
+}
+
+impl FromStr for Period {
+    type Err = DurationError;
+
+    /// Parses an ISO 8601 duration in its calendar form, such as
+    /// `P1Y2M10DT2H30M`, keeping each field separate.
+    ///
+    /// Only the seconds field may have a fractional part, which gets
+    /// stored as milliseconds. As well as a leading minus sign that negates
+    /// the whole period, each number may have a sign of its own, which is
+    /// how periods with fields of different signs get written out.
+    fn from_str(input: &str) -> Result<Period, Self::Err> {
+        let (negative, components) = parse_iso_duration(input, true)?;
+        let mut period = Period::zero();
+
+        for component in components {
+            if component.fraction.is_some() && component.designator != 'S' {
+                return Err(DurationError::Fraction { pos: component.pos });
+            }
+
+            let field = match (component.designator, component.in_time) {
+                ('Y', false) => &mut period.years,
+                ('M', false) => &mut period.months,
+                ('W', false) => &mut period.weeks,
+                ('D', false) => &mut period.days,
+                ('H', true)  => &mut period.hours,
+                ('M', true)  => &mut period.minutes,
+                _            => {
+                    // Only the seconds are left, which can have a fraction.
+                    let ms = component.milliseconds(1).ok_or(DurationError::Overflow { pos: component.pos })?;
+                    period.seconds = ms / 1000;
+                    period.milliseconds = ms % 1000;
+                    continue;
+                },
+            };
+
+            *field = component.whole * component.sign;
+        }
+
+        Ok(if negative { -period } else { period })
+    }
+}
+
+
+/// One number-and-designator pair from an ISO 8601 duration, such as the
+/// `10D` in `P1Y10DT2H`.
+#[derive(PartialEq, Debug, Clone, Copy)]
+struct DurationComponent {
+    designator: char,
+    in_time: bool,
+    sign: i64,
+    whole: i64,
+    fraction: Option<(i64, u32)>,
+    pos: usize,
+}
+
+impl DurationComponent {
+
+    /// Returns the number of milliseconds this component is worth, given
+    /// the number of seconds in its unit, or `None` if it overflows.
+    ///
+    /// Fractions are truncated to the nearest millisecond.
+    fn milliseconds(&self, unit_seconds: i64) -> Option<i64> {
+        let unit_ms = unit_seconds * 1000;
+        let whole = self.whole.checked_mul(unit_ms)?;
+
+        let fraction = match self.fraction {
+            Some((digits, count)) => digits * unit_ms / 10_i64.pow(count),
+            None                  => 0,
+        };
+
+        whole.checked_add(fraction).map(|ms| ms * self.sign)
+    }
+}
+
+/// The maximum number of fractional digits that get taken into account.
+/// Any after this are checked for validity, but otherwise ignored.
+const MAX_FRACTION_DIGITS: u32 = 9;
+
+/// Splits an ISO 8601 duration into its components, returning them along
+/// with whether the whole duration is negative.
+///
+/// This checks the syntax strictly: the designators have to be in order,
+/// with no repeats, and at least one component has to be present (in the
+/// time part too, if there is a `T`). Only the last component may have a
+/// fractional part, and weeks cannot be combined with anything else.
+fn parse_iso_duration(input: &str, signed_components: bool) -> Result<(bool, Vec<DurationComponent>), DurationError> {
+    let mut chars = input.char_indices().peekable();
+    let mut components: Vec<DurationComponent> = Vec::new();
+
+    let negative = match chars.peek() {
+        Some(&(_, '-')) => { let _ = chars.next(); true },
+        Some(&(_, '+')) => { let _ = chars.next(); false },
+        _               => false,
+    };
+
+    match chars.next() {
+        Some((_, 'P')) => {},
+        Some((pos, _)) => return Err(DurationError::MissingDesignator { pos }),
+        None           => return Err(DurationError::UnexpectedEnd { pos: input.len() }),
+    }
+
+    let mut in_time = false;
+    let mut last_order = None;
+
+    loop {
+        let start = match chars.peek() {
+            Some(&(_, 'T')) if !in_time => {
+                let _ = chars.next();
+                in_time = true;
+                continue;
+            },
+            Some(&(pos, _)) => pos,
+            None => break,
+        };
+
+        let sign = match chars.peek() {
+            Some(&(_, '-')) if signed_components => { let _ = chars.next(); -1 },
+            Some(&(_, '+')) if signed_components => { let _ = chars.next();  1 },
+            _                                    => 1,
+        };
+
+        let (whole, digit_count) = parse_digits(&mut chars, u32::MAX);
+        if digit_count == 0 {
+            return Err(unexpected(chars.peek(), input));
+        }
+
+        let whole = match whole {
+            Some(w) => w,
+            None    => return Err(DurationError::Overflow { pos: start }),
+        };
+
+        let fraction = match chars.peek() {
+            Some(&(_, '.')) | Some(&(_, ',')) => {
+                let _ = chars.next();
+                let (digits, count) = parse_digits(&mut chars, MAX_FRACTION_DIGITS);
+                if count == 0 {
+                    return Err(unexpected(chars.peek(), input));
+                }
+                Some((digits.unwrap_or(0), count.min(MAX_FRACTION_DIGITS)))
+            },
+            _ => None,
+        };
+
+        let (pos, designator) = match chars.next() {
+            Some(pc) => pc,
+            None     => return Err(DurationError::UnexpectedEnd { pos: input.len() }),
+        };
+
+        let order = match (designator, in_time) {
+            ('Y', false) => 0,  ('M', false) => 1,
+            ('W', false) => 2,  ('D', false) => 3,
+            ('H', true)  => 4,  ('M', true)  => 5,
+            ('S', true)  => 6,
+            (c, _)       => return Err(DurationError::UnexpectedChar { c, pos }),
+        };
+
+        if last_order.is_some_and(|last| order <= last) {
+            return Err(DurationError::OutOfOrder { c: designator, pos });
+        }
+
+        if let Some(previous) = components.last() {
+            if previous.fraction.is_some() {
+                return Err(DurationError::Fraction { pos: previous.pos });
+            }
+        }
+
+        last_order = Some(order);
+        components.push(DurationComponent { designator, in_time, sign, whole, fraction, pos: start });
+    }
+
+    if components.is_empty() {
+        return Err(DurationError::UnexpectedEnd { pos: input.len() });
+    }
+
+    // A `T` can only be followed by time components, so if there aren’t
+    // any, the input must have ended straight after it.
+    if in_time && !components.iter().any(|c| c.in_time) {
+        return Err(DurationError::UnexpectedEnd { pos: input.len() });
+    }
+
+    if components.len() > 1 {
+        if let Some(week) = components.iter().find(|c| c.designator == 'W') {
+            return Err(DurationError::MixedWeeks { pos: week.pos });
+        }
+    }
+
+    Ok((negative, components))
+}
+
+/// Reads a run of ASCII digits, returning their value (or `None` if it
+/// overflowed), and how many digits there were. Only the first `limit`
+/// digits contribute to the value.
+fn parse_digits<I>(chars: &mut ::std::iter::Peekable<I>, limit: u32) -> (Option<i64>, u32)
+where I: Iterator<Item=(usize, char)> {
+    let mut value = Some(0_i64);
+    let mut count = 0;
+
+    while let Some(&(_, c)) = chars.peek() {
+        let digit = match c.to_digit(10) {
+            Some(d) => d as i64,
+            None    => break,
+        };
+
+        if count < limit {
+            value = value.and_then(|v| v.checked_mul(10)).and_then(|v| v.checked_add(digit));
+        }
+
+        count += 1;
+        let _ = chars.next();
+    }
+
+    (value, count)
+}
+
+/// Returns the error for the character that’s next in the input, or the
+/// end of the input if there isn’t one.
+fn unexpected(next: Option<&(usize, char)>, input: &str) -> DurationError {
+    match next {
+        Some(&(pos, c)) => DurationError::UnexpectedChar { c, pos },
+        None            => DurationError::UnexpectedEnd { pos: input.len() },
+    }
+}
+
+
+/// An error encountered while parsing an ISO 8601 duration. Every variant
+/// records the byte position in the input that the problem was found at.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum DurationError {
+
+    /// The input didn’t begin with `P` (after an optional sign).
+    MissingDesignator { pos: usize },
+
+    /// A character was found where it isn’t allowed.
+    UnexpectedChar { c: char, pos: usize },
+
+    /// The input ended before the duration was complete.
+    UnexpectedEnd { pos: usize },
+
+    /// A designator was repeated, or came after a smaller one.
+    OutOfOrder { c: char, pos: usize },
+
+    /// A fractional number was used somewhere other than the last
+    /// component.
+    Fraction { pos: usize },
+
+    /// Weeks were combined with another component.
+    MixedWeeks { pos: usize },
+
+    /// A duration contained years or months, which have no fixed length.
+    NotExact { c: char, pos: usize },
+
+    /// A number was too large to be represented.
+    Overflow { pos: usize },
+}
+
+impl DurationError {
+
+    /// Returns the byte position in the input where the error was found.
+    pub fn position(&self) -> usize {
+        match *self {
+            DurationError::MissingDesignator { pos }
+            | DurationError::UnexpectedChar { pos, .. }
+            | DurationError::UnexpectedEnd { pos }
+            | DurationError::OutOfOrder { pos, .. }
+            | DurationError::Fraction { pos }
+            | DurationError::MixedWeeks { pos }
+            | DurationError::NotExact { pos, .. }
+            | DurationError::Overflow { pos }  => pos,
+        }
+    }
+}
+
+impl fmt::Display for DurationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DurationError::MissingDesignator { pos }  => write!(f, "expected ‘P’ at position {}", pos),
+            DurationError::UnexpectedChar { c, pos }  => write!(f, "unexpected character ‘{}’ at position {}", c, pos),
+            DurationError::UnexpectedEnd { pos }      => write!(f, "unexpected end of input at position {}", pos),
+            DurationError::OutOfOrder { c, pos }      => write!(f, "designator ‘{}’ out of order at position {}", c, pos),
+            DurationError::Fraction { pos }           => write!(f, "fraction not allowed at position {}", pos),
+            DurationError::MixedWeeks { pos }         => write!(f, "weeks combined with other components at position {}", pos),
+            DurationError::NotExact { c, pos }        => write!(f, "designator ‘{}’ has no fixed length at position {}", c, pos),
+            DurationError::Overflow { pos }           => write!(f, "number too large at position {}", pos),
+        }
+    }
+}
+
+impl ErrorTrait for DurationError {
+}
+
+
+impl FromStr for IsoInterval {
+    type Err = IntervalError;
+
+    /// Parses an ISO 8601 time interval in any of its four forms: two
+    /// datetimes, a datetime and a duration, a duration and a datetime, or
+    /// a duration on its own, with the parts separated by a `/`.
+    ///
+    /// The datetimes have to be given in full, rather than leaving out the
+    /// fields of the end that are the same as the start’s.
+    fn from_str(input: &str) -> Result<IsoInterval, Self::Err> {
+        let parts: Vec<&str> = input.split('/').collect();
+
+        match parts[..] {
+            [ period ] => Ok(IsoInterval::Period(interval_period(period)?)),
+            [ start, end ] => match (start.starts_with('P'), end.starts_with('P')) {
+                (false, false) => Ok(IsoInterval::StartEnd(interval_datetime(start)?, interval_datetime(end)?)),
+                (false, true)  => Ok(IsoInterval::StartPeriod(interval_datetime(start)?, interval_period(end)?)),
+                (true, false)  => Ok(IsoInterval::PeriodEnd(interval_period(start)?, interval_datetime(end)?)),
+                (true, true)   => Err(IntervalError::Format),
+            },
+            _ => Err(IntervalError::Format),
+        }
+    }
+}
+
+impl FromStr for RepeatingInterval {
+    type Err = IntervalError;
+
+    /// Parses an ISO 8601 repeating interval, such as
+    /// `R5/2008-03-01T13:00:00Z/P1Y2M10DT2H30M`. Leaving out the number
+    /// of repetitions, or giving it as `-1`, makes the interval repeat
+    /// forever.
+    fn from_str(input: &str) -> Result<RepeatingInterval, Self::Err> {
+        let mut parts = input.splitn(2, '/');
+        let count = parts.next().unwrap_or("");
+        let rest = parts.next().ok_or(IntervalError::Format)?;
+
+        if !count.starts_with('R') {
+            return Err(IntervalError::Format);
+        }
+
+        let repetitions = match &count[1..] {
+            "" | "-1"  => None,
+            number     => Some(number.parse().map_err(|_| IntervalError::Repetitions)?),
+        };
+
+        Ok(RepeatingInterval { repetitions, interval: rest.parse()? })
+    }
+}
+
+fn interval_datetime(input: &str) -> Result<OffsetDateTime, IntervalError> {
+    input.parse().map_err(IntervalError::DateTime)
+}
+
+fn interval_period(input: &str) -> Result<Period, IntervalError> {
+    input.parse().map_err(IntervalError::Duration)
+}
+
+
+/// An error encountered while parsing an ISO 8601 time interval or
+/// repeating interval.
+#[derive(PartialEq, Debug, Clone)]
+pub enum IntervalError {
+
+    /// The input didn’t have the right number of parts, or both of its
+    /// parts were durations.
+    Format,
+
+    /// The number of repetitions wasn’t a number.
+    Repetitions,
+
+    /// One of the ends couldn’t be parsed as a datetime.
+    DateTime(Error<OffsetError>),
+
+    /// The duration couldn’t be parsed. Its position is counted from the
+    /// start of the duration, rather than the start of the whole input.
+    Duration(DurationError),
+}
+
+impl fmt::Display for IntervalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            IntervalError::Format              => write!(f, "expected a datetime or duration on each side of ‘/’"),
+            IntervalError::Repetitions         => write!(f, "invalid number of repetitions"),
+            IntervalError::DateTime(ref error) => write!(f, "invalid datetime: {}", error),
+            IntervalError::Duration(ref error) => write!(f, "invalid duration: {}", error),
+        }
+    }
+}
+
+impl ErrorTrait for IntervalError {
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::DurationError;
+    use cal::period::Period;
+    use duration::Duration;
+
+    #[test]
+    fn hours_and_minutes() {
+        assert_eq!("PT1H30M".parse(), Ok(Duration::of(5400)));
+    }
+
+    #[test]
+    fn fractional_seconds() {
+        assert_eq!("PT0,25S".parse(), Ok(Duration::of_ms(0, 250)));
+    }
+
+    #[test]
+    fn fraction_must_be_last() {
+        assert_eq!("PT1.5H30M".parse::<Duration>(), Err(DurationError::Fraction { pos: 2 }));
+    }
+
+    #[test]
+    fn calendar_period() {
+        let period = Period { years: 1, months: 2, days: 10, hours: 2, minutes: 30, ..Period::zero() };
+        assert_eq!("P1Y2M10DT2H30M".parse(), Ok(period));
+    }
+
+    #[test]
+    fn calendar_duration() {
+        assert_eq!("P1Y".parse::<Duration>(), Err(DurationError::NotExact { c: 'Y', pos: 1 }));
+    }
+}
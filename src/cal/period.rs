@@ -0,0 +1,351 @@
+//! Calendar-based lengths of time, made of years, months, and days.
+
+use std::ops::{Add, Sub, Neg};
+
+use cal::datetime::{LocalDate, LocalDateTime, Error};
+pub use cal::datetime::MonthOverflow;
+use cal::zone::{ZonedDateTime, LocalTimes};
+use duration::Duration;
+
+
+/// A **period** is a length of time measured on the calendar, such as
+/// “one month” or “two years and three days”, rather than on the timeline.
+///
+/// The difference between a period and a `Duration` is that a duration is
+/// always the same fixed number of seconds, whereas the number of days in
+/// a period of one month depends on which month it gets added to. For this
+/// reason, a period can only be turned into an exact length of time once
+/// it has been applied to a date.
+///
+/// Each field is stored separately, and may be negative. The time fields
+/// are optional, in the sense that they are ignored when a period is added
+/// to a `LocalDate`.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub struct Period {
+    pub years:   i64,
+    pub months:  i64,
+    pub weeks:   i64,
+    pub days:    i64,
+
+    pub hours:   i64,
+    pub minutes: i64,
+    pub seconds: i64,
+    pub milliseconds: i64,
+}
+
+impl Period {
+
+    /// Creates a new zero-length period.
+    pub fn zero() -> Period {
+        Period::default()
+    }
+
+    /// Creates a new period that’s the given number of years long.
+    pub fn years(years: i64) -> Period {
+        Period { years, ..Period::default() }
+    }
+
+    /// Creates a new period that’s the given number of months long.
+    pub fn months(months: i64) -> Period {
+        Period { months, ..Period::default() }
+    }
+
+    /// Creates a new period that’s the given number of weeks long.
+    pub fn weeks(weeks: i64) -> Period {
+        Period { weeks, ..Period::default() }
+    }
+
+    /// Creates a new period that’s the given number of days long.
+    pub fn days(days: i64) -> Period {
+        Period { days, ..Period::default() }
+    }
+
+    /// Creates a new period with the given years, months, and days fields.
+    pub fn ymd(years: i64, months: i64, days: i64) -> Period {
+        Period { years, months, days, ..Period::default() }
+    }
+
+    /// Creates a new period with the given hours, minutes, and seconds
+    /// fields.
+    pub fn hms(hours: i64, minutes: i64, seconds: i64) -> Period {
+        Period { hours, minutes, seconds, ..Period::default() }
+    }
+
+    /// Returns whether every field of this period is zero.
+    pub fn is_zero(&self) -> bool {
+        *self == Period::zero()
+    }
+
+    /// Returns whether this period has any hours, minutes, seconds, or
+    /// milliseconds in it.
+    pub fn has_time(&self) -> bool {
+        self.hours != 0 || self.minutes != 0 || self.seconds != 0 || self.milliseconds != 0
+    }
+
+    /// Returns the total number of months in the years and months fields.
+    pub fn total_months(&self) -> i64 {
+        self.years * 12 + self.months
+    }
+
+    /// Returns the total number of days in the weeks and days fields.
+    pub fn total_days(&self) -> i64 {
+        self.weeks * 7 + self.days
+    }
+
+    /// Returns the time fields as an exact `Duration`.
+    pub fn time_duration(&self) -> Duration {
+        let ms = ((self.hours * 60 + self.minutes) * 60 + self.seconds) * 1000 + self.milliseconds;
+        Duration::of_ms(ms.div_euclid(1000), ms.rem_euclid(1000) as i16)
+    }
+
+    /// Returns a copy of this period with its fields carried over into the
+    /// larger units where the conversion is exact: every twelve months
+    /// become a year, and every thousand milliseconds, sixty seconds, and
+    /// sixty minutes become a second, a minute, and an hour. The fields end
+    /// up with the same sign as each other.
+    ///
+    /// Weeks and days, and days and hours, are left alone, as the length of
+    /// a day depends on the time zone it gets used in.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::Period;
+    ///
+    /// let period = Period::ymd(1, 14, 0).normalized();
+    /// assert_eq!(period, Period::ymd(2, 2, 0));
+    ///
+    /// let period = Period::ymd(1, -2, 0).normalized();
+    /// assert_eq!(period, Period::ymd(0, 10, 0));
+    /// ```
+    pub fn normalized(&self) -> Period {
+        let months = self.total_months();
+        let ms = ((self.hours * 60 + self.minutes) * 60 + self.seconds) * 1000 + self.milliseconds;
+
+        Period {
+            years:   months / 12,
+            months:  months % 12,
+            weeks:   self.weeks,
+            days:    self.days,
+
+            hours:   ms / 3_600_000,
+            minutes: ms / 60_000 % 60,
+            seconds: ms / 1000 % 60,
+            milliseconds: ms % 1000,
+        }
+    }
+
+    /// Returns the period in years, months, and days between two dates.
+    ///
+    /// Whole months are counted first, in the same way as
+    /// `LocalDate::months_until`, then the remaining days. When the
+    /// starting day doesn’t exist in the month before the end date, the
+    /// month is clamped to its last day, so the period between the 31st of
+    /// January and the 1st of March is one month and one day (in a common
+    /// year), the month taking it to the 28th of February.
+    ///
+    /// If the end date is before the start date, every field of the result
+    /// is negative or zero.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, Month, Period};
+    ///
+    /// let start = LocalDate::ymd(2017, Month::January, 15).unwrap();
+    /// let end   = LocalDate::ymd(2018, Month::March, 20).unwrap();
+    /// assert_eq!(Period::between(start, end), Period::ymd(1, 2, 5));
+    /// assert_eq!(Period::between(end, start), Period::ymd(-1, -2, -5));
+    /// ```
+    pub fn between(start: LocalDate, end: LocalDate) -> Period {
+        let months = start.months_until(end);
+        let shifted = start.add_months(months, MonthOverflow::Clamp).unwrap();
+        Period::ymd(months / 12, months % 12, shifted.days_until(end))
+    }
+}
+
+
+impl Add<Period> for Period {
+    type Output = Period;
+
+    fn add(self, rhs: Period) -> Period {
+        Period {
+            years:   self.years   + rhs.years,
+            months:  self.months  + rhs.months,
+            weeks:   self.weeks   + rhs.weeks,
+            days:    self.days    + rhs.days,
+
+            hours:   self.hours   + rhs.hours,
+            minutes: self.minutes + rhs.minutes,
+            seconds: self.seconds + rhs.seconds,
+            milliseconds: self.milliseconds + rhs.milliseconds,
+        }
+    }
+}
+
+impl Sub<Period> for Period {
+    type Output = Period;
+
+    fn sub(self, rhs: Period) -> Period {
+        self + -rhs
+    }
+}
+
+impl Neg for Period {
+    type Output = Period;
+
+    fn neg(self) -> Period {
+        Period {
+            years:   -self.years,
+            months:  -self.months,
+            weeks:   -self.weeks,
+            days:    -self.days,
+
+            hours:   -self.hours,
+            minutes: -self.minutes,
+            seconds: -self.seconds,
+            milliseconds: -self.milliseconds,
+        }
+    }
+}
+
+
+impl LocalDate {
+
+    /// Adds a period to this date, using the given policy to decide what
+    /// happens when the result lands past the end of a month.
+    ///
+    /// The years and months get added first, then the weeks and days. Any
+    /// time fields in the period are ignored.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, Month, Period, MonthOverflow};
+    ///
+    /// let date = LocalDate::ymd(2016, Month::January, 31).unwrap();
+    /// let period = Period::months(1);
+    ///
+    /// assert_eq!(date.add_period(period, MonthOverflow::Clamp),
+    ///            LocalDate::ymd(2016, Month::February, 29));
+    /// assert_eq!(date.add_period(period, MonthOverflow::RollOver),
+    ///            LocalDate::ymd(2016, Month::March, 2));
+    /// assert!(date.add_period(period, MonthOverflow::Reject).is_err());
+    /// ```
+    pub fn add_period(&self, period: Period, overflow: MonthOverflow) -> Result<LocalDate, Error> {
+        Ok(self.add_months(period.total_months(), overflow)?.add_days(period.total_days()))
+    }
+
+    /// Subtracts a period from this date, using the given policy to decide
+    /// what happens when the result lands past the end of a month.
+    pub fn sub_period(&self, period: Period, overflow: MonthOverflow) -> Result<LocalDate, Error> {
+        self.add_period(-period, overflow)
+    }
+}
+
+impl Add<Period> for LocalDate {
+    type Output = LocalDate;
+
+    /// Adds a period to this date, clamping to the end of the month.
+    fn add(self, period: Period) -> LocalDate {
+        self.add_period(period, MonthOverflow::Clamp).unwrap()
+    }
+}
+
+impl Sub<Period> for LocalDate {
+    type Output = LocalDate;
+
+    /// Subtracts a period from this date, clamping to the end of the month.
+    fn sub(self, period: Period) -> LocalDate {
+        self.sub_period(period, MonthOverflow::Clamp).unwrap()
+    }
+}
+
+
+impl LocalDateTime {
+
+    /// Adds a period to this datetime, using the given policy to decide
+    /// what happens when the result lands past the end of a month.
+    ///
+    /// The date fields get added to the date first, then the time fields
+    /// get added as an exact duration.
+    pub fn add_period(&self, period: Period, overflow: MonthOverflow) -> Result<LocalDateTime, Error> {
+        let date = self.date().add_period(period, overflow)?;
+        Ok(LocalDateTime::new(date, self.time()) + period.time_duration())
+    }
+
+    /// Subtracts a period from this datetime, using the given policy to
+    /// decide what happens when the result lands past the end of a month.
+    pub fn sub_period(&self, period: Period, overflow: MonthOverflow) -> Result<LocalDateTime, Error> {
+        self.add_period(-period, overflow)
+    }
+}
+
+impl Add<Period> for LocalDateTime {
+    type Output = LocalDateTime;
+
+    /// Adds a period to this datetime, clamping to the end of the month.
+    fn add(self, period: Period) -> LocalDateTime {
+        self.add_period(period, MonthOverflow::Clamp).unwrap()
+    }
+}
+
+impl Sub<Period> for LocalDateTime {
+    type Output = LocalDateTime;
+
+    /// Subtracts a period from this datetime, clamping to the end of the
+    /// month.
+    fn sub(self, period: Period) -> LocalDateTime {
+        self.sub_period(period, MonthOverflow::Clamp).unwrap()
+    }
+}
+
+
+impl<'a> ZonedDateTime<'a> {
+
+    /// Adds a period to the wall-clock time of this zoned datetime, then
+    /// converts the result back into the same time zone.
+    ///
+    /// Adding one day to 09:00 therefore gives 09:00 on the next day, even
+    /// if a daylight-saving transition happens in between. The result can
+    /// land in a gap or an overlap around such a transition, so it’s
+    /// returned as `LocalTimes`, just like `TimeZone::convert_local`.
+    pub fn add_period(&self, period: Period, overflow: MonthOverflow) -> Result<LocalTimes<'_>, Error> {
+        let local = self.local().add_period(period, overflow)?;
+        Ok(self.convert_local(local))
+    }
+
+    /// Subtracts a period from the wall-clock time of this zoned datetime,
+    /// then converts the result back into the same time zone.
+    pub fn sub_period(&self, period: Period, overflow: MonthOverflow) -> Result<LocalTimes<'_>, Error> {
+        self.add_period(-period, overflow)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::{Period, MonthOverflow};
+    use cal::datetime::{LocalDate, Month};
+
+    #[test]
+    fn leap_day_anniversary() {
+        let date = LocalDate::ymd(2016, Month::February, 29).unwrap();
+        assert_eq!(date + Period::years(1), LocalDate::ymd(2017, Month::February, 28).unwrap());
+        assert_eq!(date.add_period(Period::years(1), MonthOverflow::RollOver), LocalDate::ymd(2017, Month::March, 1));
+        assert_eq!(date.add_period(Period::years(4), MonthOverflow::Reject), LocalDate::ymd(2020, Month::February, 29));
+    }
+
+    #[test]
+    fn between_end_of_month() {
+        let start = LocalDate::ymd(2017, Month::January, 31).unwrap();
+        let end = LocalDate::ymd(2017, Month::March, 1).unwrap();
+        assert_eq!(Period::between(start, end), Period::ymd(0, 1, 1));
+    }
+
+    #[test]
+    fn normalized_time() {
+        let period = Period { minutes: 90, milliseconds: 1500, ..Period::zero() };
+        assert_eq!(period.normalized(), Period { hours: 1, minutes: 30, seconds: 1, milliseconds: 500, ..Period::zero() });
+    }
+}
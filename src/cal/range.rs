@@ -0,0 +1,268 @@
+//! Iterators over spans of dates and datetimes.
+
+use std::iter::FusedIterator;
+
+use cal::datetime::{LocalDate, LocalDateTime, MonthOverflow};
+use duration::Duration;
+
+
+/// The distance between the dates produced by a `DateRange`.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum DateStep {
+
+    /// Step forward by the given number of days.
+    Days(i64),
+
+    /// Step forward by the given number of weeks.
+    Weeks(i64),
+
+    /// Step forward by the given number of months.
+    ///
+    /// Each date is worked out by adding a multiple of the step to the
+    /// start date, clamping to the end of shorter months, so a monthly
+    /// range starting on the 31st of January visits the 28th of February
+    /// and then the 31st of March.
+    Months(i64),
+}
+
+/// An iterator over the dates between a start date and an end date,
+/// which can cross month and year boundaries.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::{LocalDate, Month, DateRange, DateStep};
+///
+/// let start = LocalDate::ymd(2016, Month::December, 30).unwrap();
+/// let end   = LocalDate::ymd(2017, Month::January, 2).unwrap();
+///
+/// assert_eq!(DateRange::new(start, end).len(), 3);
+/// assert_eq!(DateRange::inclusive(start, end).len(), 4);
+///
+/// let fortnights = DateRange::inclusive(start, end).step(DateStep::Weeks(2));
+/// assert_eq!(fortnights.collect::<Vec<_>>(), vec![ start ]);
+/// ```
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct DateRange {
+    start: LocalDate,
+    end: LocalDate,
+    inclusive: bool,
+    step: DateStep,
+    front: i64,
+    back: i64,
+}
+
+impl DateRange {
+
+    /// Creates a new range of dates, one day apart, from the start date up
+    /// to but not including the end date.
+    pub fn new(start: LocalDate, end: LocalDate) -> DateRange {
+        DateRange::with_end(start, end, false)
+    }
+
+    /// Creates a new range of dates, one day apart, from the start date up
+    /// to and including the end date.
+    pub fn inclusive(start: LocalDate, end: LocalDate) -> DateRange {
+        DateRange::with_end(start, end, true)
+    }
+
+    fn with_end(start: LocalDate, end: LocalDate, inclusive: bool) -> DateRange {
+        let mut range = DateRange { start, end, inclusive, step: DateStep::Days(1), front: 0, back: 0 };
+        range.back = range.count_steps();
+        range
+    }
+
+    /// Changes the distance between each date in this range, starting
+    /// again from the start date.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if the step isn’t positive.
+    pub fn step(mut self, step: DateStep) -> DateRange {
+        match step {
+            DateStep::Days(n) | DateStep::Weeks(n) | DateStep::Months(n) => {
+                assert!(n > 0, "date range step must be positive");
+            }
+        }
+
+        self.step = step;
+        self.front = 0;
+        self.back = self.count_steps();
+        self
+    }
+
+    /// Returns the date that’s the given number of steps after the start.
+    fn nth_date(&self, index: i64) -> LocalDate {
+        match self.step {
+            DateStep::Days(n)   => self.start.add_days(index * n),
+            DateStep::Weeks(n)  => self.start.add_weeks(index * n),
+
+            // Clamping can never fail.
+            DateStep::Months(n) => self.start.add_months(index * n, MonthOverflow::Clamp).unwrap(),
+        }
+    }
+
+    /// Returns the number of dates in the range.
+    fn count_steps(&self) -> i64 {
+        if self.end < self.start || (self.end == self.start && !self.inclusive) {
+            return 0;
+        }
+
+        let last = match self.step {
+            DateStep::Days(n)   => self.start.days_until(self.end) / n,
+            DateStep::Weeks(n)  => self.start.days_until(self.end) / (n * 7),
+            DateStep::Months(n) => self.start.months_until(self.end) / n,
+        };
+
+        // The last step can land on the end date exactly, which only
+        // counts for inclusive ranges.
+        if !self.inclusive && self.nth_date(last) == self.end {
+            last
+        }
+        else {
+            last + 1
+        }
+    }
+}
+
+impl Iterator for DateRange {
+    type Item = LocalDate;
+
+    fn next(&mut self) -> Option<LocalDate> {
+        if self.front < self.back {
+            self.front += 1;
+            Some(self.nth_date(self.front - 1))
+        }
+        else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back - self.front) as usize;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for DateRange {
+    fn next_back(&mut self) -> Option<LocalDate> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(self.nth_date(self.back))
+        }
+        else {
+            None
+        }
+    }
+}
+
+impl ExactSizeIterator for DateRange {}
+
+impl FusedIterator for DateRange {}
+
+
+/// An iterator over the datetimes between a start datetime and an end
+/// datetime, separated by a fixed duration.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::{LocalDate, LocalDateTime, LocalTime, Month, Duration, DateTimeRange};
+///
+/// let date  = LocalDate::ymd(2017, Month::March, 1).unwrap();
+/// let start = LocalDateTime::new(date, LocalTime::hm(9, 0).unwrap());
+/// let end   = LocalDateTime::new(date, LocalTime::hm(17, 0).unwrap());
+///
+/// let slots = DateTimeRange::new(start, end, Duration::of(30 * 60));
+/// assert_eq!(slots.len(), 16);
+/// ```
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct DateTimeRange {
+    start: i64,
+    step: i64,
+    front: i64,
+    back: i64,
+}
+
+impl DateTimeRange {
+
+    /// Creates a new range of datetimes, the given duration apart, from the
+    /// start datetime up to but not including the end datetime.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if the step isn’t positive.
+    pub fn new(start: LocalDateTime, end: LocalDateTime, step: Duration) -> DateTimeRange {
+        DateTimeRange::with_end(start, end, step, false)
+    }
+
+    /// Creates a new range of datetimes, the given duration apart, from the
+    /// start datetime up to and including the end datetime.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if the step isn’t positive.
+    pub fn inclusive(start: LocalDateTime, end: LocalDateTime, step: Duration) -> DateTimeRange {
+        DateTimeRange::with_end(start, end, step, true)
+    }
+
+    fn with_end(start: LocalDateTime, end: LocalDateTime, step: Duration, inclusive: bool) -> DateTimeRange {
+        let (seconds, milliseconds) = step.lengths();
+        let step = seconds * 1000 + i64::from(milliseconds);
+        assert!(step > 0, "datetime range step must be positive");
+
+        let start = milliseconds_since_epoch(start);
+        let span = milliseconds_since_epoch(end) - start;
+
+        let back = if span < 0 || (span == 0 && !inclusive) { 0 }
+                   else if inclusive || span % step != 0 { span / step + 1 }
+                   else { span / step };
+
+        DateTimeRange { start, step, front: 0, back }
+    }
+
+    fn nth_datetime(&self, index: i64) -> LocalDateTime {
+        let ms = self.start + index * self.step;
+        LocalDateTime::at_ms(ms.div_euclid(1000), ms.rem_euclid(1000) as i16)
+    }
+}
+
+fn milliseconds_since_epoch(datetime: LocalDateTime) -> i64 {
+    let instant = datetime.to_instant();
+    instant.seconds() * 1000 + i64::from(instant.milliseconds())
+}
+
+impl Iterator for DateTimeRange {
+    type Item = LocalDateTime;
+
+    fn next(&mut self) -> Option<LocalDateTime> {
+        if self.front < self.back {
+            self.front += 1;
+            Some(self.nth_datetime(self.front - 1))
+        }
+        else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back - self.front) as usize;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for DateTimeRange {
+    fn next_back(&mut self) -> Option<LocalDateTime> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(self.nth_datetime(self.back))
+        }
+        else {
+            None
+        }
+    }
+}
+
+impl ExactSizeIterator for DateTimeRange {}
+
+impl FusedIterator for DateTimeRange {}
@@ -0,0 +1,961 @@
+//! Recurring events, described using iCalendar recurrence rules.
+//!
+//! This module implements the `RRULE`, `RDATE`, and `EXDATE` properties
+//! from RFC 5545. A `RecurrenceRule` describes a pattern such as “the last
+//! Friday of every month”, and a `Recurrence` combines a start time with
+//! any number of rules, extra dates, and excluded dates.
+//!
+//! ### Examples
+//!
+//! ```
+//! use datetime::{LocalDate, Month};
+//! use datetime::rrule::Recurrence;
+//!
+//! let recurrence: Recurrence = "DTSTART:20170127T090000\n\
+//!                               RRULE:FREQ=MONTHLY;BYDAY=-1FR;COUNT=3".parse().unwrap();
+//!
+//! let dates: Vec<LocalDate> = recurrence.occurrences().map(|o| o.date()).collect();
+//! assert_eq!(dates, vec![
+//!     LocalDate::ymd(2017, Month::January, 27).unwrap(),
+//!     LocalDate::ymd(2017, Month::February, 24).unwrap(),
+//!     LocalDate::ymd(2017, Month::March, 31).unwrap(),
+//! ]);
+//! ```
+
+use std::collections::{BTreeSet, VecDeque};
+use std::error::Error as ErrorTrait;
+use std::fmt;
+use std::iter::Peekable;
+use std::str::FromStr;
+
+use cal::{DatePiece, TimePiece};
+use cal::datetime::{LocalDate, LocalTime, LocalDateTime, Month, Weekday, Year};
+use cal::range::DateRange;
+use cal::zone::{TimeZone, LocalTimes, ZonedDateTime};
+use duration::Duration;
+
+
+/// The last year that occurrences are searched for, so that rules that
+/// can never match don’t search forever.
+const MAX_YEAR: i64 = 9999;
+
+
+/// How often a recurrence rule repeats.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+pub enum Frequency {
+    Secondly,
+    Minutely,
+    Hourly,
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl Frequency {
+
+    /// The length of one period in seconds, for the frequencies shorter
+    /// than a day.
+    fn seconds(self) -> Option<i64> {
+        match self {
+            Frequency::Secondly => Some(1),
+            Frequency::Minutely => Some(60),
+            Frequency::Hourly   => Some(3600),
+            _                   => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Frequency::Secondly => "SECONDLY",
+            Frequency::Minutely => "MINUTELY",
+            Frequency::Hourly   => "HOURLY",
+            Frequency::Daily    => "DAILY",
+            Frequency::Weekly   => "WEEKLY",
+            Frequency::Monthly  => "MONTHLY",
+            Frequency::Yearly   => "YEARLY",
+        }
+    }
+}
+
+
+/// A **recurrence rule** is the pattern of an `RRULE` property, such as
+/// “every other week on Tuesday and Thursday”.
+///
+/// Each `by_` field restricts or expands the set of occurrences in the
+/// same way as the corresponding `BYxxx` rule part. An empty list means
+/// that rule part isn’t present.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct RecurrenceRule {
+
+    /// How often the rule repeats.
+    pub frequency: Frequency,
+
+    /// How many periods of the frequency there are between repeats, so an
+    /// interval of 2 with a weekly frequency means every other week.
+    pub interval: i64,
+
+    /// The total number of occurrences, if limited.
+    pub count: Option<u64>,
+
+    /// The last datetime an occurrence can fall on, if limited.
+    pub until: Option<LocalDateTime>,
+
+    /// Whether `until` is in UTC, as it is when it’s written with a
+    /// trailing `Z`, rather than in the same time zone as the start.
+    pub until_utc: bool,
+
+    pub by_second: Vec<i8>,
+    pub by_minute: Vec<i8>,
+    pub by_hour: Vec<i8>,
+
+    /// Weekdays, each with an optional position within the month or year:
+    /// `(0, Monday)` means every Monday, `(1, Monday)` the first Monday,
+    /// and `(-1, Monday)` the last one.
+    pub by_day: Vec<(i8, Weekday)>,
+
+    pub by_month_day: Vec<i8>,
+    pub by_year_day: Vec<i16>,
+    pub by_week_no: Vec<i8>,
+    pub by_month: Vec<Month>,
+    pub by_set_pos: Vec<i16>,
+
+    /// The day that weeks start on, which matters for weekly rules with an
+    /// interval and for week numbers.
+    pub week_start: Weekday,
+}
+
+impl RecurrenceRule {
+
+    /// Creates a new rule that repeats every period of the given frequency
+    /// forever.
+    pub fn new(frequency: Frequency) -> RecurrenceRule {
+        RecurrenceRule {
+            frequency,
+            interval:     1,
+            count:        None,
+            until:        None,
+            until_utc:    false,
+            by_second:    Vec::new(),
+            by_minute:    Vec::new(),
+            by_hour:      Vec::new(),
+            by_day:       Vec::new(),
+            by_month_day: Vec::new(),
+            by_year_day:  Vec::new(),
+            by_week_no:   Vec::new(),
+            by_month:     Vec::new(),
+            by_set_pos:   Vec::new(),
+            week_start:   Weekday::Monday,
+        }
+    }
+
+    /// Returns an iterator over the occurrences of this rule, beginning at
+    /// the given start datetime.
+    ///
+    /// The start is only an occurrence itself if it matches the rule. Any
+    /// parts of the rule that are missing are taken from the start, so a
+    /// monthly rule with no other parts repeats on the start’s day of the
+    /// month, at the start’s time of day.
+    pub fn occurrences(&self, start: LocalDateTime) -> RuleOccurrences {
+        let mut rule = self.clone();
+
+        // Fill in the day of the month or week from the start date, as
+        // described in RFC 5545.
+        if rule.by_week_no.is_empty() && rule.by_year_day.is_empty()
+        && rule.by_month_day.is_empty() && rule.by_day.is_empty() {
+            match rule.frequency {
+                Frequency::Yearly => {
+                    if rule.by_month.is_empty() {
+                        rule.by_month.push(start.month());
+                    }
+                    rule.by_month_day.push(start.day());
+                },
+                Frequency::Monthly => rule.by_month_day.push(start.day()),
+                Frequency::Weekly  => rule.by_day.push((0, start.weekday())),
+                _                  => {},
+            }
+        }
+
+        // A rule that repeats more than once a day can skip every time of
+        // day it allows, such as every other minute on odd minutes. The
+        // times of day repeat after at most a day’s worth of periods, so
+        // only those need checking.
+        let finished = match rule.sub_daily_periods(start) {
+            Some((base, step)) => {
+                let cycle = 86400 / gcd(step, 86400);
+                !(0 .. cycle).any(|n| rule.allows_time(LocalDateTime::at(base + n * step)))
+            },
+            None => false,
+        };
+
+        RuleOccurrences {
+            rule,
+            start,
+            period: 0,
+            buffer: VecDeque::new(),
+            emitted: 0,
+            finished,
+        }
+    }
+
+    /// Returns the start of the first period and the number of seconds
+    /// between periods, for rules that repeat more than once a day.
+    fn sub_daily_periods(&self, start: LocalDateTime) -> Option<(i64, i64)> {
+        let unit = self.frequency.seconds()?;
+        let start_seconds = start.to_instant().seconds();
+        Some((start_seconds - start_seconds.rem_euclid(unit), unit * self.interval))
+    }
+
+    /// Returns whether the time parts of this rule allow a period of a
+    /// rule that repeats more than once a day to start at the time of the
+    /// given datetime.
+    fn allows_time(&self, datetime: LocalDateTime) -> bool {
+        let allows = |list: &Vec<i8>, value: i8| list.is_empty() || list.contains(&value);
+
+        allows(&self.by_hour, datetime.hour())
+        && (self.frequency > Frequency::Minutely || allows(&self.by_minute, datetime.minute()))
+        && (self.frequency > Frequency::Secondly || allows(&self.by_second, datetime.second()))
+    }
+
+    /// Returns whether a date passes the date-based parts of this rule.
+    fn matches_date(&self, date: LocalDate) -> bool {
+        let year_length = if Year(date.year()).is_leap_year() { 366 } else { 365 };
+        let month_length = date.year_month().day_count() as i64;
+
+        if !self.by_month.is_empty() && !self.by_month.contains(&date.month()) {
+            return false;
+        }
+
+        if !self.by_week_no.is_empty() && !self.matches_week_no(date) {
+            return false;
+        }
+
+        if !self.by_year_day.is_empty() && !self.by_year_day.iter().any(|d| matches_position(*d as i64, date.yearday() as i64, year_length)) {
+            return false;
+        }
+
+        if !self.by_month_day.is_empty() && !self.by_month_day.iter().any(|d| matches_position(*d as i64, date.day() as i64, month_length)) {
+            return false;
+        }
+
+        if !self.by_day.is_empty() {
+            // Positions count within the month for monthly rules, or yearly
+            // rules restricted to some months, and within the year for
+            // other yearly rules. They are ignored otherwise.
+            let (index, length) = match self.frequency {
+                Frequency::Monthly => (date.day() as i64, month_length),
+                Frequency::Yearly if !self.by_month.is_empty() => (date.day() as i64, month_length),
+                Frequency::Yearly if self.by_week_no.is_empty() => (date.yearday() as i64, year_length),
+                _ => (0, 0),
+            };
+
+            let matches = self.by_day.iter().any(|&(n, weekday)| {
+                weekday == date.weekday() && (n == 0 || length == 0 || nth_matches(n as i64, index, length))
+            });
+
+            if !matches {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Returns whether a date falls in one of the weeks in `by_week_no`,
+    /// using this rule’s week start.
+    fn matches_week_no(&self, date: LocalDate) -> bool {
+        let (week_year, week) = week_number(date, self.week_start);
+        let weeks = week_count(week_year, self.week_start);
+        self.by_week_no.iter().any(|w| matches_position(*w as i64, week, weeks))
+    }
+
+    /// Returns the times of day that occurrences happen on. Fields of the
+    /// time that are fixed by the current period are given, and the rest
+    /// come from the rule, or the start time if the rule doesn’t say.
+    fn times(&self, start: LocalDateTime, fixed: (Option<i8>, Option<i8>, Option<i8>)) -> Vec<LocalTime> {
+        let pick = |fixed: Option<i8>, list: &Vec<i8>, default: i8| -> Vec<i8> {
+            match fixed {
+                Some(value) if list.is_empty() || list.contains(&value) => vec![ value ],
+                Some(_)                                                  => Vec::new(),
+                None if list.is_empty()                                  => vec![ default ],
+                None                                                     => list.clone(),
+            }
+        };
+
+        let hours   = pick(fixed.0, &self.by_hour,   start.hour());
+        let minutes = pick(fixed.1, &self.by_minute, start.minute());
+        let seconds = pick(fixed.2, &self.by_second, start.second());
+
+        let mut times = Vec::new();
+        for h in &hours {
+            for m in &minutes {
+                for s in &seconds {
+                    if let Ok(time) = LocalTime::hms(*h, *m, *s) {
+                        times.push(time);
+                    }
+                }
+            }
+        }
+
+        times.sort();
+        times
+    }
+}
+
+/// Returns whether a position from a rule part, which counts from the end
+/// if it’s negative, refers to the given index in a span of the given
+/// length. Both the index and the position count from 1.
+fn matches_position(position: i64, index: i64, length: i64) -> bool {
+    if position > 0 { position == index } else { length + 1 + position == index }
+}
+
+/// Returns whether a weekday in the given position of a span is its nth
+/// occurrence, with negative numbers counting from the end.
+fn nth_matches(n: i64, index: i64, length: i64) -> bool {
+    if n > 0 { (index - 1) / 7 + 1 == n } else { -((length - index) / 7 + 1) == n }
+}
+
+/// Returns the week-numbering year and week number that a date falls in,
+/// where week 1 is the first week with at least four days in the year.
+fn week_number(date: LocalDate, week_start: Weekday) -> (i64, i64) {
+    let year = date.year();
+
+    for &week_year in &[ year + 1, year, year - 1 ] {
+        let first = first_week_start(week_year, week_start);
+        if date >= first {
+            return (week_year, first.days_until(date) / 7 + 1);
+        }
+    }
+
+    unreachable!()
+}
+
+/// Returns the greatest common divisor of two positive numbers.
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// Returns the number of weeks in a week-numbering year.
+fn week_count(year: i64, week_start: Weekday) -> i64 {
+    first_week_start(year, week_start).days_until(first_week_start(year + 1, week_start)) / 7
+}
+
+/// Returns the first day of week 1 of the given year.
+fn first_week_start(year: i64, week_start: Weekday) -> LocalDate {
+    let jan_1 = LocalDate::ymd(year, Month::January, 1).unwrap();
+    let offset = jan_1.weekday().days_since(week_start) as i64;
+    if offset <= 3 { jan_1.add_days(-offset) } else { jan_1.add_days(7 - offset) }
+}
+
+
+/// An iterator over the occurrences of a single recurrence rule.
+///
+/// Use the `occurrences` method on `RecurrenceRule` to create instances of
+/// this iterator.
+#[derive(Debug, Clone)]
+pub struct RuleOccurrences {
+    rule: RecurrenceRule,
+    start: LocalDateTime,
+    period: i64,
+    buffer: VecDeque<LocalDateTime>,
+    emitted: u64,
+    finished: bool,
+}
+
+impl RuleOccurrences {
+
+    /// Works out every occurrence in the next period of the rule’s
+    /// frequency, returning `None` once the periods go past `MAX_YEAR`.
+    fn next_period(&mut self) -> Option<Vec<LocalDateTime>> {
+        let rule = &self.rule;
+        let start = self.start;
+        let step = self.period * rule.interval;
+        self.period += 1;
+
+        let days: Vec<LocalDate> = match rule.frequency {
+            Frequency::Yearly => {
+                let year = start.year() + step;
+                let jan_1 = LocalDate::ymd(year, Month::January, 1).ok()?;
+                DateRange::new(jan_1, LocalDate::ymd(year + 1, Month::January, 1).ok()?).collect()
+            },
+            Frequency::Monthly => {
+                start.date().year_month().add_months(step).days(..).collect()
+            },
+            Frequency::Weekly => {
+                let first = start.date().weekday_on_or_before(rule.week_start).add_weeks(step);
+                DateRange::new(first, first.add_weeks(1)).collect()
+            },
+            Frequency::Daily => {
+                vec![ start.date().add_days(step) ]
+            },
+            Frequency::Hourly | Frequency::Minutely | Frequency::Secondly => {
+                return self.next_sub_daily_period();
+            },
+        };
+
+        if days.first().is_none_or(|d| d.year() > MAX_YEAR) {
+            return None;
+        }
+
+        let times = rule.times(start, (None, None, None));
+        let mut set = Vec::new();
+        for day in days.into_iter().filter(|d| rule.matches_date(*d)) {
+            for time in &times {
+                set.push(LocalDateTime::new(day, *time));
+            }
+        }
+
+        Some(self.select_positions(set))
+    }
+
+    /// Works out the occurrences in the next period for rules that repeat
+    /// more than once a day. Days that don’t pass the rule’s date parts are
+    /// skipped over entirely, as are periods that start at a time of day
+    /// the rule’s time parts don’t allow.
+    fn next_sub_daily_period(&mut self) -> Option<Vec<LocalDateTime>> {
+        let (base, step) = self.rule.sub_daily_periods(self.start).unwrap();
+
+        let datetime = loop {
+            let seconds = base + (self.period - 1) * step;
+            let datetime = LocalDateTime::at(seconds);
+            if datetime.year() > MAX_YEAR {
+                return None;
+            }
+
+            if !self.rule.matches_date(datetime.date()) {
+                let next_midnight = (seconds.div_euclid(86400) + 1) * 86400;
+                self.period = (next_midnight - base + step - 1) / step;
+                return Some(Vec::new());
+            }
+
+            // Rules with no allowed times were finished before they
+            // started, so one comes up before the times of day repeat.
+            if self.rule.allows_time(datetime) {
+                break datetime;
+            }
+
+            self.period += 1;
+        };
+
+        let fixed = match self.rule.frequency {
+            Frequency::Hourly   => (Some(datetime.hour()), None, None),
+            Frequency::Minutely => (Some(datetime.hour()), Some(datetime.minute()), None),
+            _                   => (Some(datetime.hour()), Some(datetime.minute()), Some(datetime.second())),
+        };
+
+        let set = self.rule.times(self.start, fixed).into_iter()
+                      .map(|time| LocalDateTime::new(datetime.date(), time))
+                      .collect();
+
+        Some(self.select_positions(set))
+    }
+
+    /// Applies the `by_set_pos` part of the rule to the occurrences in one
+    /// period.
+    fn select_positions(&self, mut set: Vec<LocalDateTime>) -> Vec<LocalDateTime> {
+        set.sort();
+
+        if self.rule.by_set_pos.is_empty() {
+            return set;
+        }
+
+        let length = set.len() as i64;
+        let mut selected: Vec<LocalDateTime> = self.rule.by_set_pos.iter().filter_map(|p| {
+            let index = if *p > 0 { *p as i64 - 1 } else { length + *p as i64 };
+            if index >= 0 && index < length { Some(set[index as usize]) } else { None }
+        }).collect();
+
+        selected.sort();
+        selected.dedup();
+        selected
+    }
+}
+
+impl Iterator for RuleOccurrences {
+    type Item = LocalDateTime;
+
+    fn next(&mut self) -> Option<LocalDateTime> {
+        loop {
+            if let Some(datetime) = self.buffer.pop_front() {
+                if self.rule.until.is_some_and(|until| datetime > until)
+                || self.rule.count.is_some_and(|count| self.emitted >= count) {
+                    self.finished = true;
+                    self.buffer.clear();
+                    return None;
+                }
+
+                self.emitted += 1;
+                return Some(datetime);
+            }
+
+            if self.finished {
+                return None;
+            }
+
+            match self.next_period() {
+                Some(set) => {
+                    let start = self.start;
+                    self.buffer.extend(set.into_iter().filter(|d| *d >= start));
+                },
+                None => self.finished = true,
+            }
+        }
+    }
+}
+
+
+/// A **recurrence** is a complete set of repeating occurrences: a start
+/// datetime, the rules that it repeats by, and any extra or excluded
+/// datetimes, as given by the `DTSTART`, `RRULE`, `RDATE`, and `EXDATE`
+/// properties of an iCalendar event.
+///
+/// Recurrences can be parsed from the text of these properties, one per
+/// line. Parameters such as `VALUE=DATE` are understood, and a `TZID`
+/// parameter on `DTSTART` is kept in the `time_zone` field so the zone can
+/// be looked up and passed to `zoned_occurrences`.
+///
+/// As RFC 5545 says, the start is always the first occurrence, even when
+/// none of the rules produce it. An `EXDATE` for the start leaves it out.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Recurrence {
+
+    /// The first occurrence, which all the rules are based on.
+    pub start: LocalDateTime,
+
+    /// The name of the time zone the datetimes are in, if known.
+    pub time_zone: Option<String>,
+
+    /// The rules this recurrence repeats by.
+    pub rules: Vec<RecurrenceRule>,
+
+    /// Extra datetimes that are always occurrences.
+    pub dates: Vec<LocalDateTime>,
+
+    /// Datetimes that are never occurrences, even if a rule produces them.
+    pub exceptions: Vec<LocalDateTime>,
+}
+
+impl Recurrence {
+
+    /// Creates a new recurrence with the given start and no rules, so the
+    /// start is its only occurrence.
+    pub fn new(start: LocalDateTime) -> Recurrence {
+        Recurrence {
+            start,
+            time_zone:  None,
+            rules:      Vec::new(),
+            dates:      vec![ start ],
+            exceptions: Vec::new(),
+        }
+    }
+
+    /// Returns an iterator over every occurrence of this recurrence, in
+    /// order, without duplicates.
+    ///
+    /// Without a time zone, an `until` in UTC is compared as though it were
+    /// in the same time zone as the start, which is only right when the
+    /// start is in UTC too.
+    pub fn occurrences(&self) -> Occurrences {
+        self.occurrences_of(&self.rules)
+    }
+
+    /// Returns an iterator over the occurrences of this recurrence, using
+    /// the given rules in place of its own.
+    fn occurrences_of(&self, rules: &[RecurrenceRule]) -> Occurrences {
+        let mut dates = self.dates.clone();
+        dates.sort();
+
+        // RFC 5545 counts the start as the first occurrence of every rule,
+        // even one that doesn’t produce it, so those rules have one fewer
+        // occurrence of their own.
+        let start_counts = self.dates.contains(&self.start);
+        let rules = rules.iter().map(|rule| {
+            let mut rule = rule.clone();
+            if let Some(count) = rule.count {
+                if start_counts && rule.occurrences(self.start).next() != Some(self.start) {
+                    rule.count = Some(count.saturating_sub(1));
+                }
+            }
+            rule.occurrences(self.start).peekable()
+        });
+
+        Occurrences {
+            rules:      rules.collect(),
+            dates:      dates.into_iter().peekable(),
+            exceptions: self.exceptions.iter().cloned().collect(),
+            last:       None,
+        }
+    }
+
+    /// Returns an iterator over every occurrence of this recurrence in the
+    /// given time zone.
+    ///
+    /// As described in RFC 5545, occurrences that fall in a gap when the
+    /// clocks go forward happen at the same time of day using the offset
+    /// from before the gap, which puts them after it, and occurrences that
+    /// happen twice when the clocks go back happen on the first of the two.
+    pub fn zoned_occurrences<'z>(&self, time_zone: &'z TimeZone) -> ZonedOccurrences<'z> {
+        // Move any end times in UTC into the zone, so they can be compared
+        // with the local occurrences.
+        let rules: Vec<RecurrenceRule> = self.rules.iter().cloned().map(|mut rule| {
+            if rule.until_utc {
+                rule.until = rule.until.map(|until| time_zone.to_zoned(until));
+                rule.until_utc = false;
+            }
+            rule
+        }).collect();
+
+        ZonedOccurrences { occurrences: self.occurrences_of(&rules), time_zone }
+    }
+}
+
+
+/// An iterator over the occurrences of a `Recurrence`.
+#[derive(Debug)]
+pub struct Occurrences {
+    rules: Vec<Peekable<RuleOccurrences>>,
+    dates: Peekable<::std::vec::IntoIter<LocalDateTime>>,
+    exceptions: BTreeSet<LocalDateTime>,
+    last: Option<LocalDateTime>,
+}
+
+impl Iterator for Occurrences {
+    type Item = LocalDateTime;
+
+    fn next(&mut self) -> Option<LocalDateTime> {
+        loop {
+            let earliest_rule = self.rules.iter_mut()
+                                    .enumerate()
+                                    .filter_map(|(i, r)| r.peek().map(|d| (*d, i)))
+                                    .min();
+
+            let datetime = match (earliest_rule, self.dates.peek().cloned()) {
+                (Some((r, _)), Some(d)) if d <= r => self.dates.next(),
+                (Some((_, i)), _)                 => self.rules[i].next(),
+                (None, Some(_))                   => self.dates.next(),
+                (None, None)                      => return None,
+            }.unwrap();
+
+            if self.last == Some(datetime) || self.exceptions.contains(&datetime) {
+                continue;
+            }
+
+            self.last = Some(datetime);
+            return Some(datetime);
+        }
+    }
+}
+
+
+/// An iterator over the occurrences of a `Recurrence` in a time zone.
+#[derive(Debug)]
+pub struct ZonedOccurrences<'z> {
+    occurrences: Occurrences,
+    time_zone: &'z TimeZone,
+}
+
+impl<'z> Iterator for ZonedOccurrences<'z> {
+    type Item = ZonedDateTime<'z>;
+
+    fn next(&mut self) -> Option<ZonedDateTime<'z>> {
+        let local = self.occurrences.next()?;
+
+        Some(match self.time_zone.convert_local(local) {
+            LocalTimes::Precise(zoned)            => zoned,
+            LocalTimes::Ambiguous { earlier, .. } => earlier,
+            LocalTimes::Impossible => {
+                // Use the offset from a day earlier, which is before the
+                // gap, to find the instant, then show that instant in the
+                // zone, which is after the gap.
+                let offset = self.time_zone.offset(local - Duration::of(86400));
+                let wall = self.time_zone.to_zoned(local - Duration::of(offset));
+                match self.time_zone.convert_local(wall) {
+                    LocalTimes::Precise(zoned)            => zoned,
+                    LocalTimes::Ambiguous { earlier, .. } => earlier,
+                    LocalTimes::Impossible                => return self.next(),
+                }
+            },
+        })
+    }
+}
+
+
+impl FromStr for RecurrenceRule {
+    type Err = RecurrenceError;
+
+    fn from_str(input: &str) -> Result<RecurrenceRule, RecurrenceError> {
+        let input = input.trim();
+        let input = input.strip_prefix("RRULE:").unwrap_or(input);
+
+        let mut frequency = None;
+        let mut rule = RecurrenceRule::new(Frequency::Yearly);
+
+        for part in input.split(';').filter(|p| !p.is_empty()) {
+            let (name, value) = match part.find('=') {
+                Some(index) => (&part[.. index], &part[index + 1 ..]),
+                None        => return Err(RecurrenceError::UnknownPart(part.to_owned())),
+            };
+
+            let invalid = || RecurrenceError::InvalidValue { part: name.to_owned(), value: value.to_owned() };
+
+            match &*name.to_uppercase() {
+                "FREQ" => {
+                    frequency = Some(match &*value.to_uppercase() {
+                        "SECONDLY" => Frequency::Secondly,
+                        "MINUTELY" => Frequency::Minutely,
+                        "HOURLY"   => Frequency::Hourly,
+                        "DAILY"    => Frequency::Daily,
+                        "WEEKLY"   => Frequency::Weekly,
+                        "MONTHLY"  => Frequency::Monthly,
+                        "YEARLY"   => Frequency::Yearly,
+                        _          => return Err(invalid()),
+                    });
+                },
+                "INTERVAL"   => rule.interval = value.parse().ok().filter(|i| *i > 0).ok_or_else(invalid)?,
+                "COUNT"      => rule.count = Some(value.parse().map_err(|_| invalid())?),
+                "UNTIL"      => {
+                    rule.until = Some(parse_datetime(value).ok_or_else(invalid)?);
+                    rule.until_utc = value.trim().ends_with('Z');
+                },
+                "BYSECOND"   => rule.by_second = parse_list(value, 0, 60).ok_or_else(invalid)?,
+                "BYMINUTE"   => rule.by_minute = parse_list(value, 0, 59).ok_or_else(invalid)?,
+                "BYHOUR"     => rule.by_hour = parse_list(value, 0, 23).ok_or_else(invalid)?,
+                "BYMONTHDAY" => rule.by_month_day = parse_signed_list(value, 31).ok_or_else(invalid)?,
+                "BYYEARDAY"  => rule.by_year_day = parse_signed_list(value, 366).ok_or_else(invalid)?,
+                "BYWEEKNO"   => rule.by_week_no = parse_signed_list(value, 53).ok_or_else(invalid)?,
+                "BYSETPOS"   => rule.by_set_pos = parse_signed_list(value, 366).ok_or_else(invalid)?,
+                "BYMONTH"    => {
+                    let months: Vec<i8> = parse_list(value, 1, 12).ok_or_else(invalid)?;
+                    rule.by_month = months.into_iter().map(|m| Month::from_one(m).unwrap()).collect();
+                },
+                "BYDAY" => {
+                    rule.by_day = value.split(',').map(parse_weekday_num).collect::<Option<_>>().ok_or_else(invalid)?;
+                },
+                "WKST" => rule.week_start = parse_weekday(value).ok_or_else(invalid)?,
+                _      => return Err(RecurrenceError::UnknownPart(name.to_owned())),
+            }
+        }
+
+        if rule.count.is_some() && rule.until.is_some() {
+            return Err(RecurrenceError::CountWithUntil);
+        }
+
+        rule.frequency = frequency.ok_or(RecurrenceError::MissingFrequency)?;
+        Ok(rule)
+    }
+}
+
+impl fmt::Display for RecurrenceRule {
+
+    /// Writes this rule in the format of an `RRULE` property value.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fn list<T: fmt::Display>(f: &mut fmt::Formatter, name: &str, values: &[T]) -> fmt::Result {
+            if values.is_empty() {
+                return Ok(());
+            }
+
+            write!(f, ";{}=", name)?;
+            for (i, value) in values.iter().enumerate() {
+                if i > 0 { write!(f, ",")?; }
+                write!(f, "{}", value)?;
+            }
+            Ok(())
+        }
+
+        write!(f, "FREQ={}", self.frequency.name())?;
+
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+
+        if let Some(count) = self.count {
+            write!(f, ";COUNT={}", count)?;
+        }
+
+        if let Some(until) = self.until {
+            write!(f, ";UNTIL={:04}{:02}{:02}T{:02}{:02}{:02}",
+                   until.year(), until.month().months_from_january() + 1, until.day(),
+                   until.hour(), until.minute(), until.second())?;
+
+            if self.until_utc {
+                write!(f, "Z")?;
+            }
+        }
+
+        let months: Vec<usize> = self.by_month.iter().map(|m| m.months_from_january() + 1).collect();
+        let days: Vec<String> = self.by_day.iter().map(|&(n, weekday)| {
+            if n == 0 { weekday_code(weekday).to_owned() } else { format!("{}{}", n, weekday_code(weekday)) }
+        }).collect();
+
+        list(f, "BYMONTH",    &months)?;
+        list(f, "BYWEEKNO",   &self.by_week_no)?;
+        list(f, "BYYEARDAY",  &self.by_year_day)?;
+        list(f, "BYMONTHDAY", &self.by_month_day)?;
+        list(f, "BYDAY",      &days)?;
+        list(f, "BYHOUR",     &self.by_hour)?;
+        list(f, "BYMINUTE",   &self.by_minute)?;
+        list(f, "BYSECOND",   &self.by_second)?;
+        list(f, "BYSETPOS",   &self.by_set_pos)?;
+
+        if self.week_start != Weekday::Monday {
+            write!(f, ";WKST={}", weekday_code(self.week_start))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Recurrence {
+    type Err = RecurrenceError;
+
+    fn from_str(input: &str) -> Result<Recurrence, RecurrenceError> {
+        let mut recurrence: Option<Recurrence> = None;
+        let mut rules = Vec::new();
+        let mut dates = Vec::new();
+        let mut exceptions = Vec::new();
+
+        for line in input.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            let colon = line.find(':').ok_or_else(|| RecurrenceError::UnknownProperty(line.to_owned()))?;
+            let (name_and_params, value) = (&line[.. colon], &line[colon + 1 ..]);
+
+            let mut params = name_and_params.split(';');
+            let name = params.next().unwrap().to_uppercase();
+            let time_zone = params.filter_map(|p| p.strip_prefix("TZID=")).next().map(str::to_owned);
+
+            let invalid = || RecurrenceError::InvalidValue { part: name.clone(), value: value.to_owned() };
+            let parse_dates = |value: &str| value.split(',').map(parse_datetime).collect::<Option<Vec<_>>>().ok_or_else(invalid);
+
+            match &*name {
+                "DTSTART" => {
+                    let start = parse_datetime(value).ok_or_else(invalid)?;
+                    recurrence = Some(Recurrence { time_zone, ..Recurrence::new(start) });
+                },
+                "RRULE"  => rules.push(value.parse()?),
+                "RDATE"  => dates.extend(parse_dates(value)?),
+                "EXDATE" => exceptions.extend(parse_dates(value)?),
+                _        => return Err(RecurrenceError::UnknownProperty(name)),
+            }
+        }
+
+        let mut recurrence = recurrence.ok_or(RecurrenceError::MissingStart)?;
+        recurrence.rules = rules;
+        recurrence.dates.extend(dates);
+        recurrence.exceptions = exceptions;
+        Ok(recurrence)
+    }
+}
+
+/// Parses a date or datetime in the basic format used by iCalendar, such
+/// as `19970902` or `19970902T090000Z`. UTC datetimes are returned as
+/// they are, so callers that care must check for the `Z` themselves.
+fn parse_datetime(input: &str) -> Option<LocalDateTime> {
+    let input = input.trim();
+    let input = input.strip_suffix('Z').unwrap_or(input);
+
+    let number = |range: ::std::ops::Range<usize>| -> Option<i64> {
+        let digits = input.get(range)?;
+        if digits.bytes().all(|b| b.is_ascii_digit()) { digits.parse().ok() } else { None }
+    };
+
+    let month = Month::from_one(number(4..6)? as i8).ok()?;
+    let date = LocalDate::ymd(number(0..4)?, month, number(6..8)? as i8).ok()?;
+
+    let time = match input.len() {
+        8  => LocalTime::midnight(),
+        15 if input.get(8..9) == Some("T") => LocalTime::hms(number(9..11)? as i8, number(11..13)? as i8, number(13..15)? as i8).ok()?,
+        _  => return None,
+    };
+
+    Some(LocalDateTime::new(date, time))
+}
+
+/// Parses a comma-separated list of numbers within a range.
+fn parse_list(input: &str, min: i8, max: i8) -> Option<Vec<i8>> {
+    input.split(',')
+         .map(|n| n.trim().parse().ok().filter(|n| *n >= min && *n <= max))
+         .collect()
+}
+
+/// Parses a comma-separated list of non-zero numbers that can count back
+/// from the end, so they are between `-max` and `max`.
+fn parse_signed_list<T>(input: &str, max: i16) -> Option<Vec<T>>
+where T: FromStr + Into<i16> + Copy {
+    input.split(',')
+         .map(|n| n.trim().parse::<T>().ok().filter(|n| { let n: i16 = (*n).into(); n != 0 && n.abs() <= max }))
+         .collect()
+}
+
+/// Parses a weekday with an optional position, such as `MO` or `-1FR`.
+fn parse_weekday_num(input: &str) -> Option<(i8, Weekday)> {
+    let input = input.trim();
+    let split = input.len().checked_sub(2)?;
+    if !input.is_char_boundary(split) {
+        return None;
+    }
+
+    let weekday = parse_weekday(&input[split ..])?;
+    let n = match &input[.. split] {
+        "" => 0,
+        n  => n.parse().ok().filter(|n: &i8| *n != 0 && n.abs() <= 53)?,
+    };
+
+    Some((n, weekday))
+}
+
+fn parse_weekday(input: &str) -> Option<Weekday> {
+    Some(match &*input.to_uppercase() {
+        "SU" => Weekday::Sunday,    "MO" => Weekday::Monday,
+        "TU" => Weekday::Tuesday,   "WE" => Weekday::Wednesday,
+        "TH" => Weekday::Thursday,  "FR" => Weekday::Friday,
+        "SA" => Weekday::Saturday,  _    => return None,
+    })
+}
+
+fn weekday_code(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Sunday    => "SU",  Weekday::Monday   => "MO",
+        Weekday::Tuesday   => "TU",  Weekday::Wednesday => "WE",
+        Weekday::Thursday  => "TH",  Weekday::Friday   => "FR",
+        Weekday::Saturday  => "SA",
+    }
+}
+
+
+/// An error that occurs when parsing a recurrence rule or recurrence.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum RecurrenceError {
+
+    /// The rule has no `FREQ` part.
+    MissingFrequency,
+
+    /// The recurrence has no `DTSTART` property.
+    MissingStart,
+
+    /// The rule has both a `COUNT` and an `UNTIL` part, which RFC 5545
+    /// doesn’t allow.
+    CountWithUntil,
+
+    /// The rule has a part that isn’t recognised.
+    UnknownPart(String),
+
+    /// The recurrence has a property that isn’t recognised.
+    UnknownProperty(String),
+
+    /// A part or property has a value that isn’t valid for it.
+    InvalidValue { part: String, value: String },
+}
+
+impl fmt::Display for RecurrenceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RecurrenceError::MissingFrequency           => write!(f, "recurrence rule has no FREQ part"),
+            RecurrenceError::MissingStart               => write!(f, "recurrence has no DTSTART property"),
+            RecurrenceError::CountWithUntil             => write!(f, "recurrence rule has both COUNT and UNTIL parts"),
+            RecurrenceError::UnknownPart(ref name)      => write!(f, "unknown recurrence rule part {:?}", name),
+            RecurrenceError::UnknownProperty(ref name)  => write!(f, "unknown recurrence property {:?}", name),
+            RecurrenceError::InvalidValue { ref part, ref value } => write!(f, "invalid value {:?} for {}", value, part),
+        }
+    }
+}
+
+impl ErrorTrait for RecurrenceError {}
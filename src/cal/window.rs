@@ -0,0 +1,283 @@
+//! Windows of time, for grouping instants into buckets.
+//!
+//! A `FixedWindows` value divides the timeline into windows of the same
+//! length, which can either follow on from each other (tumbling windows)
+//! or overlap (sliding windows). A `DailyWindows` value divides it into
+//! local days in a time zone, which aren’t always 24 hours long.
+//!
+//! ### Examples
+//!
+//! ```
+//! use datetime::{Instant, Duration};
+//! use datetime::window::{FixedWindows, Window};
+//!
+//! let five_minutes = FixedWindows::tumbling(Duration::of(300));
+//!
+//! assert_eq!(five_minutes.window(Instant::at(1_500_000_123)),
+//!            Window { start: Instant::at(1_500_000_000), end: Instant::at(1_500_000_300) });
+//! ```
+
+use std::iter::FusedIterator;
+
+use cal::datetime::{LocalDate, LocalDateTime};
+use cal::zone::TimeZone;
+use duration::Duration;
+use instant::Instant;
+
+
+/// A window of time, starting at one instant and lasting up to but not
+/// including another.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct Window {
+
+    /// The first instant in the window.
+    pub start: Instant,
+
+    /// The instant just after the window.
+    pub end: Instant,
+}
+
+impl Window {
+
+    /// Returns whether the given instant is in this window.
+    pub fn contains(&self, instant: Instant) -> bool {
+        self.start <= instant && instant < self.end
+    }
+
+    /// Returns the length of this window.
+    pub fn duration(&self) -> Duration {
+        let length = milliseconds(self.end) - milliseconds(self.start);
+        Duration::of_ms(length.div_euclid(1000), length.rem_euclid(1000) as i16)
+    }
+}
+
+fn milliseconds(instant: Instant) -> i64 {
+    instant.seconds() * 1000 + i64::from(instant.milliseconds())
+}
+
+fn instant(milliseconds: i64) -> Instant {
+    Instant::at_ms(milliseconds.div_euclid(1000), milliseconds.rem_euclid(1000) as i16)
+}
+
+
+/// Windows of a fixed length, starting at regular intervals from an
+/// origin instant, which is the Unix epoch unless given otherwise.
+///
+/// Tumbling windows start one after another, so every instant is in
+/// exactly one window. Sliding windows start more often than they last,
+/// so every instant is in several of them.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct FixedWindows {
+    size: i64,
+    step: i64,
+    origin: i64,
+}
+
+impl FixedWindows {
+
+    /// Creates tumbling windows of the given length.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if the length isn’t positive.
+    pub fn tumbling(size: Duration) -> FixedWindows {
+        FixedWindows::sliding(size, size)
+    }
+
+    /// Creates sliding windows of the given length, with a new window
+    /// starting every `step`.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if the length or the step aren’t positive.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{Instant, Duration};
+    /// use datetime::window::FixedWindows;
+    ///
+    /// // Ten-minute windows, starting every five minutes.
+    /// let windows = FixedWindows::sliding(Duration::of(600), Duration::of(300));
+    /// let starts: Vec<i64> = windows.windows_containing(Instant::at(1_500_000_123))
+    ///                               .map(|w| w.start.seconds())
+    ///                               .collect();
+    ///
+    /// assert_eq!(starts, vec![ 1_499_999_700, 1_500_000_000 ]);
+    /// ```
+    pub fn sliding(size: Duration, step: Duration) -> FixedWindows {
+        let (size, step) = (duration_milliseconds(size), duration_milliseconds(step));
+        assert!(size > 0 && step > 0, "window length and step must be positive");
+        FixedWindows { size, step, origin: 0 }
+    }
+
+    /// Returns these windows with one of them starting at the given
+    /// instant, instead of at the Unix epoch.
+    pub fn aligned_to(self, origin: Instant) -> FixedWindows {
+        FixedWindows { origin: milliseconds(origin), ..self }
+    }
+
+    /// Returns the window that the given instant is in. For sliding
+    /// windows, this is the last one to start.
+    pub fn window(&self, instant: Instant) -> Window {
+        let index = (milliseconds(instant) - self.origin).div_euclid(self.step);
+        self.nth_window(index)
+    }
+
+    /// Returns an iterator over every window that the given instant is in.
+    pub fn windows_containing(&self, instant: Instant) -> Windows {
+        let ms = milliseconds(instant);
+        self.windows_between_milliseconds(ms, ms + 1)
+    }
+
+    /// Returns an iterator over every window that overlaps the span from
+    /// the start instant up to but not including the end instant.
+    pub fn windows_between(&self, start: Instant, end: Instant) -> Windows {
+        self.windows_between_milliseconds(milliseconds(start), milliseconds(end))
+    }
+
+    fn windows_between_milliseconds(&self, start: i64, end: i64) -> Windows {
+        let front = (start - self.size - self.origin).div_euclid(self.step) + 1;
+        let back  = (end - 1 - self.origin).div_euclid(self.step) + 1;
+        Windows { windows: *self, front, back: if end > start { back.max(front) } else { front } }
+    }
+
+    fn nth_window(&self, index: i64) -> Window {
+        let start = self.origin + index * self.step;
+        Window { start: instant(start), end: instant(start + self.size) }
+    }
+}
+
+fn duration_milliseconds(duration: Duration) -> i64 {
+    let (seconds, milliseconds) = duration.lengths();
+    seconds * 1000 + i64::from(milliseconds)
+}
+
+
+/// An iterator over a run of `FixedWindows`, in order of their start.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Windows {
+    windows: FixedWindows,
+    front: i64,
+    back: i64,
+}
+
+impl Iterator for Windows {
+    type Item = Window;
+
+    fn next(&mut self) -> Option<Window> {
+        if self.front < self.back {
+            self.front += 1;
+            Some(self.windows.nth_window(self.front - 1))
+        }
+        else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back - self.front) as usize;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for Windows {
+    fn next_back(&mut self) -> Option<Window> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(self.windows.nth_window(self.back))
+        }
+        else {
+            None
+        }
+    }
+}
+
+impl ExactSizeIterator for Windows {}
+
+impl FusedIterator for Windows {}
+
+
+/// Windows that each last one local day in a time zone, from the start
+/// of one day to the start of the next.
+///
+/// Days are usually 24 hours long, but are shorter or longer on days when
+/// the clocks change, and start later than midnight when the clocks go
+/// forward at midnight.
+#[derive(Debug, Clone, Copy)]
+pub struct DailyWindows<'z> {
+    time_zone: &'z TimeZone,
+}
+
+impl<'z> DailyWindows<'z> {
+
+    /// Creates daily windows for the given time zone.
+    pub fn new(time_zone: &'z TimeZone) -> DailyWindows<'z> {
+        DailyWindows { time_zone }
+    }
+
+    /// Returns the window for the given local date.
+    pub fn day(&self, date: LocalDate) -> Window {
+        Window {
+            start: self.time_zone.start_of_day(date).to_instant(),
+            end: self.time_zone.start_of_day(date.add_days(1)).to_instant(),
+        }
+    }
+
+    /// Returns the local date of the window that the given instant is in.
+    fn date_of(&self, instant: Instant) -> LocalDate {
+        let mut date = self.time_zone.to_zoned(LocalDateTime::from_instant(instant)).date();
+
+        // The offset lookup can be off by an instant right at a change, so
+        // make sure the day really does contain the instant.
+        while instant < self.day(date).start {
+            date = date.add_days(-1);
+        }
+        while instant >= self.day(date).end {
+            date = date.add_days(1);
+        }
+
+        date
+    }
+
+    /// Returns the window that the given instant is in.
+    pub fn window(&self, instant: Instant) -> Window {
+        self.day(self.date_of(instant))
+    }
+
+    /// Returns an iterator over every day that overlaps the span from the
+    /// start instant up to but not including the end instant.
+    pub fn windows_between(&self, start: Instant, end: Instant) -> DailyWindowIter<'z> {
+        let date = self.date_of(start);
+
+        // An empty span has no windows, which is the same as ending at the
+        // start of the first day.
+        let end = if end > start { end } else { self.day(date).start };
+        DailyWindowIter { windows: *self, date, end }
+    }
+}
+
+
+/// An iterator over a run of `DailyWindows`.
+#[derive(Debug, Clone)]
+pub struct DailyWindowIter<'z> {
+    windows: DailyWindows<'z>,
+    date: LocalDate,
+    end: Instant,
+}
+
+impl<'z> Iterator for DailyWindowIter<'z> {
+    type Item = Window;
+
+    fn next(&mut self) -> Option<Window> {
+        let window = self.windows.day(self.date);
+        if window.start >= self.end {
+            return None;
+        }
+
+        self.date = self.date.add_days(1);
+        Some(window)
+    }
+}
+
+impl<'z> FusedIterator for DailyWindowIter<'z> {}
@@ -5,7 +5,8 @@ use std::sync::Arc;
 
 use duration::Duration;
 use instant::Instant;
-use cal::{LocalDateTime, DatePiece, TimePiece, Month, Weekday};
+use cal::{LocalDate, LocalTime, LocalDateTime, DatePiece, TimePiece, Month, Weekday};
+use cal::datetime::TimeUnit;
 use util::RangeExt;
 
 
@@ -100,6 +101,50 @@ impl TimeZone {
             TimeZoneSource::Runtime(ref arc) => arc.fixed_timespans.borrow().convert_local(local, self.0.clone()),
         }
     }
+
+    /// Returns the first moment of the given date in this time zone. This
+    /// is usually midnight, but in zones where the clocks go forward at
+    /// midnight, the day starts at the time they go forward to.
+    pub fn start_of_day(&self, date: LocalDate) -> ZonedDateTime<'_> {
+        let midnight = LocalDateTime::new(date, LocalTime::midnight());
+        match self.convert_local(midnight) {
+            LocalTimes::Precise(zoned)            => zoned,
+            LocalTimes::Ambiguous { earlier, .. } => earlier,
+            LocalTimes::Impossible                => self.end_of_gap(midnight),
+        }
+    }
+
+    /// Returns the moment the clocks go forward, for a local datetime
+    /// that doesn’t exist because it falls in the gap.
+    pub(crate) fn end_of_gap(&self, local: LocalDateTime) -> ZonedDateTime<'_> {
+        end_of_gap(|l| self.convert_local(l), local)
+    }
+}
+
+/// Returns the first local datetime after the given one that exists,
+/// using the given function to convert local datetimes to zoned ones.
+fn end_of_gap<'a, F>(convert: F, local: LocalDateTime) -> ZonedDateTime<'a>
+where F: Fn(LocalDateTime) -> LocalTimes<'a> {
+    let exists = |seconds| !convert(local + Duration::of(seconds)).is_impossible();
+
+    // Find a time that exists by doubling the distance, then search back
+    // for the first one.
+    let mut high = 1;
+    while !exists(high) {
+        high *= 2;
+    }
+
+    let mut low = high / 2 + 1;
+    while low < high {
+        let middle = low + (high - low) / 2;
+        if exists(middle) { high = middle } else { low = middle + 1 }
+    }
+
+    match convert(local + Duration::of(low)) {
+        LocalTimes::Precise(zoned)            => zoned,
+        LocalTimes::Ambiguous { earlier, .. } => earlier,
+        LocalTimes::Impossible                => unreachable!(),
+    }
 }
 
 
@@ -331,6 +376,53 @@ impl<'a> ZonedDateTime<'a> {
     pub fn to_instant(&self) -> Instant {
         (self.adjusted - Duration::of(self.current_offset)).to_instant()
     }
+
+    /// Returns the date and time that this value shows on a wall clock in
+    /// its time zone.
+    pub fn local(&self) -> LocalDateTime {
+        self.adjusted
+    }
+
+    /// Converts another local datetime into a zoned datetime that uses the
+    /// same time zone as this one. See `TimeZone::convert_local` for why
+    /// this can return zero, one, or two values.
+    pub fn convert_local(&self, local: LocalDateTime) -> LocalTimes<'_> {
+        match self.time_zone {
+            TimeZoneSource::Static(tz)       => tz.fixed_timespans.convert_local(local, self.time_zone.clone()),
+            TimeZoneSource::Runtime(ref arc) => arc.fixed_timespans.borrow().convert_local(local, self.time_zone.clone()),
+        }
+    }
+
+    /// Returns this datetime with its wall-clock time truncated to a
+    /// multiple of the given unit.
+    ///
+    /// If the truncated time doesn’t exist because the clocks went
+    /// forward, the result is the moment they went forward, so truncating
+    /// to a day always gives the real start of the local day. If the
+    /// truncated time happens twice because the clocks went back, units of
+    /// a day or longer give the first one, and shorter units give the one
+    /// on the same side of the change as this datetime.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if the unit’s count isn’t positive.
+    pub fn truncate_to(&self, unit: TimeUnit) -> ZonedDateTime<'a> {
+        let local = self.adjusted.truncate_to(unit);
+
+        let zoned = match self.convert_local(local) {
+            LocalTimes::Precise(zoned) => zoned,
+            LocalTimes::Ambiguous { earlier, later } => {
+                if !unit.is_date_based() && later.to_instant() <= self.to_instant() { later } else { earlier }
+            },
+            LocalTimes::Impossible => end_of_gap(|l| self.convert_local(l), local),
+        };
+
+        ZonedDateTime {
+            adjusted: zoned.adjusted,
+            current_offset: zoned.current_offset,
+            time_zone: self.time_zone.clone(),
+        }
+    }
 }
 
 impl<'a> DatePiece for ZonedDateTime<'a> {
@@ -5,7 +5,7 @@ use std::ops::{Add, Sub, Mul};
 
 /// A **duration** is a length of time on the timeline, irrespective of
 /// time zone or calendar format, with millisecond precision.
-#[derive(Clone, PartialEq, Eq, Debug, Copy)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Copy)]
 pub struct Duration {
     seconds: i64,
     milliseconds: i16,
@@ -3,6 +3,7 @@
 use std::fmt;
 use std::ops::{Add, Sub};
 
+use cal::datetime::{LocalDateTime, TimeUnit};
 use system::sys_time;
 use duration::Duration;
 
@@ -56,6 +57,71 @@ impl Instant {
     pub fn milliseconds(&self) -> i16 {
         self.milliseconds
     }
+
+    /// Returns this instant truncated to a multiple of the given unit,
+    /// with days, months, and years worked out in UTC.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if the unit’s count isn’t positive.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{Instant, TimeUnit};
+    ///
+    /// let instant = Instant::at_ms(1_500_000_000, 750);
+    /// assert_eq!(instant.truncate_to(TimeUnit::Seconds(1)), Instant::at(1_500_000_000));
+    /// assert_eq!(instant.round_to(TimeUnit::Seconds(1)), Instant::at(1_500_000_001));
+    /// assert_eq!(instant.truncate_to(TimeUnit::Hours(1)), Instant::at(1_499_997_600));
+    /// ```
+    pub fn truncate_to(&self, unit: TimeUnit) -> Instant {
+        LocalDateTime::from_instant(*self).truncate_to(unit).to_instant()
+    }
+
+    /// Returns this instant rounded to the nearest multiple of the given
+    /// unit, with halfway values rounding up, and with days, months, and
+    /// years worked out in UTC.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if the unit’s count isn’t positive.
+    pub fn round_to(&self, unit: TimeUnit) -> Instant {
+        LocalDateTime::from_instant(*self).round_to(unit).to_instant()
+    }
+
+    /// Returns the Julian Date of this instant, treating it as UTC.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::Instant;
+    ///
+    /// assert_eq!(Instant::at_epoch().julian_date(), 2_440_587.5);
+    /// assert_eq!(Instant::at_epoch().modified_julian_date(), 40_587.0);
+    /// assert_eq!(Instant::from_julian_date(2_440_588.0), Instant::at(43_200));
+    /// ```
+    pub fn julian_date(&self) -> f64 {
+        LocalDateTime::from_instant(*self).julian_date()
+    }
+
+    /// Computes the instant at the given Julian Date in UTC, to the
+    /// nearest millisecond.
+    pub fn from_julian_date(jd: f64) -> Instant {
+        LocalDateTime::from_julian_date(jd).to_instant()
+    }
+
+    /// Returns the Modified Julian Date of this instant, treating it as
+    /// UTC.
+    pub fn modified_julian_date(&self) -> f64 {
+        LocalDateTime::from_instant(*self).modified_julian_date()
+    }
+
+    /// Computes the instant at the given Modified Julian Date in UTC, to
+    /// the nearest millisecond.
+    pub fn from_modified_julian_date(mjd: f64) -> Instant {
+        LocalDateTime::from_modified_julian_date(mjd).to_instant()
+    }
 }
 
 impl fmt::Debug for Instant {
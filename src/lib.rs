@@ -22,14 +22,29 @@ extern crate iso8601;
 
 mod cal;
 pub use cal::{DatePiece, TimePiece};
-pub use cal::datetime::{LocalDate, LocalTime, LocalDateTime, Month, Weekday, Year, YearMonth};
+pub use cal::datetime::{LocalDate, LocalTime, LocalDateTime, Month, MonthOverflow, TimeUnit, Weekday, Year, YearMonth, YearQuarter, YearWeek};
 pub use cal::fmt::custom as fmt;
+pub use cal::fmt::human;
+pub use cal::fmt::relative;
 pub use cal::fmt::ISO;  // TODO: replace this with just a 'fmt' import
+pub use cal::parse::{DurationError, IntervalError};
 pub use cal::offset::{Offset, OffsetDateTime};
+pub use cal::period::Period;
+pub use cal::range::{DateRange, DateStep, DateTimeRange};
 pub use cal::zone::{TimeZone, ZonedDateTime};
 pub use cal::zone as zone;
 
 pub use cal::convenience;
+pub use cal::business;
+pub use cal::calendar;
+pub use cal::cron;
+pub use cal::era;
+pub use cal::fiscal;
+pub use cal::holidays;
+pub use cal::interval;
+pub use cal::julian;
+pub use cal::window;
+pub use cal::rrule;
 
 mod duration;
 pub use duration::Duration;
@@ -37,6 +52,9 @@ pub use duration::Duration;
 mod instant;
 pub use instant::Instant;
 
+mod monotonic;
+pub use monotonic::{MonotonicTime, Stopwatch};
+
 mod system;
 pub use system::sys_timezone;
 
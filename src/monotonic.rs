@@ -0,0 +1,182 @@
+//! Readings of a clock that only ever moves forwards.
+
+use std::fmt;
+use std::ops::{Add, Sub};
+
+use duration::Duration;
+use system::sys_monotonic_time;
+
+
+/// A **monotonic time** is a reading of the system’s monotonic clock, with
+/// millisecond precision.
+///
+/// Unlike an `Instant`, a monotonic time is not a point on the calendar
+/// timeline: it counts from an unspecified starting point, and is only
+/// useful when compared with, or subtracted from, another monotonic time
+/// taken on the same machine. In exchange, it never jumps backwards or
+/// forwards when the wall clock gets adjusted (say, by NTP), which makes it
+/// the right choice for timeouts, rate limiters, and benchmarks.
+///
+/// There is deliberately no conversion between the two types.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct MonotonicTime {
+    seconds: i64,
+    milliseconds: i16,
+}
+
+impl MonotonicTime {
+
+    /// Reads the monotonic clock.
+    #[cfg_attr(target_os = "redox", allow(unused_unsafe))]
+    pub fn now() -> MonotonicTime {
+        let (seconds, milliseconds) = unsafe { sys_monotonic_time() };
+        MonotonicTime { seconds, milliseconds }
+    }
+
+    /// Returns the amount of time that has passed since this reading was
+    /// taken.
+    pub fn elapsed(&self) -> Duration {
+        MonotonicTime::now() - *self
+    }
+}
+
+impl fmt::Debug for MonotonicTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MonotonicTime({}s/{}ms)", self.seconds, self.milliseconds)
+    }
+}
+
+impl Sub<MonotonicTime> for MonotonicTime {
+    type Output = Duration;
+
+    fn sub(self, earlier: MonotonicTime) -> Duration {
+        Duration::of_ms(self.seconds, self.milliseconds) - Duration::of_ms(earlier.seconds, earlier.milliseconds)
+    }
+}
+
+impl Add<Duration> for MonotonicTime {
+    type Output = MonotonicTime;
+
+    fn add(self, duration: Duration) -> MonotonicTime {
+        let (seconds, milliseconds) = (Duration::of_ms(self.seconds, self.milliseconds) + duration).lengths();
+        MonotonicTime { seconds, milliseconds }
+    }
+}
+
+impl Sub<Duration> for MonotonicTime {
+    type Output = MonotonicTime;
+
+    fn sub(self, duration: Duration) -> MonotonicTime {
+        let (seconds, milliseconds) = (Duration::of_ms(self.seconds, self.milliseconds) - duration).lengths();
+        MonotonicTime { seconds, milliseconds }
+    }
+}
+
+
+/// A **stopwatch** measures elapsed time using the monotonic clock, and
+/// keeps a record of laps as it goes.
+#[derive(Debug, Clone)]
+pub struct Stopwatch {
+    started: MonotonicTime,
+    last_lap: MonotonicTime,
+    laps: Vec<Duration>,
+}
+
+impl Stopwatch {
+
+    /// Creates a new stopwatch that has started running.
+    pub fn start() -> Stopwatch {
+        Stopwatch::started_at(MonotonicTime::now())
+    }
+
+    /// Creates a new stopwatch that started running at the given time.
+    pub fn started_at(start: MonotonicTime) -> Stopwatch {
+        Stopwatch {
+            started: start,
+            last_lap: start,
+            laps: Vec::new(),
+        }
+    }
+
+    /// Returns the time this stopwatch was started.
+    pub fn start_time(&self) -> MonotonicTime {
+        self.started
+    }
+
+    /// Returns the total time that has passed since this stopwatch was
+    /// started, laps included.
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    /// Ends the current lap, returning its length, and starts a new one.
+    pub fn lap(&mut self) -> Duration {
+        self.lap_at(MonotonicTime::now())
+    }
+
+    /// Ends the current lap at the given time, returning its length, and
+    /// starts a new one.
+    ///
+    /// Times earlier than the end of the previous lap produce a lap of
+    /// zero length rather than a negative one.
+    pub fn lap_at(&mut self, now: MonotonicTime) -> Duration {
+        let now = if now < self.last_lap { self.last_lap } else { now };
+        let length = now - self.last_lap;
+        self.last_lap = now;
+        self.laps.push(length);
+        length
+    }
+
+    /// Returns the lengths of all the laps that have been completed so far,
+    /// in order.
+    pub fn laps(&self) -> &[Duration] {
+        &self.laps
+    }
+
+    /// Resets the stopwatch to zero, discarding its laps, and returns the
+    /// total time that had elapsed.
+    pub fn restart(&mut self) -> Duration {
+        let now = MonotonicTime::now();
+        let elapsed = now - self.started;
+        *self = Stopwatch::started_at(now);
+        elapsed
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::{MonotonicTime, Stopwatch};
+    use duration::Duration;
+
+    #[test]
+    fn subtraction_borrows_milliseconds() {
+        let earlier = MonotonicTime { seconds: 10, milliseconds: 900 };
+        let later = MonotonicTime { seconds: 12, milliseconds: 100 };
+        assert_eq!(later - earlier, Duration::of_ms(1, 200));
+    }
+
+    #[test]
+    fn addition_carries_milliseconds() {
+        let time = MonotonicTime { seconds: 10, milliseconds: 900 };
+        assert_eq!(time + Duration::of_ms(0, 250), MonotonicTime { seconds: 11, milliseconds: 150 });
+    }
+
+    #[test]
+    fn laps() {
+        let start = MonotonicTime { seconds: 100, milliseconds: 0 };
+        let mut watch = Stopwatch::started_at(start);
+
+        assert_eq!(watch.lap_at(start + Duration::of(3)), Duration::of(3));
+        assert_eq!(watch.lap_at(start + Duration::of_ms(4, 500)), Duration::of_ms(1, 500));
+        assert_eq!(watch.laps(), &[ Duration::of(3), Duration::of_ms(1, 500) ]);
+    }
+
+    #[test]
+    fn laps_never_go_negative() {
+        let start = MonotonicTime { seconds: 100, milliseconds: 0 };
+        let mut watch = Stopwatch::started_at(start);
+
+        assert_eq!(watch.lap_at(start - Duration::of(1)), Duration::zero());
+    }
+}
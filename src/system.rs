@@ -86,6 +86,54 @@ pub fn sys_time() -> (i64, i16) {
    (ts.tv_sec, (ts.tv_nsec / 1000) as i16)
 }
 
+/// Returns the current reading of the system’s monotonic clock, as a tuple
+/// of seconds and the millisecond of the second.
+///
+/// Unlike `sys_time`, this clock never jumps when the wall-clock time gets
+/// adjusted, but its readings are only meaningful relative to each other:
+/// the point it counts from is unspecified (usually the last boot).
+#[cfg(not(any(target_os = "macos", target_os = "ios", target_os = "redox", windows)))]
+pub unsafe fn sys_monotonic_time() -> (i64, i16) {
+    let mut tv = libc::timespec { tv_sec: 0, tv_nsec: 0 };
+    let _ = clock_gettime(libc::CLOCK_MONOTONIC, &mut tv);
+    (tv.tv_sec as i64, (tv.tv_nsec / 1_000_000) as i16)
+}
+
+/// Returns the current reading of the system’s monotonic clock, as a tuple
+/// of seconds and the millisecond of the second.
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+pub unsafe fn sys_monotonic_time() -> (i64, i16) {
+    let mut info = libc::mach_timebase_info { numer: 0, denom: 0 };
+    let _ = libc::mach_timebase_info(&mut info);
+
+    let nanos = libc::mach_absolute_time() as u128 * info.numer as u128 / info.denom as u128;
+    ((nanos / 1_000_000_000) as i64, (nanos % 1_000_000_000 / 1_000_000) as i16)
+}
+
+/// Returns the current reading of the system’s monotonic clock, as a tuple
+/// of seconds and the millisecond of the second.
+#[cfg(windows)]
+pub unsafe fn sys_monotonic_time() -> (i64, i16) {
+    use kernel32::{QueryPerformanceCounter, QueryPerformanceFrequency};
+
+    let mut ticks = 0;
+    let mut frequency = 0;
+    let _ = QueryPerformanceCounter(&mut ticks);
+    let _ = QueryPerformanceFrequency(&mut frequency);
+
+    (ticks / frequency, (ticks % frequency * 1000 / frequency) as i16)
+}
+
+/// Returns the current reading of the system’s monotonic clock, as a tuple
+/// of seconds and the millisecond of the second.
+#[cfg(target_os = "redox")]
+pub fn sys_monotonic_time() -> (i64, i16) {
+   let mut ts = redox_syscall::TimeSpec::default();
+   let monotonic_clock = redox_syscall::CLOCK_MONOTONIC;
+   let _ = redox_syscall::clock_gettime(monotonic_clock, &mut ts);
+   (ts.tv_sec, (ts.tv_nsec / 1_000_000) as i16)
+}
+
 /// Attempts to determine the system’s current time zone. There’s no
 /// guaranteed way to do this, so this function returns `None` if no
 /// timezone could be found.
@@ -137,7 +185,7 @@ fn is_tz_component(component: &OsStr) -> bool {
 
 #[cfg(test)]
 mod test {
-    use super::{sys_time, extract_timezone};
+    use super::{sys_time, sys_monotonic_time, extract_timezone};
     use std::path::Path;
 
     #[test]
@@ -145,6 +193,14 @@ mod test {
         assert!((0, 0) != unsafe { sys_time() })
     }
 
+    #[test]
+    #[cfg_attr(target_os = "redox", allow(unused_unsafe))]
+    fn monotonic_never_goes_backwards() {
+        let first = unsafe { sys_monotonic_time() };
+        let second = unsafe { sys_monotonic_time() };
+        assert!(first <= second)
+    }
+
     #[test]
     fn two() {
         let timezone = extract_timezone(Path::new("/usr/share/zoneinfo/Europe/London"));
@@ -0,0 +1,181 @@
+extern crate datetime;
+use datetime::{LocalDate, Month, Weekday};
+use datetime::business::{BusinessCalendar, HolidayRule, Observance, Rolling};
+
+fn us_calendar() -> BusinessCalendar {
+    let mut calendar = BusinessCalendar::new();
+    calendar.add_rule(HolidayRule::fixed("New Year’s Day", Month::January, 1).observed(Observance::NearestWeekday));
+    calendar.add_rule(HolidayRule::nth_weekday("Memorial Day", -1, Weekday::Monday, Month::May));
+    calendar.add_rule(HolidayRule::fixed("Independence Day", Month::July, 4).observed(Observance::NearestWeekday));
+    calendar.add_rule(HolidayRule::nth_weekday("Thanksgiving", 4, Weekday::Thursday, Month::November));
+    calendar.add_rule(HolidayRule::fixed("Christmas Day", Month::December, 25).observed(Observance::NearestWeekday));
+    calendar
+}
+
+
+#[test]
+fn thanksgiving() {
+    let rule = HolidayRule::nth_weekday("Thanksgiving", 4, Weekday::Thursday, Month::November);
+    assert_eq!(rule.date(2017), Some(LocalDate::ymd(2017, Month::November, 23).unwrap()));
+    assert_eq!(rule.date(2018), Some(LocalDate::ymd(2018, Month::November, 22).unwrap()));
+}
+
+#[test]
+fn last_monday() {
+    let rule = HolidayRule::nth_weekday("Memorial Day", -1, Weekday::Monday, Month::May);
+    assert_eq!(rule.date(2017), Some(LocalDate::ymd(2017, Month::May, 29).unwrap()));
+    assert_eq!(rule.date(2021), Some(LocalDate::ymd(2021, Month::May, 31).unwrap()));
+}
+
+#[test]
+fn no_fifth_weekday() {
+    let rule = HolidayRule::nth_weekday("Nothing", 5, Weekday::Monday, Month::February);
+    assert_eq!(rule.date(2017), None);
+}
+
+#[test]
+fn leap_day_skipped() {
+    let rule = HolidayRule::fixed("Leap Day", Month::February, 29);
+    assert_eq!(rule.date(2017), None);
+    assert_eq!(rule.date(2016), Some(LocalDate::ymd(2016, Month::February, 29).unwrap()));
+}
+
+#[test]
+fn easter_offsets() {
+    assert_eq!(HolidayRule::easter("Good Friday", -2).date(2024), Some(LocalDate::ymd(2024, Month::March, 29).unwrap()));
+    assert_eq!(HolidayRule::easter("Easter Monday", 1).date(2024), Some(LocalDate::ymd(2024, Month::April, 1).unwrap()));
+    assert_eq!(HolidayRule::easter("Whit Monday", 50).date(2024), Some(LocalDate::ymd(2024, Month::May, 20).unwrap()));
+}
+
+#[test]
+fn orthodox_easter_offsets() {
+    assert_eq!(HolidayRule::orthodox_easter("Orthodox Good Friday", -2).date(2024),
+               Some(LocalDate::ymd(2024, Month::May, 3).unwrap()));
+    assert_eq!(HolidayRule::orthodox_easter("Orthodox Easter Monday", 1).date(2023),
+               Some(LocalDate::ymd(2023, Month::April, 17).unwrap()));
+}
+
+#[test]
+fn observed_on_friday() {
+    let rule = HolidayRule::fixed("Independence Day", Month::July, 4).observed(Observance::NearestWeekday);
+    assert_eq!(rule.observed_date(2020), Some(LocalDate::ymd(2020, Month::July, 3).unwrap()));
+}
+
+#[test]
+fn observed_on_monday() {
+    let rule = HolidayRule::fixed("Boxing Day", Month::December, 26).observed(Observance::NextMonday);
+    assert_eq!(rule.observed_date(2015), Some(LocalDate::ymd(2015, Month::December, 28).unwrap()));
+}
+
+
+#[test]
+fn weekends() {
+    let calendar = BusinessCalendar::new();
+    assert!(!calendar.is_business_day(LocalDate::ymd(2017, Month::June, 10).unwrap()));
+    assert!(calendar.is_business_day(LocalDate::ymd(2017, Month::June, 12).unwrap()));
+}
+
+#[test]
+fn friday_saturday_weekend() {
+    let calendar = BusinessCalendar::with_weekend(&[ Weekday::Friday, Weekday::Saturday ]);
+    assert!(!calendar.is_business_day(LocalDate::ymd(2017, Month::June, 9).unwrap()));
+    assert!(calendar.is_business_day(LocalDate::ymd(2017, Month::June, 11).unwrap()));
+}
+
+#[test]
+fn holidays_moved_off_the_calendars_weekend() {
+    // The 3rd of May 2024 was a Friday.
+    let mut calendar = BusinessCalendar::with_weekend(&[ Weekday::Friday, Weekday::Saturday ]);
+    calendar.add_rule(HolidayRule::fixed("Holiday", Month::May, 3).observed(Observance::NextFreeWeekday));
+    assert!(calendar.is_holiday(LocalDate::ymd(2024, Month::May, 5).unwrap()));
+    assert_eq!(calendar.holidays(2024), vec![ LocalDate::ymd(2024, Month::May, 5).unwrap() ]);
+}
+
+#[test]
+fn explicit_holiday() {
+    let mut calendar = BusinessCalendar::new();
+    calendar.add_holiday(LocalDate::ymd(2017, Month::June, 12).unwrap());
+    assert!(calendar.is_holiday(LocalDate::ymd(2017, Month::June, 12).unwrap()));
+    assert_eq!(calendar.next_business_day(LocalDate::ymd(2017, Month::June, 9).unwrap()),
+               LocalDate::ymd(2017, Month::June, 13).unwrap());
+}
+
+#[test]
+fn observed_in_previous_year() {
+    // The 1st of January 2022 was a Saturday.
+    assert!(us_calendar().is_holiday(LocalDate::ymd(2021, Month::December, 31).unwrap()));
+}
+
+#[test]
+fn holidays_in_year() {
+    assert_eq!(us_calendar().holidays(2021), vec![
+        LocalDate::ymd(2021, Month::January, 1).unwrap(), LocalDate::ymd(2021, Month::May, 31).unwrap(),
+        LocalDate::ymd(2021, Month::July, 5).unwrap(), LocalDate::ymd(2021, Month::November, 25).unwrap(),
+        LocalDate::ymd(2021, Month::December, 24).unwrap(), LocalDate::ymd(2021, Month::December, 31).unwrap(),
+    ]);
+}
+
+#[test]
+#[should_panic]
+fn all_weekend() {
+    let _ = BusinessCalendar::with_weekend(&[ Weekday::Sunday, Weekday::Monday, Weekday::Tuesday, Weekday::Wednesday,
+                                              Weekday::Thursday, Weekday::Friday, Weekday::Saturday ]);
+}
+
+
+#[test]
+fn t_plus_two() {
+    // Wednesday before Thanksgiving.
+    assert_eq!(us_calendar().add_business_days(LocalDate::ymd(2017, Month::November, 22).unwrap(), 2),
+               LocalDate::ymd(2017, Month::November, 27).unwrap());
+}
+
+#[test]
+fn backwards() {
+    assert_eq!(us_calendar().add_business_days(LocalDate::ymd(2017, Month::July, 5).unwrap(), -2),
+               LocalDate::ymd(2017, Month::June, 30).unwrap());
+}
+
+#[test]
+fn zero_days() {
+    let sunday = LocalDate::ymd(2017, Month::July, 2).unwrap();
+    assert_eq!(us_calendar().add_business_days(sunday, 0), sunday);
+}
+
+#[test]
+fn between_with_holiday() {
+    let start = LocalDate::ymd(2017, Month::July, 1).unwrap();
+    let end = LocalDate::ymd(2017, Month::July, 8).unwrap();
+    assert_eq!(us_calendar().business_days_between(start, end), 4);
+}
+
+#[test]
+fn previous() {
+    assert_eq!(us_calendar().previous_business_day(LocalDate::ymd(2017, Month::December, 26).unwrap()),
+               LocalDate::ymd(2017, Month::December, 22).unwrap());
+}
+
+
+#[test]
+fn business_day_unchanged() {
+    let day = LocalDate::ymd(2017, Month::March, 1).unwrap();
+    assert_eq!(BusinessCalendar::new().roll(day, Rolling::Preceding), day);
+}
+
+#[test]
+fn preceding() {
+    assert_eq!(BusinessCalendar::new().roll(LocalDate::ymd(2017, Month::July, 2).unwrap(), Rolling::Preceding),
+               LocalDate::ymd(2017, Month::June, 30).unwrap());
+}
+
+#[test]
+fn modified_preceding() {
+    assert_eq!(BusinessCalendar::new().roll(LocalDate::ymd(2017, Month::July, 2).unwrap(), Rolling::ModifiedPreceding),
+               LocalDate::ymd(2017, Month::July, 3).unwrap());
+}
+
+#[test]
+fn following_over_holiday() {
+    assert_eq!(us_calendar().roll(LocalDate::ymd(2017, Month::December, 24).unwrap(), Rolling::Following),
+               LocalDate::ymd(2017, Month::December, 26).unwrap());
+}
@@ -1,5 +1,5 @@
 extern crate datetime;
-use datetime::{LocalDateTime, Duration};
+use datetime::{LocalDate, LocalDateTime, Duration, Month, MonthOverflow, Year};
 
 
 #[test]
@@ -13,3 +13,52 @@ fn subtraction() {
     let date = LocalDateTime::at(100000000);
     assert_eq!(LocalDateTime::at(99999999), date - Duration::of(1))
 }
+
+#[test]
+fn add_days_across_leap_day() {
+    let date = LocalDate::ymd(2016, Month::February, 28).unwrap();
+    assert_eq!(date.add_days(2), LocalDate::ymd(2016, Month::March, 1).unwrap());
+}
+
+#[test]
+fn subtract_days() {
+    let date = LocalDate::ymd(2000, Month::January, 1).unwrap();
+    assert_eq!(date.add_days(-1), LocalDate::ymd(1999, Month::December, 31).unwrap());
+}
+
+#[test]
+fn add_weeks() {
+    let date = LocalDate::ymd(2017, Month::December, 25).unwrap();
+    assert_eq!(date.add_weeks(2), LocalDate::ymd(2018, Month::January, 8).unwrap());
+}
+
+#[test]
+fn add_months_across_year() {
+    let date = LocalDate::ymd(2017, Month::October, 15).unwrap();
+    assert_eq!(date.add_months(5, MonthOverflow::Reject), LocalDate::ymd(2018, Month::March, 15));
+}
+
+#[test]
+fn subtract_months_clamped() {
+    let date = LocalDate::ymd(2017, Month::March, 31).unwrap();
+    assert_eq!(date.add_months(-1, MonthOverflow::Clamp), LocalDate::ymd(2017, Month::February, 28));
+}
+
+#[test]
+fn subtract_months_rolled_over() {
+    let date = LocalDate::ymd(2017, Month::March, 31).unwrap();
+    assert_eq!(date.add_months(-1, MonthOverflow::RollOver), LocalDate::ymd(2017, Month::March, 3));
+}
+
+#[test]
+fn leap_day_rejected() {
+    let date = LocalDate::ymd(2016, Month::February, 29).unwrap();
+    assert!(date.add_years(-1, MonthOverflow::Reject).is_err());
+}
+
+#[test]
+fn year_month_backwards() {
+    let ym = Year(2017).month(Month::January);
+    assert_eq!(ym.add_months(-1), Year(2016).month(Month::December));
+    assert_eq!(ym.add_years(-17), Year(2000).month(Month::January));
+}
@@ -0,0 +1,202 @@
+extern crate datetime;
+use datetime::{LocalDate, Weekday};
+use datetime::calendar::{Calendar, HebrewDate, IslamicDate, PersianDate};
+use datetime::Month::*;
+
+/// Checks that a date converts both ways, and returns its fields.
+fn convert<C: Calendar>(local: LocalDate) -> (i64, i8, i8) {
+    let converted = C::from_local_date(local);
+    assert_eq!(converted.to_local_date(), local);
+    (converted.year(), converted.month(), converted.day())
+}
+
+/// Checks that every day in a span of Julian Day Numbers converts there
+/// and back again, and that consecutive days have consecutive fields.
+fn round_trip<C: Calendar>(start: i64, end: i64) {
+    let mut previous = C::from_julian_day_number(start - 1);
+    for jdn in start .. end {
+        let date = C::from_julian_day_number(jdn);
+        assert_eq!(date.julian_day_number(), jdn);
+        assert!(C::ymd(date.year(), date.month(), date.day()).is_ok());
+
+        if date.day() != 1 {
+            assert_eq!((date.year(), date.month(), date.day() - 1), (previous.year(), previous.month(), previous.day()));
+        }
+        else if date.month() != 1 {
+            assert_eq!((date.year(), date.month() - 1), (previous.year(), previous.month()));
+            assert_eq!(previous.day(), C::days_in_month(previous.year(), previous.month()));
+        }
+        else {
+            assert_eq!(date.year() - 1, previous.year());
+            assert_eq!(previous.month(), C::months_in_year(previous.year()));
+        }
+
+        previous = date;
+    }
+}
+
+
+#[test]
+fn hebrew_new_years() {
+    assert_eq!(convert::<HebrewDate>(LocalDate::ymd(1999, September, 11).unwrap()), (5760, 1, 1));
+    assert_eq!(convert::<HebrewDate>(LocalDate::ymd(2022, September, 26).unwrap()), (5783, 1, 1));
+    assert_eq!(convert::<HebrewDate>(LocalDate::ymd(2023, September, 16).unwrap()), (5784, 1, 1));
+    assert_eq!(convert::<HebrewDate>(LocalDate::ymd(2024, October, 3).unwrap()), (5785, 1, 1));
+    assert_eq!(convert::<HebrewDate>(LocalDate::ymd(2025, September, 23).unwrap()), (5786, 1, 1));
+}
+
+#[test]
+fn festivals() {
+    assert_eq!(HebrewDate::ymd(5784, 3, 25).unwrap().to_local_date(), LocalDate::ymd(2023, December, 8).unwrap());
+    assert_eq!(HebrewDate::ymd(5783, 6, 14).unwrap().to_local_date(), LocalDate::ymd(2023, March, 7).unwrap());
+    assert_eq!(HebrewDate::ymd(5784, 7, 14).unwrap().to_local_date(), LocalDate::ymd(2024, March, 24).unwrap());
+    assert_eq!(HebrewDate::ymd(5784, 8, 15).unwrap().to_local_date(), LocalDate::ymd(2024, April, 23).unwrap());
+    assert_eq!(HebrewDate::ymd(5785, 7, 15).unwrap().to_local_date(), LocalDate::ymd(2025, April, 13).unwrap());
+}
+
+// Reference values from Reingold and Dershowitz, “Calendrical
+// Calculations”, appendix C, with the months renumbered from Tishrei.
+#[test]
+fn hebrew_reference_dates() {
+    assert_eq!(convert::<HebrewDate>(LocalDate::ymd(-586, July, 24).unwrap()), (3174, 11, 10));
+    assert_eq!(convert::<HebrewDate>(LocalDate::ymd(1945, November, 12).unwrap()), (5706, 3, 7));
+    assert_eq!(convert::<HebrewDate>(LocalDate::ymd(1996, February, 25).unwrap()), (5756, 6, 5));
+    assert_eq!(convert::<HebrewDate>(LocalDate::ymd(2038, November, 10).unwrap()), (5799, 2, 12));
+    assert_eq!(convert::<HebrewDate>(LocalDate::ymd(2094, July, 18).unwrap()), (5854, 11, 5));
+}
+
+#[test]
+fn hebrew_leap_years() {
+    let leap: Vec<i64> = (5776 .. 5795).filter(|y| HebrewDate::is_leap_year(*y)).collect();
+    assert_eq!(leap, vec![ 5776, 5779, 5782, 5784, 5787, 5790, 5793 ]);
+    assert_eq!(HebrewDate::months_in_year(5784), 13);
+    assert_eq!(HebrewDate::months_in_year(5785), 12);
+}
+
+#[test]
+fn year_lengths() {
+    let length = |year| HebrewDate::ymd(year + 1, 1, 1).unwrap().julian_day_number() - HebrewDate::ymd(year, 1, 1).unwrap().julian_day_number();
+    assert_eq!(length(5783), 355);
+    assert_eq!(length(5784), 383);
+    assert_eq!(length(5785), 355);
+    assert_eq!(HebrewDate::days_in_month(5783, 2), 30);
+    assert_eq!(HebrewDate::days_in_month(5784, 2), 29);
+    assert_eq!(HebrewDate::days_in_month(5784, 3), 29);
+}
+
+#[test]
+fn hebrew_month_names() {
+    assert_eq!(HebrewDate::ymd(5784, 6, 1).unwrap().month_name(), "Adar I");
+    assert_eq!(HebrewDate::ymd(5784, 7, 1).unwrap().month_name(), "Adar II");
+    assert_eq!(HebrewDate::ymd(5785, 6, 1).unwrap().month_name(), "Adar");
+    assert_eq!(HebrewDate::ymd(5785, 7, 1).unwrap().month_name(), "Nisan");
+}
+
+#[test]
+fn invalid() {
+    assert!(HebrewDate::ymd(5785, 13, 1).is_err());
+    assert!(HebrewDate::ymd(5784, 3, 30).is_err());
+    assert!(HebrewDate::ymd(5784, 1, 0).is_err());
+}
+
+#[test]
+fn weekdays() {
+    // The new year never falls on a Sunday, Wednesday, or Friday.
+    for year in 5700 .. 5900 {
+        let weekday = HebrewDate::ymd(year, 1, 1).unwrap().weekday();
+        assert!(weekday != Weekday::Sunday && weekday != Weekday::Wednesday && weekday != Weekday::Friday);
+    }
+}
+
+#[test]
+fn hebrew_every_day() {
+    round_trip::<HebrewDate>(2_400_000, 2_500_000);
+}
+
+
+#[test]
+fn epoch() {
+    assert_eq!(IslamicDate::ymd(1, 1, 1).unwrap().to_local_date(), LocalDate::ymd(622, July, 19).unwrap());
+    assert_eq!(IslamicDate::ymd(1, 1, 1).unwrap().weekday(), Weekday::Friday);
+}
+
+// Reference values from Reingold and Dershowitz, “Calendrical
+// Calculations”, appendix C.
+#[test]
+fn islamic_reference_dates() {
+    assert_eq!(convert::<IslamicDate>(LocalDate::ymd(-586, July, 24).unwrap()), (-1245, 12, 9));
+    assert_eq!(convert::<IslamicDate>(LocalDate::ymd(1945, November, 12).unwrap()), (1364, 12, 6));
+    assert_eq!(convert::<IslamicDate>(LocalDate::ymd(1996, February, 25).unwrap()), (1416, 10, 5));
+    assert_eq!(convert::<IslamicDate>(LocalDate::ymd(2038, November, 10).unwrap()), (1460, 10, 12));
+    assert_eq!(convert::<IslamicDate>(LocalDate::ymd(2094, July, 18).unwrap()), (1518, 3, 5));
+}
+
+#[test]
+fn islamic_new_years() {
+    assert_eq!(convert::<IslamicDate>(LocalDate::ymd(2023, July, 19).unwrap()), (1445, 1, 1));
+
+    // The observed calendar started this year a day earlier.
+    assert_eq!(convert::<IslamicDate>(LocalDate::ymd(2024, July, 8).unwrap()), (1446, 1, 1));
+}
+
+#[test]
+fn islamic_leap_years() {
+    let leap: Vec<i64> = (1 .. 31).filter(|y| IslamicDate::is_leap_year(*y)).collect();
+    assert_eq!(leap, vec![ 2, 5, 7, 10, 13, 16, 18, 21, 24, 26, 29 ]);
+    assert_eq!(IslamicDate::days_in_month(1445, 12), 30);
+    assert_eq!(IslamicDate::days_in_month(1446, 12), 29);
+    assert!(IslamicDate::ymd(1446, 12, 30).is_err());
+}
+
+#[test]
+fn islamic_month_names() {
+    assert_eq!(IslamicDate::ymd(1445, 9, 1).unwrap().month_name(), "Ramadan");
+    assert_eq!(IslamicDate::ymd(1445, 12, 1).unwrap().month_name(), "Dhu al-Hijjah");
+}
+
+#[test]
+fn islamic_every_day() {
+    round_trip::<IslamicDate>(1_900_000, 2_500_000);
+}
+
+
+#[test]
+fn persian_new_years() {
+    assert_eq!(convert::<PersianDate>(LocalDate::ymd(2021, March, 21).unwrap()), (1400, 1, 1));
+    assert_eq!(convert::<PersianDate>(LocalDate::ymd(2024, March, 20).unwrap()), (1403, 1, 1));
+    assert_eq!(convert::<PersianDate>(LocalDate::ymd(2025, March, 21).unwrap()), (1404, 1, 1));
+}
+
+#[test]
+fn revolution_day() {
+    assert_eq!(convert::<PersianDate>(LocalDate::ymd(1979, February, 11).unwrap()), (1357, 11, 22));
+}
+
+// Reference values from Reingold and Dershowitz, “Calendrical
+// Calculations”, appendix C.
+#[test]
+fn persian_reference_dates() {
+    assert_eq!(convert::<PersianDate>(LocalDate::ymd(1945, November, 12).unwrap()), (1324, 8, 21));
+    assert_eq!(convert::<PersianDate>(LocalDate::ymd(1996, February, 25).unwrap()), (1374, 12, 6));
+    assert_eq!(convert::<PersianDate>(LocalDate::ymd(2038, November, 10).unwrap()), (1417, 8, 19));
+    assert_eq!(convert::<PersianDate>(LocalDate::ymd(2094, July, 18).unwrap()), (1473, 4, 28));
+}
+
+#[test]
+fn persian_leap_years() {
+    let leap: Vec<i64> = (1390 .. 1410).filter(|y| PersianDate::is_leap_year(*y)).collect();
+    assert_eq!(leap, vec![ 1391, 1395, 1399, 1403, 1408 ]);
+    assert_eq!(PersianDate::ymd(1403, 12, 30).unwrap().to_local_date(), LocalDate::ymd(2025, March, 20).unwrap());
+    assert!(PersianDate::ymd(1402, 12, 30).is_err());
+}
+
+#[test]
+fn persian_month_names() {
+    assert_eq!(PersianDate::ymd(1403, 1, 1).unwrap().month_name(), "Farvardin");
+    assert_eq!(PersianDate::ymd(1403, 12, 1).unwrap().month_name(), "Esfand");
+}
+
+#[test]
+fn persian_every_day() {
+    round_trip::<PersianDate>(2_300_000, 2_500_000);
+}
@@ -0,0 +1,338 @@
+extern crate datetime;
+use datetime::{LocalDate, LocalDateTime, LocalTime, Instant};
+use datetime::cron::{Schedule, CronError, GapPolicy};
+use datetime::Month::*;
+use datetime::zone::{StaticTimeZone, FixedTimespanSet, FixedTimespan, TimeZoneSource, TimeZone};
+use std::borrow::Cow;
+
+
+#[test]
+fn five_fields() {
+    let schedule: Schedule = "30 9 * * *".parse().unwrap();
+    let start = LocalDateTime::new(LocalDate::ymd(2017, May, 1).unwrap(), LocalTime::hm(9, 30).unwrap());
+    assert_eq!(schedule.after(start),
+               Some(LocalDateTime::new(LocalDate::ymd(2017, May, 2).unwrap(), LocalTime::hm(9, 30).unwrap())));
+}
+
+#[test]
+fn six_fields() {
+    let schedule: Schedule = "15 30 9 * * *".parse().unwrap();
+    let next = schedule.after(LocalDateTime::new(LocalDate::ymd(2017, May, 1).unwrap(), LocalTime::midnight())).unwrap();
+    assert_eq!(next.time(), LocalTime::hms(9, 30, 15).unwrap());
+}
+
+#[test]
+fn macros() {
+    let daily: Schedule = "@daily".parse().unwrap();
+    assert_eq!(daily, "0 0 * * *".parse().unwrap());
+    assert_eq!("@annually".parse::<Schedule>(), "@yearly".parse());
+    assert_eq!("@weekly".parse::<Schedule>(), "0 0 * * SUN".parse());
+}
+
+#[test]
+fn names_are_case_insensitive() {
+    assert_eq!("0 0 * jan-mar mon".parse::<Schedule>(), "0 0 * 1-3 1".parse());
+}
+
+#[test]
+fn sunday_is_zero_or_seven() {
+    assert_eq!("0 0 * * 7".parse::<Schedule>(), "0 0 * * 0".parse());
+}
+
+#[test]
+fn wrong_field_count() {
+    assert_eq!("0 0 * *".parse::<Schedule>(), Err(CronError::FieldCount(4)));
+}
+
+#[test]
+fn out_of_range() {
+    assert_eq!("0 24 * * *".parse::<Schedule>(), Err(CronError::InvalidField { field: "hour", value: "24".to_owned() }));
+}
+
+#[test]
+fn backwards_range() {
+    assert!("0 0 * * FRI-MON".parse::<Schedule>().is_err());
+}
+
+#[test]
+fn zero_step() {
+    assert!("*/0 * * * *".parse::<Schedule>().is_err());
+}
+
+#[test]
+fn unknown_macro() {
+    assert_eq!("@reboot".parse::<Schedule>(), Err(CronError::UnknownMacro("@reboot".to_owned())));
+}
+
+
+#[test]
+fn weekdays_at_nine() {
+    let schedule: Schedule = "0 9 * * MON-FRI".parse().unwrap();
+    let start = LocalDateTime::new(LocalDate::ymd(2017, June, 30).unwrap(), LocalTime::hm(9, 0).unwrap());
+    let times: Vec<LocalDateTime> = schedule.upcoming(start).take(2).collect();
+    assert_eq!(times, vec![
+        LocalDateTime::new(LocalDate::ymd(2017, July, 3).unwrap(), LocalTime::hm(9, 0).unwrap()),
+        LocalDateTime::new(LocalDate::ymd(2017, July, 4).unwrap(), LocalTime::hm(9, 0).unwrap()),
+    ]);
+}
+
+#[test]
+fn steps() {
+    let schedule: Schedule = "*/15 * * * *".parse().unwrap();
+    let start = LocalDateTime::new(LocalDate::ymd(2017, May, 1).unwrap(), LocalTime::hm(23, 40).unwrap());
+    let times: Vec<LocalDateTime> = schedule.upcoming(start).take(3).collect();
+    assert_eq!(times, vec![
+        LocalDateTime::new(LocalDate::ymd(2017, May, 1).unwrap(), LocalTime::hm(23, 45).unwrap()),
+        LocalDateTime::new(LocalDate::ymd(2017, May, 2).unwrap(), LocalTime::hm(0, 0).unwrap()),
+        LocalDateTime::new(LocalDate::ymd(2017, May, 2).unwrap(), LocalTime::hm(0, 15).unwrap()),
+    ]);
+}
+
+#[test]
+fn stepped_range() {
+    let schedule: Schedule = "0 8-18/4 * * *".parse().unwrap();
+    let start = LocalDateTime::new(LocalDate::ymd(2017, May, 1).unwrap(), LocalTime::midnight());
+    let hours: Vec<LocalDateTime> = schedule.upcoming(start).take(4).collect();
+    assert_eq!(hours, vec![
+        LocalDateTime::new(LocalDate::ymd(2017, May, 1).unwrap(), LocalTime::hm(8, 0).unwrap()),
+        LocalDateTime::new(LocalDate::ymd(2017, May, 1).unwrap(), LocalTime::hm(12, 0).unwrap()),
+        LocalDateTime::new(LocalDate::ymd(2017, May, 1).unwrap(), LocalTime::hm(16, 0).unwrap()),
+        LocalDateTime::new(LocalDate::ymd(2017, May, 2).unwrap(), LocalTime::hm(8, 0).unwrap()),
+    ]);
+}
+
+#[test]
+fn lists() {
+    let schedule: Schedule = "0 0 1,15 * *".parse().unwrap();
+    let start = LocalDateTime::new(LocalDate::ymd(2017, May, 1).unwrap(), LocalTime::midnight());
+    let times: Vec<LocalDateTime> = schedule.upcoming(start).take(3).collect();
+    assert_eq!(times, vec![
+        LocalDateTime::new(LocalDate::ymd(2017, May, 15).unwrap(), LocalTime::midnight()),
+        LocalDateTime::new(LocalDate::ymd(2017, June, 1).unwrap(), LocalTime::midnight()),
+        LocalDateTime::new(LocalDate::ymd(2017, June, 15).unwrap(), LocalTime::midnight()),
+    ]);
+}
+
+#[test]
+fn seconds() {
+    let schedule: Schedule = "*/20 * * * * *".parse().unwrap();
+    let start = LocalDateTime::new(LocalDate::ymd(2017, May, 1).unwrap(), LocalTime::hm(12, 0).unwrap());
+    let seconds: Vec<i64> = schedule.upcoming(start).take(4)
+                                    .map(|t| t.to_instant().seconds() - start.to_instant().seconds())
+                                    .collect();
+    assert_eq!(seconds, vec![ 20, 40, 60, 80 ]);
+}
+
+#[test]
+fn either_day_field() {
+    // The 13th of every month, and every Friday.
+    let schedule: Schedule = "0 0 13 * FRI".parse().unwrap();
+    let start = LocalDateTime::new(LocalDate::ymd(2017, January, 1).unwrap(), LocalTime::midnight());
+    let dates: Vec<LocalDate> = schedule.upcoming(start).take(4).map(|t| t.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(2017, January, 6).unwrap(), LocalDate::ymd(2017, January, 13).unwrap(),
+        LocalDate::ymd(2017, January, 20).unwrap(), LocalDate::ymd(2017, January, 27).unwrap(),
+    ]);
+}
+
+#[test]
+fn stepped_day_field_is_unrestricted() {
+    // Mondays that are odd days of the month, not every other day.
+    let schedule: Schedule = "0 0 */2 * MON".parse().unwrap();
+    let start = LocalDateTime::new(LocalDate::ymd(2017, January, 1).unwrap(), LocalTime::midnight());
+    let dates: Vec<LocalDate> = schedule.upcoming(start).take(3).map(|t| t.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(2017, January, 9).unwrap(), LocalDate::ymd(2017, January, 23).unwrap(),
+        LocalDate::ymd(2017, February, 13).unwrap(),
+    ]);
+}
+
+#[test]
+fn question_mark() {
+    let schedule: Schedule = "0 0 13 * ?".parse().unwrap();
+    let start = LocalDateTime::new(LocalDate::ymd(2017, January, 1).unwrap(), LocalTime::midnight());
+    let dates: Vec<LocalDate> = schedule.upcoming(start).take(2).map(|t| t.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(2017, January, 13).unwrap(), LocalDate::ymd(2017, February, 13).unwrap(),
+    ]);
+}
+
+#[test]
+fn never_fires() {
+    let schedule: Schedule = "0 0 30 2 *".parse().unwrap();
+    let start = LocalDateTime::new(LocalDate::ymd(2017, January, 1).unwrap(), LocalTime::midnight());
+    assert_eq!(schedule.after(start), None);
+}
+
+#[test]
+fn leap_days() {
+    let schedule: Schedule = "0 0 29 2 *".parse().unwrap();
+    let start = LocalDateTime::new(LocalDate::ymd(2017, January, 1).unwrap(), LocalTime::midnight());
+    let dates: Vec<LocalDate> = schedule.upcoming(start).take(2).map(|t| t.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(2020, February, 29).unwrap(), LocalDate::ymd(2024, February, 29).unwrap(),
+    ]);
+}
+
+
+#[test]
+fn last_day_of_month() {
+    let schedule: Schedule = "0 0 L * *".parse().unwrap();
+    let start = LocalDateTime::new(LocalDate::ymd(2017, January, 1).unwrap(), LocalTime::midnight());
+    let dates: Vec<LocalDate> = schedule.upcoming(start).take(3).map(|t| t.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(2017, January, 31).unwrap(), LocalDate::ymd(2017, February, 28).unwrap(),
+        LocalDate::ymd(2017, March, 31).unwrap(),
+    ]);
+}
+
+#[test]
+fn last_weekday_of_month() {
+    // The 30th of September 2017 was a Saturday, and the 31st of
+    // December was a Sunday.
+    let schedule: Schedule = "0 0 LW 9,12 *".parse().unwrap();
+    let start = LocalDateTime::new(LocalDate::ymd(2017, January, 1).unwrap(), LocalTime::midnight());
+    let dates: Vec<LocalDate> = schedule.upcoming(start).take(2).map(|t| t.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(2017, September, 29).unwrap(), LocalDate::ymd(2017, December, 29).unwrap(),
+    ]);
+}
+
+#[test]
+fn nearest_weekday() {
+    // The 15th of July 2017 was a Saturday, and the 15th of October
+    // was a Sunday.
+    let schedule: Schedule = "0 0 15W 7,10 *".parse().unwrap();
+    let start = LocalDateTime::new(LocalDate::ymd(2017, January, 1).unwrap(), LocalTime::midnight());
+    let dates: Vec<LocalDate> = schedule.upcoming(start).take(2).map(|t| t.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(2017, July, 14).unwrap(), LocalDate::ymd(2017, October, 16).unwrap(),
+    ]);
+}
+
+#[test]
+fn nearest_weekday_stays_in_month() {
+    // The 1st of April 2017 was a Saturday.
+    let schedule: Schedule = "0 0 1W 4 *".parse().unwrap();
+    let start = LocalDateTime::new(LocalDate::ymd(2017, January, 1).unwrap(), LocalTime::midnight());
+    assert_eq!(schedule.after(start).map(|t| t.date()), Some(LocalDate::ymd(2017, April, 3).unwrap()));
+}
+
+#[test]
+fn last_weekday_of_kind() {
+    let schedule: Schedule = "0 0 * * 5L".parse().unwrap();
+    let start = LocalDateTime::new(LocalDate::ymd(2017, January, 1).unwrap(), LocalTime::midnight());
+    let dates: Vec<LocalDate> = schedule.upcoming(start).take(2).map(|t| t.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(2017, January, 27).unwrap(), LocalDate::ymd(2017, February, 24).unwrap(),
+    ]);
+}
+
+#[test]
+fn nth_weekday() {
+    let schedule: Schedule = "0 0 * * FRI#3".parse().unwrap();
+    let start = LocalDateTime::new(LocalDate::ymd(2017, January, 1).unwrap(), LocalTime::midnight());
+    let dates: Vec<LocalDate> = schedule.upcoming(start).take(2).map(|t| t.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(2017, January, 20).unwrap(), LocalDate::ymd(2017, February, 17).unwrap(),
+    ]);
+}
+
+#[test]
+fn fifth_weekday_skips_months() {
+    // Only March and May had five Fridays in the first half of 2017.
+    let schedule: Schedule = "0 0 * 1-6 5#5".parse().unwrap();
+    let start = LocalDateTime::new(LocalDate::ymd(2017, January, 1).unwrap(), LocalTime::midnight());
+    let dates: Vec<LocalDate> = schedule.upcoming(start).take(2).map(|t| t.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(2017, March, 31).unwrap(), LocalDate::ymd(2017, June, 30).unwrap(),
+    ]);
+}
+
+
+// The clocks go forward from 01:00 to 02:00 on the 30th of March 2008,
+// and back from 02:00 to 01:00 on the 26th of October.
+const TEST_ZONESET: &StaticTimeZone<'static> = &StaticTimeZone {
+    name: "Test Zoneset",
+    fixed_timespans: FixedTimespanSet {
+        first: FixedTimespan { offset: 0, is_dst: false, name: Cow::Borrowed("ZONE_A") },
+        rest: &[
+            (1206838800, FixedTimespan { offset: 3600, is_dst: true, name: Cow::Borrowed("ZONE_B") }),
+            (1224982800, FixedTimespan { offset: 0, is_dst: false, name: Cow::Borrowed("ZONE_A") }),
+        ]
+    }
+};
+
+fn wall_times(expression: &str, start: LocalDateTime, gaps: GapPolicy, limit: usize) -> Vec<LocalDateTime> {
+    let zone = TimeZone(TimeZoneSource::Static(TEST_ZONESET));
+    let schedule: Schedule = expression.parse().unwrap();
+    schedule.upcoming_in(&zone, start.to_instant(), gaps).take(limit).map(|z| z.local()).collect()
+}
+
+#[test]
+fn gap_skipped() {
+    let day = LocalDate::ymd(2008, March, 30).unwrap();
+    let times = wall_times("30 * * * *", LocalDateTime::new(day, LocalTime::midnight()), GapPolicy::Skip, 2);
+    assert_eq!(times, vec![
+        LocalDateTime::new(day, LocalTime::hm(0, 30).unwrap()),
+        LocalDateTime::new(day, LocalTime::hm(2, 30).unwrap()),
+    ]);
+}
+
+#[test]
+fn gap_runs_once() {
+    let day = LocalDate::ymd(2008, March, 30).unwrap();
+    let times = wall_times("30 * * * *", LocalDateTime::new(day, LocalTime::midnight()), GapPolicy::RunOnce, 3);
+    assert_eq!(times, vec![
+        LocalDateTime::new(day, LocalTime::hm(0, 30).unwrap()),
+        LocalDateTime::new(day, LocalTime::hm(2, 0).unwrap()),
+        LocalDateTime::new(day, LocalTime::hm(2, 30).unwrap()),
+    ]);
+}
+
+#[test]
+fn many_times_in_gap_run_once() {
+    let day = LocalDate::ymd(2008, March, 30).unwrap();
+    let times = wall_times("*/15 * * * *", LocalDateTime::new(day, LocalTime::hm(0, 40).unwrap()), GapPolicy::RunOnce, 3);
+    assert_eq!(times, vec![
+        LocalDateTime::new(day, LocalTime::hm(0, 45).unwrap()),
+        LocalDateTime::new(day, LocalTime::hm(2, 0).unwrap()),
+        LocalDateTime::new(day, LocalTime::hm(2, 15).unwrap()),
+    ]);
+}
+
+#[test]
+fn daily_in_gap() {
+    let start = LocalDateTime::new(LocalDate::ymd(2008, March, 29).unwrap(), LocalTime::hm(12, 0).unwrap());
+
+    let skipped = wall_times("30 1 * * *", start, GapPolicy::Skip, 1);
+    assert_eq!(skipped, vec![ LocalDateTime::new(LocalDate::ymd(2008, March, 31).unwrap(), LocalTime::hm(1, 30).unwrap()) ]);
+
+    let run = wall_times("30 1 * * *", start, GapPolicy::RunOnce, 1);
+    assert_eq!(run, vec![ LocalDateTime::new(LocalDate::ymd(2008, March, 30).unwrap(), LocalTime::hm(2, 0).unwrap()) ]);
+}
+
+#[test]
+fn overlap_fires_once() {
+    let zone = TimeZone(TimeZoneSource::Static(TEST_ZONESET));
+    let schedule: Schedule = "30 * * * *".parse().unwrap();
+    let start = Instant::at(1224975600);  // 00:00 on the 26th, in ZONE_B
+
+    let instants: Vec<i64> = schedule.upcoming_in(&zone, start, GapPolicy::Skip).take(3)
+                                     .map(|z| z.to_instant().seconds())
+                                     .collect();
+
+    // 00:30 and 01:30 in ZONE_B, then 02:30 in ZONE_A.
+    assert_eq!(instants, vec![ 1224977400, 1224981000, 1224988200 ]);
+}
+
+#[test]
+fn utc_zone() {
+    let start = LocalDateTime::new(LocalDate::ymd(2008, March, 29).unwrap(), LocalTime::midnight());
+    let times = wall_times("0 12 * * *", start, GapPolicy::Skip, 3);
+    assert_eq!(times, vec![
+        LocalDateTime::new(LocalDate::ymd(2008, March, 29).unwrap(), LocalTime::hm(12, 0).unwrap()),
+        LocalDateTime::new(LocalDate::ymd(2008, March, 30).unwrap(), LocalTime::hm(12, 0).unwrap()),
+        LocalDateTime::new(LocalDate::ymd(2008, March, 31).unwrap(), LocalTime::hm(12, 0).unwrap()),
+    ]);
+}
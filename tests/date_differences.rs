@@ -0,0 +1,74 @@
+extern crate datetime;
+use datetime::{LocalDate, Month, Period};
+
+
+#[test]
+fn same_day() {
+    let today = LocalDate::ymd(2017, Month::May, 5).unwrap();
+    assert_eq!(today.days_until(today), 0);
+}
+
+#[test]
+fn days_across_years() {
+    let start = LocalDate::ymd(1999, Month::December, 31).unwrap();
+    let end = LocalDate::ymd(2001, Month::January, 1).unwrap();
+    assert_eq!(start.days_until(end), 367);
+    assert_eq!(end.days_until(start), -367);
+}
+
+#[test]
+fn partial_weeks_are_dropped() {
+    let start = LocalDate::ymd(2017, Month::January, 1).unwrap();
+    let end = LocalDate::ymd(2017, Month::January, 21).unwrap();
+    assert_eq!(start.weeks_until(end), 2);
+    assert_eq!(end.weeks_until(start), -2);
+}
+
+#[test]
+fn day_of_month_not_reached() {
+    let start = LocalDate::ymd(2017, Month::January, 15).unwrap();
+    assert_eq!(start.months_until(LocalDate::ymd(2017, Month::March, 14).unwrap()), 1);
+}
+
+#[test]
+fn end_of_month() {
+    let start = LocalDate::ymd(2016, Month::January, 31).unwrap();
+    assert_eq!(start.months_until(LocalDate::ymd(2016, Month::February, 29).unwrap()), 1);
+}
+
+#[test]
+fn months_reversed_end_of_month() {
+    let start = LocalDate::ymd(2017, Month::March, 31).unwrap();
+    assert_eq!(start.months_until(LocalDate::ymd(2017, Month::February, 28).unwrap()), -1);
+}
+
+#[test]
+fn months_reversed_not_reached() {
+    let start = LocalDate::ymd(2017, Month::March, 15).unwrap();
+    assert_eq!(start.months_until(LocalDate::ymd(2017, Month::February, 16).unwrap()), 0);
+}
+
+#[test]
+fn leap_day_birthday() {
+    let birthday = LocalDate::ymd(2000, Month::February, 29).unwrap();
+    assert_eq!(birthday.years_until(LocalDate::ymd(2001, Month::February, 27).unwrap()), 0);
+    assert_eq!(birthday.years_until(LocalDate::ymd(2001, Month::February, 28).unwrap()), 1);
+}
+
+#[test]
+fn years_reversed() {
+    let start = LocalDate::ymd(2017, Month::June, 15).unwrap();
+    assert_eq!(start.years_until(LocalDate::ymd(1990, Month::June, 16).unwrap()), -26);
+}
+
+#[test]
+fn period_reversed_end_of_month() {
+    let start = LocalDate::ymd(2017, Month::March, 31).unwrap();
+    assert_eq!(Period::between(start, LocalDate::ymd(2017, Month::February, 27).unwrap()), Period::ymd(0, -1, -1));
+}
+
+#[test]
+fn tenure() {
+    let start = LocalDate::ymd(2009, Month::September, 30).unwrap();
+    assert_eq!(Period::between(start, LocalDate::ymd(2017, Month::August, 1).unwrap()), Period::ymd(7, 10, 2));
+}
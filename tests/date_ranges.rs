@@ -0,0 +1,137 @@
+extern crate datetime;
+use datetime::{LocalDate, LocalDateTime, LocalTime, Month, Duration, DateRange, DateStep, DateTimeRange};
+
+
+#[test]
+fn across_years() {
+    let start = LocalDate::ymd(2016, Month::December, 31).unwrap();
+    let end = LocalDate::ymd(2017, Month::January, 2).unwrap();
+    let range = DateRange::new(start, end);
+    assert_eq!(range.collect::<Vec<_>>(), vec![
+        LocalDate::ymd(2016, Month::December, 31).unwrap(), LocalDate::ymd(2017, Month::January, 1).unwrap(),
+    ]);
+}
+
+#[test]
+fn empty() {
+    let day = LocalDate::ymd(2017, Month::June, 1).unwrap();
+    assert_eq!(DateRange::new(day, day).len(), 0);
+    assert_eq!(DateRange::inclusive(day, day).len(), 1);
+}
+
+#[test]
+fn backwards_is_empty() {
+    let start = LocalDate::ymd(2017, Month::June, 2).unwrap();
+    let end = LocalDate::ymd(2017, Month::June, 1).unwrap();
+    let range = DateRange::inclusive(start, end);
+    assert_eq!(range.count(), 0);
+}
+
+#[test]
+fn leap_year() {
+    let start = LocalDate::ymd(2016, Month::January, 1).unwrap();
+    let end = LocalDate::ymd(2017, Month::January, 1).unwrap();
+    let range = DateRange::new(start, end);
+    assert_eq!(range.len(), 366);
+}
+
+#[test]
+fn reversed() {
+    let start = LocalDate::ymd(2017, Month::February, 27).unwrap();
+    let end = LocalDate::ymd(2017, Month::March, 1).unwrap();
+    let mut range = DateRange::inclusive(start, end);
+    assert_eq!(range.next_back(), Some(LocalDate::ymd(2017, Month::March, 1).unwrap()));
+    assert_eq!(range.next(), Some(LocalDate::ymd(2017, Month::February, 27).unwrap()));
+    assert_eq!(range.next_back(), Some(LocalDate::ymd(2017, Month::February, 28).unwrap()));
+    assert_eq!(range.next(), None);
+}
+
+#[test]
+fn every_three_days() {
+    let start = LocalDate::ymd(2017, Month::January, 1).unwrap();
+    let end = LocalDate::ymd(2017, Month::January, 7).unwrap();
+    let range = DateRange::new(start, end).step(DateStep::Days(3));
+    assert_eq!(range.collect::<Vec<_>>(), vec![
+        LocalDate::ymd(2017, Month::January, 1).unwrap(), LocalDate::ymd(2017, Month::January, 4).unwrap(),
+    ]);
+}
+
+#[test]
+fn weekly_exclusive_end() {
+    let start = LocalDate::ymd(2017, Month::January, 1).unwrap();
+    let end = LocalDate::ymd(2017, Month::January, 29).unwrap();
+    let mut range = DateRange::new(start, end).step(DateStep::Weeks(1));
+    assert_eq!(range.len(), 4);
+    assert_eq!(range.next_back(), Some(LocalDate::ymd(2017, Month::January, 22).unwrap()));
+}
+
+#[test]
+fn weekly_inclusive_end() {
+    let start = LocalDate::ymd(2017, Month::January, 1).unwrap();
+    let end = LocalDate::ymd(2017, Month::January, 29).unwrap();
+    let range = DateRange::inclusive(start, end).step(DateStep::Weeks(1));
+    assert_eq!(range.len(), 5);
+}
+
+#[test]
+fn month_ends() {
+    let start = LocalDate::ymd(2016, Month::January, 31).unwrap();
+    let end = LocalDate::ymd(2016, Month::May, 31).unwrap();
+    let range = DateRange::inclusive(start, end).step(DateStep::Months(1));
+    assert_eq!(range.collect::<Vec<_>>(), vec![
+        LocalDate::ymd(2016, Month::January, 31).unwrap(), LocalDate::ymd(2016, Month::February, 29).unwrap(),
+        LocalDate::ymd(2016, Month::March, 31).unwrap(), LocalDate::ymd(2016, Month::April, 30).unwrap(),
+        LocalDate::ymd(2016, Month::May, 31).unwrap(),
+    ]);
+}
+
+#[test]
+fn quarterly_backwards() {
+    let start = LocalDate::ymd(2015, Month::November, 15).unwrap();
+    let end = LocalDate::ymd(2017, Month::February, 1).unwrap();
+    let range = DateRange::new(start, end).step(DateStep::Months(3));
+    assert_eq!(range.rev().take(2).collect::<Vec<_>>(), vec![
+        LocalDate::ymd(2016, Month::November, 15).unwrap(), LocalDate::ymd(2016, Month::August, 15).unwrap(),
+    ]);
+}
+
+
+#[test]
+fn hourly() {
+    let day = LocalDate::ymd(2017, Month::December, 31).unwrap();
+    let start = LocalDateTime::new(day, LocalTime::hm(21, 0).unwrap());
+    let end = LocalDateTime::new(day, LocalTime::hm(23, 30).unwrap());
+    let range = DateTimeRange::new(start, end, Duration::of(3600));
+    assert_eq!(range.collect::<Vec<_>>(), vec![
+        start,
+        LocalDateTime::new(day, LocalTime::hm(22, 0).unwrap()),
+        LocalDateTime::new(day, LocalTime::hm(23, 0).unwrap()),
+    ]);
+}
+
+#[test]
+fn inclusive_end() {
+    let day = LocalDate::ymd(2017, Month::December, 31).unwrap();
+    let start = LocalDateTime::new(day, LocalTime::hm(21, 0).unwrap());
+    let end = LocalDateTime::new(day, LocalTime::hm(23, 0).unwrap());
+    let range = DateTimeRange::inclusive(start, end, Duration::of(3600));
+    assert_eq!(range.len(), 3);
+}
+
+#[test]
+fn across_midnight() {
+    let start = LocalDateTime::new(LocalDate::ymd(2017, Month::December, 31).unwrap(), LocalTime::hm(23, 0).unwrap());
+    let end = LocalDateTime::new(LocalDate::ymd(2018, Month::January, 1).unwrap(), LocalTime::hm(1, 0).unwrap());
+    let mut range = DateTimeRange::new(start, end, Duration::of(45 * 60));
+    assert_eq!(range.next_back(),
+               Some(LocalDateTime::new(LocalDate::ymd(2018, Month::January, 1).unwrap(), LocalTime::hm(0, 30).unwrap())));
+}
+
+#[test]
+fn milliseconds() {
+    let day = LocalDate::ymd(2017, Month::December, 31).unwrap();
+    let start = LocalDateTime::new(day, LocalTime::hm(12, 0).unwrap());
+    let end = LocalDateTime::new(day, LocalTime::hm(12, 1).unwrap());
+    let range = DateTimeRange::new(start, end, Duration::of_ms(0, 250));
+    assert_eq!(range.len(), 240);
+}
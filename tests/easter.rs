@@ -0,0 +1,75 @@
+extern crate datetime;
+use datetime::{DatePiece, LocalDate, Weekday, Year};
+use datetime::julian::JulianDate;
+use datetime::Month::*;
+
+
+#[test]
+fn western_recent_years() {
+    assert_eq!(Year(2000).easter(), LocalDate::ymd(2000, April, 23).unwrap());
+    assert_eq!(Year(2008).easter(), LocalDate::ymd(2008, March, 23).unwrap());
+    assert_eq!(Year(2019).easter(), LocalDate::ymd(2019, April, 21).unwrap());
+    assert_eq!(Year(2024).easter(), LocalDate::ymd(2024, March, 31).unwrap());
+    assert_eq!(Year(2026).easter(), LocalDate::ymd(2026, April, 5).unwrap());
+}
+
+#[test]
+fn earliest_and_latest() {
+    assert_eq!(Year(1818).easter(), LocalDate::ymd(1818, March, 22).unwrap());
+    assert_eq!(Year(2285).easter(), LocalDate::ymd(2285, March, 22).unwrap());
+    assert_eq!(Year(1943).easter(), LocalDate::ymd(1943, April, 25).unwrap());
+    assert_eq!(Year(2038).easter(), LocalDate::ymd(2038, April, 25).unwrap());
+}
+
+#[test]
+fn always_a_sunday_in_range() {
+    for year in 1583 .. 3000 {
+        let easter = Year(year).easter();
+        assert_eq!(easter.weekday(), Weekday::Sunday);
+        assert!(easter >= LocalDate::ymd(year, March, 22).unwrap() && easter <= LocalDate::ymd(year, April, 25).unwrap());
+    }
+}
+
+// The Gregorian computus repeats every 5,700,000 years, which checks
+// that the calculation holds up for years before 0.
+#[test]
+fn negative_years() {
+    for year in 1990 .. 2030 {
+        let (now, then) = (Year(year).easter(), Year(year - 5_700_000).easter());
+        assert_eq!((now.month(), now.day()), (then.month(), then.day()));
+    }
+}
+
+
+#[test]
+fn orthodox_recent_years() {
+    assert_eq!(Year(2008).orthodox_easter(), LocalDate::ymd(2008, April, 27).unwrap());
+    assert_eq!(Year(2013).orthodox_easter(), LocalDate::ymd(2013, May, 5).unwrap());
+    assert_eq!(Year(2021).orthodox_easter(), LocalDate::ymd(2021, May, 2).unwrap());
+    assert_eq!(Year(2023).orthodox_easter(), LocalDate::ymd(2023, April, 16).unwrap());
+    assert_eq!(Year(2024).orthodox_easter(), LocalDate::ymd(2024, May, 5).unwrap());
+}
+
+#[test]
+fn same_as_western() {
+    assert_eq!(Year(2011).orthodox_easter(), Year(2011).easter());
+    assert_eq!(Year(2017).orthodox_easter(), Year(2017).easter());
+    assert_eq!(Year(2025).orthodox_easter(), Year(2025).easter());
+}
+
+#[test]
+fn always_a_sunday() {
+    for year in -1000 .. 3000 {
+        assert_eq!(Year(year).orthodox_easter().weekday(), Weekday::Sunday);
+    }
+}
+
+// The Julian computus repeats every 532 years in the Julian calendar.
+#[test]
+fn julian_cycle() {
+    for year in 1990 .. 2030 {
+        let now = JulianDate::from_local_date(Year(year).orthodox_easter());
+        let then = JulianDate::from_local_date(Year(year - 532 * 5).orthodox_easter());
+        assert_eq!((now.month(), now.day()), (then.month(), then.day()));
+    }
+}
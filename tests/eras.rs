@@ -0,0 +1,164 @@
+extern crate datetime;
+extern crate locale;
+use datetime::{DatePiece, LocalDate, LocalDateTime, LocalTime};
+use datetime::era::{Era, EraYear, JapaneseEra, JapaneseYear};
+use datetime::fmt::DateFormat;
+use datetime::julian::JulianDate;
+use datetime::Month::*;
+
+fn format(input: &str, when: LocalDate) -> String {
+    let when = LocalDateTime::new(when, LocalTime::midnight());
+    DateFormat::parse(input).unwrap().format(&when, &locale::Time::english())
+}
+
+
+#[test]
+fn common_era() {
+    assert_eq!(LocalDate::ymd(2017, March, 15).unwrap().era(), Era::CommonEra);
+    assert_eq!(LocalDate::ymd(2017, March, 15).unwrap().year_of_era(), 2017);
+    assert_eq!(LocalDate::ymd(1, January, 1).unwrap().year_of_era(), 1);
+}
+
+#[test]
+fn year_zero() {
+    assert_eq!(LocalDate::ymd(0, January, 1).unwrap().era(), Era::BeforeCommonEra);
+    assert_eq!(LocalDate::ymd(0, January, 1).unwrap().year_of_era(), 1);
+}
+
+#[test]
+fn before_common_era() {
+    assert_eq!(LocalDate::ymd(-43, March, 15).unwrap().year_of_era(), 44);
+    assert_eq!(EraYear::of_year(-43), EraYear { era: Era::BeforeCommonEra, year: 44 });
+}
+
+#[test]
+fn round_trip() {
+    for year in -500 .. 500 {
+        assert_eq!(EraYear::of_year(year).to_year(), year);
+    }
+}
+
+#[test]
+fn gregorian_display() {
+    assert_eq!(EraYear::of_year(-43).to_string(), "44 BC");
+    assert_eq!(EraYear::of_year(1066).to_string(), "1066 AD");
+}
+
+#[test]
+fn gregorian_parsing() {
+    assert_eq!("44 BC".parse::<EraYear>().unwrap().to_year(), -43);
+    assert_eq!("1 BCE".parse::<EraYear>().unwrap().to_year(), 0);
+    assert_eq!("AD 1066".parse::<EraYear>().unwrap().to_year(), 1066);
+    assert_eq!("2017 c.e.".parse::<EraYear>().unwrap().to_year(), 2017);
+}
+
+#[test]
+fn gregorian_bad_parsing() {
+    assert!("0 BC".parse::<EraYear>().is_err());
+    assert!("44 BC AD".parse::<EraYear>().is_err());
+    assert!("44 XY".parse::<EraYear>().is_err());
+    assert!("1066".parse::<EraYear>().is_err());
+}
+
+
+#[test]
+fn reiwa() {
+    let year = LocalDate::ymd(2026, October, 18).unwrap().japanese_year().unwrap();
+    assert_eq!(year, JapaneseYear { era: JapaneseEra::Reiwa, year: 8 });
+    assert_eq!(year.to_year(), 2026);
+}
+
+#[test]
+fn boundaries() {
+    assert_eq!(LocalDate::ymd(2019, April, 30).unwrap().japanese_year().unwrap(),
+               JapaneseYear { era: JapaneseEra::Heisei, year: 31 });
+    assert_eq!(LocalDate::ymd(2019, May, 1).unwrap().japanese_year().unwrap(), JapaneseYear { era: JapaneseEra::Reiwa, year: 1 });
+    assert_eq!(LocalDate::ymd(1989, January, 7).unwrap().japanese_year().unwrap(),
+               JapaneseYear { era: JapaneseEra::Showa, year: 64 });
+    assert_eq!(LocalDate::ymd(1989, January, 8).unwrap().japanese_year().unwrap(),
+               JapaneseYear { era: JapaneseEra::Heisei, year: 1 });
+}
+
+#[test]
+fn japanese_before_meiji() {
+    assert_eq!(LocalDate::ymd(1868, October, 22).unwrap().japanese_year(), None);
+    assert_eq!(LocalDate::ymd(1868, October, 23).unwrap().japanese_year().unwrap(),
+               JapaneseYear { era: JapaneseEra::Meiji, year: 1 });
+}
+
+#[test]
+fn julian_dates() {
+    // The 25th of July 1912 in the Julian calendar was the 7th of
+    // August in the Gregorian one, after the Taishō era started.
+    let julian = JulianDate::ymd(1912, July, 25).unwrap();
+    assert_eq!(julian.japanese_year().unwrap(), JapaneseYear { era: JapaneseEra::Taisho, year: 1 });
+    assert_eq!(julian.era(), Era::CommonEra);
+}
+
+#[test]
+fn start_dates() {
+    assert_eq!(JapaneseEra::Taisho.start(), LocalDate::ymd(1912, July, 30).unwrap());
+    assert_eq!(JapaneseEra::Reiwa.start(), LocalDate::ymd(2019, May, 1).unwrap());
+    assert_eq!(JapaneseEra::Showa.last_year(), Some(64));
+    assert_eq!(JapaneseEra::Reiwa.last_year(), None);
+}
+
+#[test]
+fn year_range() {
+    assert!(JapaneseYear::new(JapaneseEra::Heisei, 31).is_ok());
+    assert!(JapaneseYear::new(JapaneseEra::Heisei, 32).is_err());
+    assert!(JapaneseYear::new(JapaneseEra::Reiwa, 0).is_err());
+}
+
+#[test]
+fn dates_in_era() {
+    let heisei = JapaneseYear::new(JapaneseEra::Heisei, 31).unwrap();
+    assert_eq!(heisei.date(April, 30).unwrap(), LocalDate::ymd(2019, April, 30).unwrap());
+    assert!(heisei.date(May, 1).is_err());
+
+    let reiwa = JapaneseYear::new(JapaneseEra::Reiwa, 1).unwrap();
+    assert!(reiwa.date(April, 30).is_err());
+}
+
+#[test]
+fn japanese_display() {
+    assert_eq!(JapaneseYear { era: JapaneseEra::Reiwa, year: 8 }.to_string(), "令和8年");
+    assert_eq!(JapaneseYear { era: JapaneseEra::Reiwa, year: 1 }.to_string(), "令和元年");
+}
+
+#[test]
+fn japanese_parsing() {
+    let reiwa = |year| JapaneseYear { era: JapaneseEra::Reiwa, year };
+    assert_eq!("令和8年".parse::<JapaneseYear>().unwrap(), reiwa(8));
+    assert_eq!("令和元年".parse::<JapaneseYear>().unwrap(), reiwa(1));
+    assert_eq!("令和８年".parse::<JapaneseYear>().unwrap(), reiwa(8));
+    assert_eq!("Reiwa 8".parse::<JapaneseYear>().unwrap(), reiwa(8));
+    assert_eq!("R8".parse::<JapaneseYear>().unwrap(), reiwa(8));
+    assert_eq!("Shōwa 64".parse::<JapaneseYear>().unwrap().to_year(), 1989);
+    assert_eq!("Showa 64".parse::<JapaneseYear>().unwrap().to_year(), 1989);
+}
+
+#[test]
+fn japanese_bad_parsing() {
+    assert!("令和".parse::<JapaneseYear>().is_err());
+    assert!("Heisei 32".parse::<JapaneseYear>().is_err());
+    assert!("Kanei 3".parse::<JapaneseYear>().is_err());
+}
+
+
+#[test]
+fn era_names() {
+    assert_eq!(format("{:e} {:G}", LocalDate::ymd(-43, March, 15).unwrap()), "44 BC");
+    assert_eq!(format("{:e} {_:G}", LocalDate::ymd(2017, March, 15).unwrap()), "2017 CE");
+}
+
+#[test]
+fn japanese() {
+    assert_eq!(format("{:J}{:j}年", LocalDate::ymd(2026, October, 18).unwrap()), "令和8年");
+    assert_eq!(format("{_:J} {:j}", LocalDate::ymd(1989, January, 7).unwrap()), "Shōwa 64");
+}
+
+#[test]
+fn formatting_before_meiji() {
+    assert_eq!(format("{:J}{:j}", LocalDate::ymd(1800, January, 1).unwrap()), "");
+}
@@ -0,0 +1,219 @@
+extern crate datetime;
+use datetime::{LocalDate, LocalDateTime, LocalTime, Weekday, Year, YearQuarter, DatePiece};
+use datetime::fiscal::{FiscalCalendar, FiscalDate, YearNaming, RetailCalendar, RetailDate, WeekPattern, YearEnd};
+use datetime::Month::*;
+
+
+#[test]
+fn quarter_boundaries() {
+    assert_eq!(LocalDate::ymd(2017, March, 31).unwrap().quarter(), 1);
+    assert_eq!(LocalDate::ymd(2017, April, 1).unwrap().quarter(), 2);
+    assert_eq!(LocalDate::ymd(2017, September, 30).unwrap().quarter(), 3);
+    assert_eq!(LocalDate::ymd(2017, October, 1).unwrap().quarter(), 4);
+}
+
+#[test]
+fn datetime_quarter() {
+    let datetime = LocalDateTime::new(LocalDate::ymd(2017, December, 31).unwrap(), LocalTime::hm(23, 59).unwrap());
+    assert_eq!(datetime.year_quarter(), YearQuarter::new(2017, 4).unwrap());
+}
+
+#[test]
+fn invalid_quarter() {
+    assert!(YearQuarter::new(2017, 0).is_err());
+    assert!(YearQuarter::new(2017, 5).is_err());
+}
+
+#[test]
+fn months() {
+    let months: Vec<_> = YearQuarter::new(2017, 4).unwrap().months().collect();
+    assert_eq!(months, vec![ Year(2017).month(October), Year(2017).month(November), Year(2017).month(December) ]);
+}
+
+#[test]
+fn day_counts() {
+    assert_eq!(YearQuarter::new(2015, 1).unwrap().day_count(), 90);
+    assert_eq!(YearQuarter::new(2016, 1).unwrap().day_count(), 91);
+    assert_eq!(YearQuarter::new(2016, 2).unwrap().day_count(), 91);
+    assert_eq!(YearQuarter::new(2016, 3).unwrap().day_count(), 92);
+}
+
+#[test]
+fn days_cover_the_quarter() {
+    let quarter = YearQuarter::new(2017, 4).unwrap();
+    let days: Vec<_> = quarter.days().collect();
+    assert_eq!(days.len() as i64, quarter.day_count());
+    assert_eq!(days.first(), Some(&LocalDate::ymd(2017, October, 1).unwrap()));
+    assert_eq!(days.last(), Some(&LocalDate::ymd(2017, December, 31).unwrap()));
+    assert!(days.iter().all(|d| d.year_quarter() == quarter));
+}
+
+#[test]
+fn add_quarters() {
+    let quarter = YearQuarter::new(2017, 3).unwrap();
+    assert_eq!(quarter.add_quarters(2), YearQuarter::new(2018, 1).unwrap());
+    assert_eq!(quarter.add_quarters(-3), YearQuarter::new(2016, 4).unwrap());
+}
+
+#[test]
+fn quarters_until() {
+    let start = YearQuarter::new(2016, 4).unwrap();
+    assert_eq!(start.quarters_until(YearQuarter::new(2018, 2).unwrap()), 6);
+    assert_eq!(YearQuarter::new(2018, 2).unwrap().quarters_until(start), -6);
+}
+
+
+#[test]
+fn calendar_year() {
+    let calendar = FiscalCalendar::default();
+    assert_eq!(calendar.fiscal_date(LocalDate::ymd(2017, August, 14).unwrap()), FiscalDate { year: 2017, quarter: 3, period: 8 });
+}
+
+#[test]
+fn april_start() {
+    let calendar = FiscalCalendar { start: April, naming: YearNaming::Starting };
+    assert_eq!(calendar.fiscal_date(LocalDate::ymd(2017, April, 1).unwrap()), FiscalDate { year: 2017, quarter: 1, period: 1 });
+    assert_eq!(calendar.fiscal_date(LocalDate::ymd(2018, March, 31).unwrap()), FiscalDate { year: 2017, quarter: 4, period: 12 });
+}
+
+#[test]
+fn july_start() {
+    let calendar = FiscalCalendar::new(July);
+    assert_eq!(calendar.fiscal_date(LocalDate::ymd(2017, June, 30).unwrap()), FiscalDate { year: 2017, quarter: 4, period: 12 });
+    assert_eq!(calendar.fiscal_date(LocalDate::ymd(2017, July, 1).unwrap()), FiscalDate { year: 2018, quarter: 1, period: 1 });
+}
+
+#[test]
+fn us_federal() {
+    let calendar = FiscalCalendar::new(October);
+    assert_eq!(calendar.fiscal_year(LocalDate::ymd(2017, October, 1).unwrap()), 2018);
+    assert_eq!(calendar.fiscal_year(LocalDate::ymd(2017, September, 30).unwrap()), 2017);
+    assert_eq!(calendar.year_start(2018), LocalDate::ymd(2017, October, 1).unwrap());
+}
+
+#[test]
+fn year_days() {
+    let calendar = FiscalCalendar::new(October);
+    let days: Vec<_> = calendar.year_days(2016).collect();
+    assert_eq!(days.len(), 366);
+    assert_eq!(days.first(), Some(&LocalDate::ymd(2015, October, 1).unwrap()));
+    assert_eq!(days.last(), Some(&LocalDate::ymd(2016, September, 30).unwrap()));
+}
+
+#[test]
+fn quarter_days() {
+    let calendar = FiscalCalendar::new(July);
+    let days: Vec<_> = calendar.quarter_days(2018, 3).unwrap().collect();
+    assert_eq!(days.first(), Some(&LocalDate::ymd(2018, January, 1).unwrap()));
+    assert_eq!(days.last(), Some(&LocalDate::ymd(2018, March, 31).unwrap()));
+    assert!(calendar.quarter_days(2018, 5).is_err());
+}
+
+#[test]
+fn period_days() {
+    let calendar = FiscalCalendar { start: April, naming: YearNaming::Starting };
+    assert_eq!(calendar.period_month(2015, 11), Ok(Year(2016).month(February)));
+    assert_eq!(calendar.period_days(2015, 11).unwrap().len(), 29);
+    assert!(calendar.period_days(2015, 13).is_err());
+}
+
+#[test]
+fn fiscal_round_trip() {
+    for start in &[ January, April, July, October ] {
+        for naming in &[ YearNaming::Starting, YearNaming::Ending ] {
+            let calendar = FiscalCalendar { start: *start, naming: *naming };
+            for day in calendar.year_days(2017) {
+                let fiscal = calendar.fiscal_date(day);
+                assert_eq!(fiscal.year, 2017);
+                assert!(calendar.period_days(fiscal.year, fiscal.period).unwrap().any(|d| d == day));
+                assert!(calendar.quarter_days(fiscal.year, fiscal.quarter).unwrap().any(|d| d == day));
+            }
+        }
+    }
+}
+
+
+#[test]
+fn nrf_years() {
+    // The NRF calendar’s 2017 year ran from the 29th of January 2017 to
+    // the 3rd of February 2018, and had 53 weeks.
+    let calendar = RetailCalendar::default();
+    assert_eq!(calendar.year_start(2017), LocalDate::ymd(2017, January, 29).unwrap());
+    assert_eq!(calendar.year_end(2017), LocalDate::ymd(2018, February, 3).unwrap());
+    assert_eq!(calendar.week_count(2017), 53);
+    assert_eq!(calendar.week_count(2018), 52);
+    assert_eq!(calendar.year_start(2018), LocalDate::ymd(2018, February, 4).unwrap());
+}
+
+#[test]
+fn nrf_periods() {
+    let calendar = RetailCalendar::default();
+    let lengths: Vec<usize> = (1 ..= 12).map(|p| calendar.period_days(2017, p).unwrap().len()).collect();
+    assert_eq!(lengths, vec![ 28, 35, 28, 28, 35, 28, 28, 35, 28, 28, 35, 35 ]);
+}
+
+#[test]
+fn fifty_third_week() {
+    let calendar = RetailCalendar::default();
+    assert_eq!(calendar.retail_date(LocalDate::ymd(2018, February, 3).unwrap()),
+               RetailDate { year: 2017, period: 12, week: 5, day: 7 });
+    assert!(calendar.date(RetailDate { year: 2018, period: 12, week: 5, day: 1 }).is_err());
+}
+
+#[test]
+fn quarters() {
+    let calendar = RetailCalendar::default();
+    let quarter: Vec<_> = calendar.quarter_days(2017, 2).unwrap().collect();
+    assert_eq!(quarter.len(), 91);
+    assert_eq!(quarter.first(), Some(&LocalDate::ymd(2017, April, 30).unwrap()));
+    assert_eq!(quarter.last(), Some(&LocalDate::ymd(2017, July, 29).unwrap()));
+    assert_eq!(calendar.quarter_days(2017, 4).unwrap().len(), 98);
+}
+
+#[test]
+fn last_weekday() {
+    // A 4-4-5 year ending on the last Saturday of September.
+    let calendar = RetailCalendar {
+        pattern: WeekPattern::FourFourFive,
+        end_weekday: Weekday::Saturday,
+        end_month: September,
+        year_end: YearEnd::LastWeekday,
+        naming: YearNaming::Ending,
+    };
+
+    assert_eq!(calendar.year_end(2017), LocalDate::ymd(2017, September, 30).unwrap());
+    assert_eq!(calendar.year_end(2018), LocalDate::ymd(2018, September, 29).unwrap());
+    assert_eq!(calendar.retail_date(LocalDate::ymd(2017, October, 1).unwrap()),
+               RetailDate { year: 2018, period: 1, week: 1, day: 1 });
+    assert_eq!(calendar.retail_date(LocalDate::ymd(2017, December, 30).unwrap()),
+               RetailDate { year: 2018, period: 3, week: 5, day: 7 });
+}
+
+#[test]
+fn invalid_positions() {
+    let calendar = RetailCalendar::default();
+    assert!(calendar.date(RetailDate { year: 2017, period: 13, week: 1, day: 1 }).is_err());
+    assert!(calendar.date(RetailDate { year: 2017, period: 1, week: 5, day: 1 }).is_err());
+    assert!(calendar.date(RetailDate { year: 2017, period: 1, week: 1, day: 8 }).is_err());
+    assert!(calendar.period_days(2017, 0).is_err());
+}
+
+#[test]
+fn retail_round_trip() {
+    for pattern in &[ WeekPattern::FourFourFive, WeekPattern::FourFiveFour, WeekPattern::FiveFourFour ] {
+        for year_end in &[ YearEnd::LastWeekday, YearEnd::NearestWeekday ] {
+            let calendar = RetailCalendar { pattern: *pattern, year_end: *year_end, .. RetailCalendar::default() };
+
+            for year in 2010 .. 2022 {
+                let days = calendar.year_days(year);
+                assert_eq!(days.len() as i64, calendar.week_count(year) * 7);
+
+                for day in days {
+                    let retail = calendar.retail_date(day);
+                    assert_eq!(retail.year, year);
+                    assert_eq!(calendar.date(retail), Ok(day));
+                }
+            }
+        }
+    }
+}
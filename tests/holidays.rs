@@ -0,0 +1,289 @@
+extern crate datetime;
+use datetime::{DatePiece, LocalDate, Month, Year};
+use datetime::business::{BusinessCalendar, HolidayDate, HolidayRule};
+use datetime::ISO;
+use datetime::holidays::{self, HolidayError, HolidaySets};
+
+/// Returns the observed dates of a built-in set’s holidays in a year, as
+/// ISO 8601 strings.
+fn observed(name: &str, year: i64) -> Vec<String> {
+    holidays::builtin(name).unwrap()
+        .holidays(Year(year)).into_iter()
+        .map(|h| h.observed.iso().to_string())
+        .collect()
+}
+
+
+#[test]
+fn federal_2021() {
+    assert_eq!(observed("us", 2021), vec![
+        "2021-01-01", "2021-01-18", "2021-02-15", "2021-05-31", "2021-06-18", "2021-07-05",
+        "2021-09-06", "2021-10-11", "2021-11-11", "2021-11-25", "2021-12-24",
+    ]);
+}
+
+#[test]
+fn no_juneteenth_before_2021() {
+    let holidays = holidays::builtin("us").unwrap().holidays(Year(2020));
+    assert_eq!(holidays.len(), 10);
+    assert!(holidays.iter().all(|h| h.name != "Juneteenth National Independence Day"));
+}
+
+#[test]
+fn new_year_observed_in_previous_year() {
+    let mut calendar = BusinessCalendar::new();
+    calendar.add_holiday_set(&holidays::builtin("us").unwrap());
+    assert!(calendar.is_holiday(LocalDate::ymd(2021, Month::December, 31).unwrap()));
+    assert_eq!(calendar.holidays(2021).len(), 12);
+}
+
+
+#[test]
+fn england_2020() {
+    assert_eq!(observed("gb-eng", 2020), vec![
+        "2020-01-01", "2020-04-10", "2020-04-13", "2020-05-08", "2020-05-25", "2020-08-31",
+        "2020-12-25", "2020-12-28",
+    ]);
+}
+
+#[test]
+fn england_2022() {
+    assert_eq!(observed("gb-eng", 2022), vec![
+        "2022-01-03", "2022-04-15", "2022-04-18", "2022-05-02", "2022-06-02", "2022-06-03",
+        "2022-08-29", "2022-09-19", "2022-12-26", "2022-12-27",
+    ]);
+}
+
+#[test]
+fn christmas_on_saturday() {
+    let holidays = holidays::builtin("gb-eng").unwrap().holidays(Year(2021));
+    let christmas: Vec<_> = holidays.iter().filter(|h| h.date.month() == Month::December).collect();
+    assert_eq!(christmas[0].name, "Christmas Day");
+    assert_eq!(christmas[0].observed, LocalDate::ymd(2021, Month::December, 27).unwrap());
+    assert_eq!(christmas[1].name, "Boxing Day");
+    assert_eq!(christmas[1].observed, LocalDate::ymd(2021, Month::December, 28).unwrap());
+}
+
+#[test]
+fn scotland_2022() {
+    assert_eq!(observed("gb-sct", 2022), vec![
+        "2022-01-03", "2022-01-04", "2022-04-15", "2022-05-02", "2022-06-02", "2022-06-03",
+        "2022-08-01", "2022-09-19", "2022-11-30", "2022-12-26", "2022-12-27",
+    ]);
+}
+
+#[test]
+fn northern_ireland_2023() {
+    assert_eq!(observed("gb-nir", 2023), vec![
+        "2023-01-02", "2023-03-17", "2023-04-07", "2023-04-10", "2023-05-01", "2023-05-08",
+        "2023-05-29", "2023-07-12", "2023-08-28", "2023-12-25", "2023-12-26",
+    ]);
+}
+
+
+#[test]
+fn national_2024() {
+    assert_eq!(observed("de", 2024), vec![
+        "2024-01-01", "2024-03-29", "2024-04-01", "2024-05-01", "2024-05-09", "2024-05-20",
+        "2024-10-03", "2024-12-25", "2024-12-26",
+    ]);
+}
+
+#[test]
+fn reformation_day_2017() {
+    for state in &[ "de", "de-by", "de-sn" ] {
+        let holidays = holidays::builtin(state).unwrap().holidays(Year(2017));
+        assert_eq!(holidays.iter().filter(|h| h.name == "Reformation Day").count(), 1);
+    }
+}
+
+#[test]
+fn bavaria() {
+    let holidays = holidays::builtin("de-by").unwrap().holidays(Year(2024));
+    assert_eq!(holidays.len(), 13);
+    assert!(holidays.iter().any(|h| h.name == "Corpus Christi" && h.date == LocalDate::ymd(2024, Month::May, 30).unwrap()));
+    assert!(holidays.iter().any(|h| h.name == "Assumption Day" && h.date == LocalDate::ymd(2024, Month::August, 15).unwrap()));
+}
+
+#[test]
+fn repentance_and_prayer_day() {
+    let set = holidays::builtin("de-sn").unwrap();
+    let day = |year| set.holidays(Year(year)).into_iter().find(|h| h.name == "Repentance and Prayer Day").unwrap().date;
+    assert_eq!(day(2022), LocalDate::ymd(2022, Month::November, 16).unwrap());
+    assert_eq!(day(2024), LocalDate::ymd(2024, Month::November, 20).unwrap());
+    assert_eq!(day(2025), LocalDate::ymd(2025, Month::November, 19).unwrap());
+}
+
+#[test]
+fn every_state() {
+    let sets = HolidaySets::builtin();
+    let states: Vec<_> = sets.names().filter(|name| name.starts_with("de-")).collect();
+    assert_eq!(states.len(), 16);
+}
+
+
+#[test]
+fn public_2024() {
+    assert_eq!(observed("fr", 2024), vec![
+        "2024-01-01", "2024-04-01", "2024-05-01", "2024-05-08", "2024-05-09", "2024-05-20",
+        "2024-07-14", "2024-08-15", "2024-11-01", "2024-11-11", "2024-12-25",
+    ]);
+}
+
+
+// Holidays on a Sunday are left out of these, as they’re observed on
+// the substitute holiday instead.
+
+#[test]
+fn national_2019() {
+    assert_eq!(observed("jp", 2019), vec![
+        "2019-01-01", "2019-01-14", "2019-02-11", "2019-03-21", "2019-04-29", "2019-04-30",
+        "2019-05-01", "2019-05-02", "2019-05-03", "2019-05-04", "2019-05-06",
+        "2019-07-15", "2019-08-12", "2019-09-16", "2019-09-23", "2019-10-14",
+        "2019-10-22", "2019-11-04", "2019-11-23",
+    ]);
+}
+
+#[test]
+fn national_2020() {
+    assert_eq!(observed("jp", 2020), vec![
+        "2020-01-01", "2020-01-13", "2020-02-11", "2020-02-24", "2020-03-20",
+        "2020-04-29", "2020-05-04", "2020-05-05", "2020-05-06", "2020-07-23",
+        "2020-07-24", "2020-08-10", "2020-09-21", "2020-09-22", "2020-11-03", "2020-11-23",
+    ]);
+}
+
+#[test]
+fn national_2026() {
+    assert_eq!(observed("jp", 2026), vec![
+        "2026-01-01", "2026-01-12", "2026-02-11", "2026-02-23", "2026-03-20", "2026-04-29",
+        "2026-05-04", "2026-05-05", "2026-05-06", "2026-07-20", "2026-08-11",
+        "2026-09-21", "2026-09-22", "2026-09-23", "2026-10-12", "2026-11-03", "2026-11-23",
+    ]);
+}
+
+#[test]
+fn citizens_holiday() {
+    let holidays = holidays::builtin("jp").unwrap().holidays(Year(2026));
+    let bridge = holidays.iter().find(|h| h.date == LocalDate::ymd(2026, Month::September, 22).unwrap()).unwrap();
+    assert_eq!(bridge.name, "Citizens’ Holiday");
+}
+
+#[test]
+fn equinoxes() {
+    let rule = HolidayRule { date: HolidayDate::MarchEquinox, ..HolidayRule::fixed("", Month::January, 1) };
+    assert_eq!(rule.date(2024), Some(LocalDate::ymd(2024, Month::March, 20).unwrap()));
+    assert_eq!(rule.date(2025), Some(LocalDate::ymd(2025, Month::March, 20).unwrap()));
+    assert_eq!(rule.date(1899), None);
+}
+
+#[test]
+fn equinoxes_before_1980() {
+    let march = HolidayRule { date: HolidayDate::MarchEquinox, ..HolidayRule::fixed("", Month::January, 1) };
+    let september = HolidayRule { date: HolidayDate::SeptemberEquinox, ..HolidayRule::fixed("", Month::January, 1) };
+    assert_eq!(march.date(1976), Some(LocalDate::ymd(1976, Month::March, 20).unwrap()));
+    assert_eq!(march.date(1977), Some(LocalDate::ymd(1977, Month::March, 21).unwrap()));
+    assert_eq!(march.date(1978), Some(LocalDate::ymd(1978, Month::March, 21).unwrap()));
+    for year in 1976 ..= 1978 {
+        assert_eq!(september.date(year), Some(LocalDate::ymd(year, Month::September, 23).unwrap()));
+    }
+}
+
+
+#[test]
+fn company_holidays() {
+    let mut sets = HolidaySets::builtin();
+    sets.load("
+        # Holidays at our company.
+        [acme]
+        include: gb-eng
+        December 24: Christmas Eve
+        Friday on or after November 23 [2025-]: Founders’ Day
+    ").unwrap();
+
+    let acme = sets.get("acme").unwrap();
+    assert_eq!(acme.holidays(Year(2024)).len(), 9);
+    assert_eq!(acme.holidays(Year(2025)).len(), 10);
+    assert_eq!(sets.get("gb-eng").unwrap().holidays(Year(2025)).len(), 8);
+}
+
+#[test]
+fn adding_to_a_set() {
+    let mut sets = HolidaySets::builtin();
+    sets.load("[fr]\nDecember 26: St Stephen’s Day").unwrap();
+    assert_eq!(sets.get("fr").unwrap().holidays(Year(2024)).len(), 12);
+}
+
+#[test]
+fn observances() {
+    let sets: HolidaySets = "
+        [test]
+        July 4: Actual
+        observed: next monday
+        July 4: Next Monday
+        observed: Nearest Weekday
+        July 4: Nearest weekday
+    ".parse().unwrap();
+
+    // The 4th of July 2026 is a Saturday.
+    let observed: Vec<_> = sets.get("test").unwrap().holidays(Year(2026)).into_iter().map(|h| h.observed).collect();
+    assert_eq!(observed, vec![
+        LocalDate::ymd(2026, Month::July, 3).unwrap(), LocalDate::ymd(2026, Month::July, 4).unwrap(),
+        LocalDate::ymd(2026, Month::July, 6).unwrap(),
+    ]);
+}
+
+#[test]
+fn dates() {
+    let sets: HolidaySets = "
+        [test]
+        last Friday in March: Last Friday
+        Orthodox Easter: Orthodox Easter
+        easter -46: Ash Wednesday
+        September equinox: Equinox
+        2024-02-29 : Leap day
+    ".parse().unwrap();
+
+    let dates: Vec<_> = sets.get("test").unwrap().holidays(Year(2024)).into_iter().map(|h| h.date).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(2024, Month::February, 14).unwrap(), LocalDate::ymd(2024, Month::February, 29).unwrap(),
+        LocalDate::ymd(2024, Month::March, 29).unwrap(), LocalDate::ymd(2024, Month::May, 5).unwrap(),
+        LocalDate::ymd(2024, Month::September, 22).unwrap(),
+    ]);
+}
+
+#[test]
+fn year_spans() {
+    let sets: HolidaySets = "[test]\nMay 1 [-2010, 2015, 2020-]: Holiday".parse().unwrap();
+    let set = sets.get("test").unwrap();
+    let years: Vec<i64> = (2005 .. 2025).filter(|y| !set.holidays(Year(*y)).is_empty()).collect();
+    assert_eq!(years, vec![ 2005, 2006, 2007, 2008, 2009, 2010, 2015, 2020, 2021, 2022, 2023, 2024 ]);
+}
+
+#[test]
+fn no_set() {
+    assert_eq!("January 1: New Year’s Day".parse::<HolidaySets>(), Err(HolidayError::NoSet { line: 1 }));
+}
+
+#[test]
+fn unknown_set() {
+    assert_eq!("[test]\ninclude: nowhere".parse::<HolidaySets>(),
+               Err(HolidayError::UnknownSet { line: 2, name: "nowhere".to_owned() }));
+}
+
+#[test]
+fn invalid_lines() {
+    for line in &[ "Smarch 1: Holiday", "sixth Monday in May: Holiday", "Easter 2: Holiday",
+                   "January 1", "January 1:", "observed: sometimes", "May 1 [2020: Holiday",
+                   "2024-01-01 [2024]: Holiday", "May 1 [-]: Holiday" ] {
+        let text = format!("[test]\n{}", line);
+        assert_eq!(text.parse::<HolidaySets>(), Err(HolidayError::InvalidLine { line: 2, text: line.to_string() }), "{}", line);
+    }
+}
+
+#[test]
+fn errors_leave_sets_unchanged() {
+    let mut sets = HolidaySets::builtin();
+    assert!(sets.load("[us]\nDecember 24: Christmas Eve\nnonsense").is_err());
+    assert_eq!(sets, HolidaySets::builtin());
+}
@@ -0,0 +1,135 @@
+extern crate datetime;
+pub use datetime::Duration;
+pub use datetime::human::{self, DurationFormat, DurationParser, ParseError, Unit, Rounding};
+
+
+mod parsing {
+    use super::*;
+
+    #[test]
+    fn compact() {
+        assert_eq!(human::parse("1h30m"), Ok(Duration::of(5400)));
+    }
+
+    #[test]
+    fn seconds() {
+        assert_eq!(human::parse("90s"), Ok(Duration::of(90)));
+    }
+
+    #[test]
+    fn words() {
+        assert_eq!(human::parse("2 days"), Ok(Duration::of(2 * 86400)));
+    }
+
+    #[test]
+    fn fraction() {
+        assert_eq!(human::parse("1.5h"), Ok(Duration::of(5400)));
+    }
+
+    #[test]
+    fn sentence() {
+        assert_eq!(human::parse("1 Hour, 2 minutes and 3 secs"), Ok(Duration::of(3723)));
+    }
+
+    #[test]
+    fn negative() {
+        assert_eq!(human::parse("-1m 30s"), Ok(Duration::of(-90)));
+    }
+
+    #[test]
+    fn milliseconds() {
+        assert_eq!(human::parse("250ms"), Ok(Duration::of_ms(0, 250)));
+    }
+
+    #[test]
+    fn unknown_unit() {
+        assert_eq!(human::parse("3 fortnights"), Err(ParseError::UnknownUnit { name: "fortnights".to_string(), pos: 2 }));
+    }
+
+    #[test]
+    fn missing_unit() {
+        assert_eq!(human::parse("3"), Err(ParseError::MissingUnit { pos: 1 }));
+    }
+
+    #[test]
+    fn missing_number() {
+        assert_eq!(human::parse("1h m"), Err(ParseError::MissingNumber { pos: 3 }));
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(human::parse("  "), Err(ParseError::Empty));
+    }
+
+    #[test]
+    fn custom_units() {
+        let mut parser = DurationParser::empty();
+        parser.add_unit("Stunden", Unit::Hour);
+        assert_eq!(parser.parse("2 stunden"), Ok(Duration::of(7200)));
+        assert!(parser.parse("2h").is_err());
+    }
+}
+
+
+mod formatting {
+    use super::*;
+
+    #[test]
+    fn compact() {
+        assert_eq!(DurationFormat::default().format(&Duration::of_ms(5405, 200)), "1h 30m 5.2s");
+    }
+
+    #[test]
+    fn zero() {
+        assert_eq!(DurationFormat::default().format(&Duration::zero()), "0s");
+    }
+
+    #[test]
+    fn days() {
+        assert_eq!(DurationFormat::default().format(&Duration::of(90061)), "1d 1h 1m 1s");
+    }
+
+    #[test]
+    fn negative() {
+        assert_eq!(DurationFormat::default().format(&Duration::of(-90)), "-1m 30s");
+    }
+
+    #[test]
+    fn smallest_unit_rounds() {
+        let format = DurationFormat { smallest: Unit::Minute, decimals: 0, rounding: Rounding::Nearest, ..DurationFormat::default() };
+        assert_eq!(format.format(&Duration::of(5430)), "1h 31m");
+
+        let format = DurationFormat { rounding: Rounding::Down, ..format };
+        assert_eq!(format.format(&Duration::of(5430)), "1h 30m");
+    }
+
+    #[test]
+    fn decimals() {
+        let format = DurationFormat { smallest: Unit::Hour, decimals: 1, ..DurationFormat::default() };
+        assert_eq!(format.format(&Duration::of(5400)), "1.5h");
+    }
+
+    #[test]
+    fn more_than_nine_decimals() {
+        let format = DurationFormat { decimals: 12, ..DurationFormat::default() };
+        assert_eq!(format.format(&Duration::of_ms(0, 500)), "0.5s");
+        assert_eq!(format.format(&Duration::of_ms(1, 5)), "1.005s");
+    }
+
+    #[test]
+    fn separator() {
+        let format = DurationFormat { separator: "", ..DurationFormat::default() };
+        assert_eq!(format.format(&Duration::of(3723)), "1h2m3s");
+    }
+}
+
+
+#[test]
+fn round_trips() {
+    let format = DurationFormat { largest: Unit::Week, smallest: Unit::Millisecond, ..DurationFormat::default() };
+    for &(s, ms) in &[ (0, 1), (59, 999), (3600, 0), (694861, 5), (-7200, 500) ] {
+        let duration = Duration::of_ms(s, ms);
+        assert_eq!(human::parse(&format.format(&duration)), Ok(duration));
+        assert_eq!(human::parse(&DurationFormat::default().format(&duration)), Ok(duration));
+    }
+}
@@ -0,0 +1,201 @@
+extern crate datetime;
+use datetime::{LocalDate, LocalDateTime, LocalTime, Instant, Duration, TimeUnit};
+use datetime::interval::{Interval, IntervalSet};
+use datetime::Month::*;
+
+fn span<T: Ord + Copy>(start: T, end: T) -> Interval<T> {
+    Interval::new(start, end).unwrap()
+}
+
+fn seconds(start: i64, end: i64) -> Interval<Instant> {
+    span(Instant::at(start), Instant::at(end))
+}
+
+
+#[test]
+fn backwards() {
+    assert!(Interval::new(LocalDate::ymd(2017, June, 2).unwrap(), LocalDate::ymd(2017, June, 1).unwrap()).is_err());
+}
+
+#[test]
+fn intervals_empty() {
+    let empty = span(LocalDate::ymd(2017, June, 1).unwrap(), LocalDate::ymd(2017, June, 1).unwrap());
+    assert!(empty.is_empty());
+    assert!(!empty.contains(LocalDate::ymd(2017, June, 1).unwrap()));
+}
+
+#[test]
+fn half_open() {
+    let nights = span(LocalDate::ymd(2017, June, 1).unwrap(), LocalDate::ymd(2017, June, 4).unwrap());
+    assert!(nights.contains(LocalDate::ymd(2017, June, 1).unwrap()));
+    assert!(nights.contains(LocalDate::ymd(2017, June, 3).unwrap()));
+    assert!(!nights.contains(LocalDate::ymd(2017, June, 4).unwrap()));
+    assert_eq!(nights.days(), 3);
+}
+
+#[test]
+fn touching_intervals_dont_overlap() {
+    let first = seconds(0, 10);
+    let second = seconds(10, 20);
+    assert!(!first.overlaps(&second));
+    assert_eq!(first.intersection(&second), None);
+    assert_eq!(first.union(&second), Some(seconds(0, 20)));
+    assert_eq!(first.gap(&second), None);
+}
+
+#[test]
+fn gap() {
+    assert_eq!(seconds(0, 10).gap(&seconds(15, 20)), Some(seconds(10, 15)));
+    assert_eq!(seconds(15, 20).gap(&seconds(0, 10)), Some(seconds(10, 15)));
+    assert_eq!(seconds(0, 10).union(&seconds(15, 20)), None);
+}
+
+#[test]
+fn encloses() {
+    assert!(seconds(0, 10).encloses(&seconds(2, 10)));
+    assert!(!seconds(0, 10).encloses(&seconds(2, 11)));
+}
+
+#[test]
+fn union_with_empty() {
+    assert_eq!(seconds(0, 10).union(&seconds(50, 50)), Some(seconds(0, 10)));
+}
+
+#[test]
+fn durations() {
+    let start = LocalDateTime::new(LocalDate::ymd(2017, June, 1).unwrap(), LocalTime::hm(22, 0).unwrap());
+    let end = LocalDateTime::new(LocalDate::ymd(2017, June, 2).unwrap(), LocalTime::hm(6, 30).unwrap());
+    assert_eq!(span(start, end).duration(), Duration::of(8 * 3600 + 1800));
+    assert_eq!(span(Instant::at_ms(0, 500), Instant::at(2)).duration(), Duration::of_ms(1, 500));
+}
+
+
+#[test]
+fn shift_across_midnight() {
+    let start = LocalDateTime::new(LocalDate::ymd(2017, June, 1).unwrap(), LocalTime::hm(22, 0).unwrap());
+    let second = LocalDateTime::new(LocalDate::ymd(2017, June, 2).unwrap(), LocalTime::midnight());
+    let third = LocalDateTime::new(LocalDate::ymd(2017, June, 3).unwrap(), LocalTime::midnight());
+    let end = LocalDateTime::new(LocalDate::ymd(2017, June, 3).unwrap(), LocalTime::hm(6, 0).unwrap());
+
+    let pieces = span(start, end).split(TimeUnit::Days(1));
+    assert_eq!(pieces, vec![ span(start, second), span(second, third), span(third, end) ]);
+}
+
+#[test]
+fn hours() {
+    let day = LocalDate::ymd(2017, June, 1).unwrap();
+    let start = LocalDateTime::new(day, LocalTime::hm(9, 30).unwrap());
+    let ten = LocalDateTime::new(day, LocalTime::hm(10, 0).unwrap());
+    let end = LocalDateTime::new(day, LocalTime::hm(11, 0).unwrap());
+
+    let pieces = span(start, end).split(TimeUnit::Hours(1));
+    assert_eq!(pieces, vec![ span(start, ten), span(ten, end) ]);
+}
+
+#[test]
+fn dates_by_month() {
+    let start = LocalDate::ymd(2017, January, 15).unwrap();
+    let end = LocalDate::ymd(2017, March, 10).unwrap();
+    let pieces = span(start, end).split(TimeUnit::Months(1));
+    assert_eq!(pieces, vec![ span(LocalDate::ymd(2017, January, 15).unwrap(), LocalDate::ymd(2017, February, 1).unwrap()),
+                             span(LocalDate::ymd(2017, February, 1).unwrap(), LocalDate::ymd(2017, March, 1).unwrap()),
+                             span(LocalDate::ymd(2017, March, 1).unwrap(), LocalDate::ymd(2017, March, 10).unwrap()) ]);
+}
+
+#[test]
+fn dates_by_hour() {
+    let start = LocalDate::ymd(2017, January, 1).unwrap();
+    let end = LocalDate::ymd(2017, January, 4).unwrap();
+    assert_eq!(span(start, end).split(TimeUnit::Hours(1)).len(), 3);
+}
+
+#[test]
+fn instants() {
+    let pieces = seconds(50, 130).split(TimeUnit::Minutes(1));
+    assert_eq!(pieces, vec![ seconds(50, 60), seconds(60, 120), seconds(120, 130) ]);
+}
+
+#[test]
+fn splitting_empty() {
+    assert!(seconds(50, 50).split(TimeUnit::Minutes(1)).is_empty());
+}
+
+
+fn set(intervals: &[(i64, i64)]) -> IntervalSet<Instant> {
+    intervals.iter().map(|&(s, e)| seconds(s, e)).collect()
+}
+
+fn spans(set: &IntervalSet<Instant>) -> Vec<(i64, i64)> {
+    set.iter().map(|i| (i.start().seconds(), i.end().seconds())).collect()
+}
+
+#[test]
+fn merges_overlapping() {
+    assert_eq!(spans(&set(&[ (0, 10), (5, 15), (30, 40) ])), vec![ (0, 15), (30, 40) ]);
+}
+
+#[test]
+fn merges_touching() {
+    assert_eq!(spans(&set(&[ (0, 10), (10, 20) ])), vec![ (0, 20) ]);
+}
+
+#[test]
+fn keeps_order() {
+    assert_eq!(spans(&set(&[ (30, 40), (0, 10), (15, 20) ])), vec![ (0, 10), (15, 20), (30, 40) ]);
+}
+
+#[test]
+fn bridges() {
+    assert_eq!(spans(&set(&[ (0, 10), (20, 30), (40, 50), (5, 45) ])), vec![ (0, 50) ]);
+}
+
+#[test]
+fn ignores_empty() {
+    assert!(set(&[ (5, 5) ]).is_empty());
+}
+
+#[test]
+fn remove_splits() {
+    let mut s = set(&[ (0, 100) ]);
+    s.remove(seconds(40, 60));
+    assert_eq!(spans(&s), vec![ (0, 40), (60, 100) ]);
+}
+
+#[test]
+fn remove_across_intervals() {
+    let mut s = set(&[ (0, 10), (20, 30), (40, 50) ]);
+    s.remove(seconds(5, 45));
+    assert_eq!(spans(&s), vec![ (0, 5), (45, 50) ]);
+}
+
+#[test]
+fn difference() {
+    let free = set(&[ (0, 100) ]).difference(&set(&[ (10, 20), (50, 60) ]));
+    assert_eq!(spans(&free), vec![ (0, 10), (20, 50), (60, 100) ]);
+}
+
+#[test]
+fn union() {
+    assert_eq!(spans(&set(&[ (0, 10) ]).union(&set(&[ (5, 20), (30, 40) ]))), vec![ (0, 20), (30, 40) ]);
+}
+
+#[test]
+fn intersection() {
+    let both = set(&[ (0, 10), (20, 30) ]).intersection(&set(&[ (5, 25) ]));
+    assert_eq!(spans(&both), vec![ (5, 10), (20, 25) ]);
+}
+
+#[test]
+fn gaps_and_span() {
+    let s = set(&[ (0, 10), (20, 30), (40, 50) ]);
+    assert_eq!(spans(&s.gaps()), vec![ (10, 20), (30, 40) ]);
+    assert_eq!(s.span(), Some(seconds(0, 50)));
+    assert_eq!(IntervalSet::<Instant>::new().span(), None);
+}
+
+#[test]
+fn contains() {
+    let s = set(&[ (0, 10), (20, 30) ]);
+    assert!(s.contains(Instant::at(25)));
+    assert!(!s.contains(Instant::at(10)));
+}
@@ -0,0 +1,162 @@
+extern crate datetime;
+pub use datetime::{Duration, Period, DurationError, ISO};
+pub use std::str::FromStr;
+
+
+mod parsing {
+    use super::*;
+
+    #[test]
+    fn days_and_time() {
+        assert_eq!(Duration::from_str("P1DT2H3M4S"), Ok(Duration::of(86400 + 7384)));
+    }
+
+    #[test]
+    fn weeks() {
+        assert_eq!(Duration::from_str("P2W"), Ok(Duration::of(14 * 86400)));
+    }
+
+    #[test]
+    fn negative() {
+        assert_eq!(Duration::from_str("-PT1.5S"), Ok(Duration::of_ms(-2, 500)));
+    }
+
+    #[test]
+    fn fractional_hours() {
+        assert_eq!(Duration::from_str("PT0.5H"), Ok(Duration::of(1800)));
+    }
+
+    #[test]
+    fn missing_p() {
+        assert_eq!(Duration::from_str("T1H"), Err(DurationError::MissingDesignator { pos: 0 }));
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(Duration::from_str("P"), Err(DurationError::UnexpectedEnd { pos: 1 }));
+    }
+
+    #[test]
+    fn empty_time() {
+        assert_eq!(Duration::from_str("P1DT"), Err(DurationError::UnexpectedEnd { pos: 4 }));
+    }
+
+    #[test]
+    fn out_of_order() {
+        assert_eq!(Duration::from_str("PT1M1H"), Err(DurationError::OutOfOrder { c: 'H', pos: 5 }));
+    }
+
+    #[test]
+    fn repeated() {
+        assert_eq!(Duration::from_str("P1D1D"), Err(DurationError::OutOfOrder { c: 'D', pos: 4 }));
+    }
+
+    #[test]
+    fn hours_in_date_part() {
+        assert_eq!(Duration::from_str("P1H"), Err(DurationError::UnexpectedChar { c: 'H', pos: 2 }));
+    }
+
+    #[test]
+    fn weeks_with_days() {
+        assert_eq!(Duration::from_str("P1W2D"), Err(DurationError::MixedWeeks { pos: 1 }));
+    }
+
+    #[test]
+    fn missing_number() {
+        assert_eq!(Duration::from_str("PTS"), Err(DurationError::UnexpectedChar { c: 'S', pos: 2 }));
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(Duration::from_str("PT99999999999999999999S"), Err(DurationError::Overflow { pos: 2 }));
+    }
+
+    #[test]
+    fn period_with_signs() {
+        assert_eq!(Period::from_str("P1Y-2M"), Ok(Period::ymd(1, -2, 0)));
+    }
+
+    #[test]
+    fn negative_period() {
+        assert_eq!(Period::from_str("-P1Y2M"), Ok(Period::ymd(-1, -2, 0)));
+    }
+
+    #[test]
+    fn period_fraction() {
+        assert_eq!(Period::from_str("P1.5D"), Err(DurationError::Fraction { pos: 1 }));
+    }
+}
+
+
+mod formatting {
+    use super::*;
+
+    #[test]
+    fn zero() {
+        assert_eq!(Duration::zero().iso().to_string(), "PT0S");
+    }
+
+    #[test]
+    fn days_and_time() {
+        assert_eq!(Duration::of(93784).iso().to_string(), "P1DT2H3M4S");
+    }
+
+    #[test]
+    fn whole_days() {
+        assert_eq!(Duration::of(172800).iso().to_string(), "P2D");
+    }
+
+    #[test]
+    fn milliseconds() {
+        assert_eq!(Duration::of_ms(61, 250).iso().to_string(), "PT1M1.25S");
+    }
+
+    #[test]
+    fn negative() {
+        assert_eq!(Duration::of_ms(-2, 500).iso().to_string(), "-PT1.5S");
+    }
+
+    #[test]
+    fn period() {
+        let period = Period { years: 1, months: 2, days: 10, hours: 2, minutes: 30, ..Period::zero() };
+        assert_eq!(period.iso().to_string(), "P1Y2M10DT2H30M");
+    }
+
+    #[test]
+    fn period_weeks() {
+        assert_eq!(Period::weeks(3).iso().to_string(), "P3W");
+        assert_eq!(Period { weeks: 1, days: 2, ..Period::zero() }.iso().to_string(), "P9D");
+    }
+
+    #[test]
+    fn zero_period() {
+        assert_eq!(Period::zero().iso().to_string(), "P0D");
+    }
+
+    #[test]
+    fn mixed_signs() {
+        assert_eq!(Period::ymd(1, -2, 0).iso().to_string(), "P1Y-2M");
+        assert_eq!(Period::ymd(-1, -2, 0).iso().to_string(), "-P1Y2M");
+    }
+}
+
+
+mod round_trips {
+    use super::*;
+
+    #[test]
+    fn durations() {
+        for input in &[ "PT0S", "P3DT4H", "PT0.001S", "-P1DT0.5S", "PT59M59.999S" ] {
+            let duration = Duration::from_str(input).unwrap();
+            assert_eq!(&duration.iso().to_string(), input);
+        }
+    }
+
+    #[test]
+    fn periods() {
+        for input in &[ "P0D", "P2W", "P1Y2M3DT4H5M6.7S", "-P1M", "P1Y-1D" ] {
+            let period = Period::from_str(input).unwrap();
+            assert_eq!(&period.iso().to_string(), input);
+        }
+    }
+}
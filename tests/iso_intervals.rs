@@ -0,0 +1,186 @@
+extern crate datetime;
+use datetime::{LocalDate, LocalDateTime, LocalTime, Instant, Offset, Period, IntervalError, ISO};
+use datetime::interval::{Interval, IsoInterval, RepeatingInterval};
+use datetime::Month::*;
+use std::str::FromStr;
+
+fn seconds(start: i64, end: i64) -> Interval<Instant> {
+    Interval::new(Instant::at(start), Instant::at(end)).unwrap()
+}
+
+
+#[test]
+fn parsing_start_and_end() {
+    let interval = IsoInterval::from_str("2007-03-01T13:00:00Z/2008-05-11T15:30:00Z").unwrap();
+    assert_eq!(interval.to_interval(), Some(seconds(1_172_754_000, 1_210_519_800)));
+}
+
+#[test]
+fn parsing_start_and_period() {
+    let interval = IsoInterval::from_str("2007-03-01T13:00:00Z/P1Y2M10DT2H30M").unwrap();
+    assert_eq!(interval.to_interval(), Some(seconds(1_172_754_000, 1_210_519_800)));
+}
+
+#[test]
+fn parsing_period_and_end() {
+    let interval = IsoInterval::from_str("P1Y2M10DT2H30M/2008-05-11T15:30:00Z").unwrap();
+    assert_eq!(interval.to_interval(), Some(seconds(1_172_754_000, 1_210_519_800)));
+}
+
+#[test]
+fn parsing_period_only() {
+    let interval = IsoInterval::from_str("P1Y2M10DT2H30M").unwrap();
+    assert_eq!(interval, IsoInterval::Period(Period { years: 1, months: 2, days: 10, hours: 2, minutes: 30, ..Period::zero() }));
+    assert_eq!(interval.start(), None);
+    assert_eq!(interval.to_interval(), None);
+}
+
+#[test]
+fn offsets() {
+    let interval = IsoInterval::from_str("2007-03-01T14:00:00+01:00/2007-03-01T10:00:00-05:00").unwrap();
+    assert_eq!(interval.to_interval(), Some(seconds(1_172_754_000, 1_172_761_200)));
+}
+
+#[test]
+fn two_periods() {
+    assert_eq!(IsoInterval::from_str("P1D/P2D"), Err(IntervalError::Format));
+}
+
+#[test]
+fn too_many_parts() {
+    assert_eq!(IsoInterval::from_str("2007-03-01T13:00:00Z/P1D/P1D"), Err(IntervalError::Format));
+}
+
+#[test]
+fn bad_period() {
+    assert!(matches!(IsoInterval::from_str("2007-03-01T13:00:00Z/P1X"), Err(IntervalError::Duration(_))));
+}
+
+#[test]
+fn bad_datetime() {
+    assert!(matches!(IsoInterval::from_str("yesterday/P1D"), Err(IntervalError::DateTime(_))));
+}
+
+#[test]
+fn parsing_repeating() {
+    let repeating = RepeatingInterval::from_str("R5/2008-03-01T13:00:00Z/P1Y2M10DT2H30M").unwrap();
+    assert_eq!(repeating.repetitions, Some(5));
+    assert!(matches!(repeating.interval, IsoInterval::StartPeriod(..)));
+}
+
+#[test]
+fn parsing_repeating_forever() {
+    assert_eq!(RepeatingInterval::from_str("R/P1D/2008-03-01T13:00:00Z").unwrap().repetitions, None);
+    assert_eq!(RepeatingInterval::from_str("R-1/P1D/2008-03-01T13:00:00Z").unwrap().repetitions, None);
+}
+
+#[test]
+fn bad_repetitions() {
+    assert_eq!(RepeatingInterval::from_str("Rfive/P1D/2008-03-01T13:00:00Z"), Err(IntervalError::Repetitions));
+    assert_eq!(RepeatingInterval::from_str("2008-03-01T13:00:00Z/P1D"), Err(IntervalError::Format));
+    assert_eq!(RepeatingInterval::from_str("R5"), Err(IntervalError::Format));
+}
+
+
+#[test]
+fn formatting_start_and_end() {
+    let start = Offset::utc().transform_date(LocalDateTime::new(LocalDate::ymd(2007, March, 1).unwrap(), LocalTime::hm(13, 0).unwrap()));
+    let end = Offset::utc().transform_date(LocalDateTime::new(LocalDate::ymd(2008, May, 11).unwrap(), LocalTime::hm(15, 30).unwrap()));
+    let interval = IsoInterval::StartEnd(start, end);
+    assert_eq!(interval.iso().to_string(), "2007-03-01T13:00:00.000Z/2008-05-11T15:30:00.000Z");
+}
+
+#[test]
+fn formatting_start_and_period() {
+    let start = Offset::utc().transform_date(LocalDateTime::new(LocalDate::ymd(2007, March, 1).unwrap(), LocalTime::hm(13, 0).unwrap()));
+    let interval = IsoInterval::StartPeriod(start, Period::ymd(1, 2, 10));
+    assert_eq!(interval.iso().to_string(), "2007-03-01T13:00:00.000Z/P1Y2M10D");
+}
+
+#[test]
+fn formatting_period_and_end() {
+    let end = Offset::utc().transform_date(LocalDateTime::new(LocalDate::ymd(2008, May, 11).unwrap(), LocalTime::hm(15, 30).unwrap()));
+    let interval = IsoInterval::PeriodEnd(Period::hms(2, 30, 0), end);
+    assert_eq!(interval.iso().to_string(), "PT2H30M/2008-05-11T15:30:00.000Z");
+}
+
+#[test]
+fn formatting_period_only() {
+    assert_eq!(IsoInterval::Period(Period::weeks(2)).iso().to_string(), "P2W");
+}
+
+#[test]
+fn formatting_repeating() {
+    let start = Offset::utc().transform_date(LocalDateTime::new(LocalDate::ymd(2008, March, 1).unwrap(), LocalTime::hm(13, 0).unwrap()));
+    let repeating = RepeatingInterval { repetitions: Some(5), interval: IsoInterval::StartPeriod(start, Period::days(1)) };
+    assert_eq!(repeating.iso().to_string(), "R5/2008-03-01T13:00:00.000Z/P1D");
+}
+
+#[test]
+fn formatting_repeating_forever() {
+    let end = Offset::utc().transform_date(LocalDateTime::new(LocalDate::ymd(2008, March, 1).unwrap(), LocalTime::hm(13, 0).unwrap()));
+    let repeating = RepeatingInterval { repetitions: None, interval: IsoInterval::PeriodEnd(Period::days(1), end) };
+    assert_eq!(repeating.iso().to_string(), "R/P1D/2008-03-01T13:00:00.000Z");
+}
+
+#[test]
+fn round_trip() {
+    let start = Offset::utc().transform_date(LocalDateTime::new(LocalDate::ymd(2007, March, 1).unwrap(), LocalTime::hm(13, 0).unwrap()));
+    let end = Offset::utc().transform_date(LocalDateTime::new(LocalDate::ymd(2008, May, 11).unwrap(), LocalTime::hm(15, 30).unwrap()));
+    let interval = IsoInterval::StartEnd(start, end);
+    assert_eq!(IsoInterval::from_str(&interval.iso().to_string()).unwrap().to_interval(), interval.to_interval());
+}
+
+
+#[test]
+fn occurrences_start_and_end() {
+    let repeating = RepeatingInterval::from_str("R3/2007-03-01T13:00:00Z/2007-03-01T14:00:00Z").unwrap();
+    let occurrences: Vec<_> = repeating.occurrences().unwrap().collect();
+    assert_eq!(occurrences, vec![ seconds(1_172_754_000, 1_172_757_600),
+                                  seconds(1_172_757_600, 1_172_761_200),
+                                  seconds(1_172_761_200, 1_172_764_800) ]);
+}
+
+#[test]
+fn months_from_the_start() {
+    let repeating = RepeatingInterval::from_str("R4/2017-01-31T09:00:00Z/P1M").unwrap();
+    let occurrences: Vec<_> = repeating.occurrences().unwrap().collect();
+    assert_eq!(occurrences, vec![ seconds(1_485_853_200, 1_488_272_400),
+                                  seconds(1_488_272_400, 1_490_950_800),
+                                  seconds(1_490_950_800, 1_493_542_800),
+                                  seconds(1_493_542_800, 1_496_221_200) ]);
+}
+
+#[test]
+fn backwards_from_the_end() {
+    let repeating = RepeatingInterval::from_str("R2/PT1H/2007-03-01T13:00:00Z").unwrap();
+    let occurrences: Vec<_> = repeating.occurrences().unwrap().collect();
+    assert_eq!(occurrences, vec![ seconds(1_172_750_400, 1_172_754_000),
+                                  seconds(1_172_746_800, 1_172_750_400) ]);
+}
+
+#[test]
+fn forever() {
+    let repeating = RepeatingInterval::from_str("R/2007-03-01T13:00:00Z/P1D").unwrap();
+    let mut occurrences = repeating.occurrences().unwrap();
+    assert_eq!(occurrences.size_hint(), (0, None));
+    assert_eq!(occurrences.nth(1000), Some(seconds(1_172_754_000 + 1000 * 86400, 1_172_754_000 + 1001 * 86400)));
+}
+
+#[test]
+fn none() {
+    let repeating = RepeatingInterval::from_str("R0/2007-03-01T13:00:00Z/P1D").unwrap();
+    assert_eq!(repeating.occurrences().unwrap().next(), None);
+}
+
+#[test]
+fn occurrences_period_only() {
+    let repeating = RepeatingInterval::from_str("R5/P1D").unwrap();
+    assert_eq!(repeating.occurrences(), None);
+}
+
+#[test]
+fn backwards_interval() {
+    let repeating = RepeatingInterval::from_str("R5/2008-03-01T13:00:00Z/2007-03-01T13:00:00Z").unwrap();
+    assert_eq!(repeating.occurrences(), None);
+}
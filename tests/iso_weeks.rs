@@ -0,0 +1,87 @@
+extern crate datetime;
+use datetime::{DatePiece, DateRange, LocalDate, Month, Weekday, Year, YearWeek};
+
+
+#[test]
+fn start_of_year_in_previous_week_year() {
+    assert_eq!(LocalDate::ymd(2016, Month::January, 3).unwrap().iso_week(), (2015, 53, Weekday::Sunday));
+}
+
+#[test]
+fn end_of_year_in_next_week_year() {
+    assert_eq!(LocalDate::ymd(2019, Month::December, 30).unwrap().iso_week(), (2020, 1, Weekday::Monday));
+}
+
+#[test]
+fn middle_of_year() {
+    assert_eq!(LocalDate::ymd(2017, Month::June, 15).unwrap().iso_week(), (2017, 24, Weekday::Thursday));
+}
+
+#[test]
+fn round_trip() {
+    let start = LocalDate::ymd(1990, Month::January, 1).unwrap();
+    let end = LocalDate::ymd(2030, Month::January, 1).unwrap();
+    let range = DateRange::new(start, end);
+    for day in range {
+        let (year, week, weekday) = day.iso_week();
+        assert_eq!(LocalDate::ywd(year, week, weekday), Ok(day));
+    }
+}
+
+#[test]
+fn week_counts() {
+    let long_years: Vec<i64> = (2000 .. 2030).filter(|y| Year(*y).iso_week_count() == 53).collect();
+    assert_eq!(long_years, vec![ 2004, 2009, 2015, 2020, 2026 ]);
+}
+
+
+#[test]
+fn from_date() {
+    assert_eq!(LocalDate::ymd(2018, Month::December, 31).unwrap().year_week(), YearWeek { year: 2019, week: 1 });
+}
+
+#[test]
+fn days_run_monday_to_sunday() {
+    let days: Vec<Weekday> = YearWeek::new(2017, 1).unwrap().days().map(|d| d.weekday()).collect();
+    assert_eq!(days, vec![ Weekday::Monday, Weekday::Tuesday, Weekday::Wednesday, Weekday::Thursday,
+                           Weekday::Friday, Weekday::Saturday, Weekday::Sunday ]);
+}
+
+#[test]
+fn days_backwards() {
+    let mut days = YearWeek::new(2017, 1).unwrap().days();
+    assert_eq!(days.next_back(), Some(LocalDate::ymd(2017, Month::January, 8).unwrap()));
+}
+
+#[test]
+fn week_zero_is_invalid() {
+    assert!(YearWeek::new(2017, 0).is_err());
+}
+
+
+#[test]
+fn us_week_of_first_day() {
+    assert_eq!(LocalDate::ymd(2017, Month::January, 1).unwrap().week_of_year(Weekday::Sunday), 1);
+    assert_eq!(LocalDate::ymd(2016, Month::January, 1).unwrap().week_of_year(Weekday::Sunday), 1);
+}
+
+#[test]
+fn us_week_rolls_on_sunday() {
+    assert_eq!(LocalDate::ymd(2016, Month::January, 2).unwrap().week_of_year(Weekday::Sunday), 1);
+    assert_eq!(LocalDate::ymd(2016, Month::January, 3).unwrap().week_of_year(Weekday::Sunday), 2);
+}
+
+#[test]
+fn us_week_end_of_year() {
+    assert_eq!(LocalDate::ymd(2016, Month::December, 31).unwrap().week_of_year(Weekday::Sunday), 53);
+    assert_eq!(LocalDate::ymd(2000, Month::December, 31).unwrap().week_of_year(Weekday::Sunday), 54);
+}
+
+#[test]
+fn week_of_month() {
+    // The 1st of March 2017 was a Wednesday.
+    assert_eq!(LocalDate::ymd(2017, Month::March, 5).unwrap().week_of_month(Weekday::Monday), 1);
+    assert_eq!(LocalDate::ymd(2017, Month::March, 6).unwrap().week_of_month(Weekday::Monday), 2);
+    assert_eq!(LocalDate::ymd(2017, Month::March, 31).unwrap().week_of_month(Weekday::Monday), 5);
+    assert_eq!(LocalDate::ymd(2017, Month::April, 30).unwrap().week_of_month(Weekday::Sunday), 6);
+}
@@ -0,0 +1,135 @@
+extern crate datetime;
+use datetime::{LocalDate, Weekday, DatePiece};
+use datetime::julian::{self, JulianDate, MixedCalendar, MixedDate};
+use datetime::Month::*;
+
+fn britain() -> MixedCalendar {
+    MixedCalendar::new(LocalDate::ymd(1752, September, 14).unwrap())
+}
+
+
+#[test]
+fn julian_dates_leap_years() {
+    assert!(julian::is_leap_year(1900));
+    assert!(julian::is_leap_year(0));
+    assert!(julian::is_leap_year(-4));
+    assert!(!julian::is_leap_year(-1));
+    assert!(JulianDate::ymd(1900, February, 29).is_ok());
+    assert!(JulianDate::ymd(1901, February, 29).is_err());
+}
+
+#[test]
+fn gregorian_reform() {
+    assert_eq!(JulianDate::ymd(1582, October, 4).unwrap().to_local_date(), LocalDate::ymd(1582, October, 14).unwrap());
+    assert_eq!(JulianDate::ymd(1582, October, 5).unwrap().to_local_date(), LocalDate::ymd(1582, October, 15).unwrap());
+    assert_eq!(JulianDate::from_local_date(LocalDate::ymd(1582, October, 15).unwrap()),
+               JulianDate::ymd(1582, October, 5).unwrap());
+}
+
+#[test]
+fn weekdays() {
+    assert_eq!(JulianDate::ymd(1582, October, 4).unwrap().weekday(), Weekday::Thursday);
+    assert_eq!(JulianDate::ymd(1752, September, 2).unwrap().weekday(), Weekday::Wednesday);
+}
+
+#[test]
+fn drift() {
+    assert_eq!(JulianDate::from_local_date(LocalDate::ymd(2000, January, 14).unwrap()),
+               JulianDate::ymd(2000, January, 1).unwrap());
+    assert_eq!(JulianDate::from_local_date(LocalDate::ymd(1900, March, 13).unwrap()),
+               JulianDate::ymd(1900, February, 29).unwrap());
+    assert_eq!(JulianDate::from_local_date(LocalDate::ymd(200, March, 1).unwrap()), JulianDate::ymd(200, March, 1).unwrap());
+}
+
+// Reference values from Jean Meeus, “Astronomical Algorithms”,
+// chapter 7.
+#[test]
+fn meeus() {
+    assert_eq!(JulianDate::ymd(-4712, January, 1).unwrap().julian_day_number(), 0);
+    assert_eq!(JulianDate::ymd(333, January, 27).unwrap().julian_day_number(), 1_842_713);
+    assert_eq!(JulianDate::ymd(837, April, 10).unwrap().julian_day_number(), 2_026_872);
+    assert_eq!(JulianDate::ymd(-1000, July, 12).unwrap().julian_day_number(), 1_356_001);
+    assert_eq!(JulianDate::ymd(-1000, February, 29).unwrap().julian_day_number(), 1_355_867);
+    assert_eq!(JulianDate::ymd(-1001, August, 17).unwrap().julian_day_number(), 1_355_671);
+}
+
+#[test]
+fn round_trip() {
+    for jdn in (-1_000_000 .. 4_000_000).step_by(997) {
+        assert_eq!(JulianDate::from_julian_day_number(jdn).julian_day_number(), jdn);
+    }
+}
+
+#[test]
+fn yeardays() {
+    assert_eq!(JulianDate::ymd(1900, December, 31).unwrap().yearday(), 366);
+    assert_eq!(JulianDate::ymd(1901, March, 1).unwrap().yearday(), 60);
+}
+
+#[test]
+fn iso_weeks() {
+    // The 20th of December 2020 in the Julian calendar was the 2nd of
+    // January 2021 in the Gregorian one.
+    assert_eq!(JulianDate::ymd(2020, December, 20).unwrap().iso_week(), (2020, 53, Weekday::Saturday));
+    assert_eq!(JulianDate::ymd(2020, December, 20).unwrap().year_week(), LocalDate::ymd(2021, January, 2).unwrap().year_week());
+    assert_eq!(britain().mixed_date(LocalDate::ymd(1700, January, 1).unwrap()).iso_week(),
+               LocalDate::ymd(1700, January, 1).unwrap().iso_week());
+}
+
+
+#[test]
+fn before_the_cutover() {
+    assert_eq!(MixedCalendar::default().date(1582, October, 4), Ok(LocalDate::ymd(1582, October, 14).unwrap()));
+    assert_eq!(britain().date(1752, September, 2), Ok(LocalDate::ymd(1752, September, 13).unwrap()));
+}
+
+#[test]
+fn after_the_cutover() {
+    assert_eq!(MixedCalendar::default().date(1582, October, 15), Ok(LocalDate::ymd(1582, October, 15).unwrap()));
+    assert_eq!(britain().date(1752, September, 14), Ok(LocalDate::ymd(1752, September, 14).unwrap()));
+}
+
+#[test]
+fn skipped_days() {
+    for day in 5 .. 15 {
+        assert!(MixedCalendar::default().date(1582, October, day).is_err());
+    }
+    for day in 3 .. 14 {
+        assert!(britain().date(1752, September, day).is_err());
+    }
+}
+
+#[test]
+fn leap_days() {
+    assert_eq!(MixedCalendar::default().date(1500, February, 29), Ok(LocalDate::ymd(1500, March, 10).unwrap()));
+    assert!(MixedCalendar::default().date(1700, February, 29).is_err());
+    assert_eq!(britain().date(1700, February, 29), Ok(LocalDate::ymd(1700, March, 11).unwrap()));
+}
+
+#[test]
+fn mixed_calendars_leap_years() {
+    assert!(MixedCalendar::default().is_leap_year(1500));
+    assert!(!MixedCalendar::default().is_leap_year(1700));
+    assert!(britain().is_leap_year(1700));
+    assert!(!britain().is_leap_year(1800));
+
+    let russia = MixedCalendar::new(LocalDate::ymd(1918, February, 14).unwrap());
+    assert!(russia.is_leap_year(1900));
+    assert!(russia.is_leap_year(1916));
+}
+
+#[test]
+fn short_year() {
+    let start = britain().date(1752, January, 1).unwrap();
+    let end = britain().date(1753, January, 1).unwrap();
+    assert_eq!(start.days_until(end), 355);
+}
+
+#[test]
+fn fields() {
+    assert_eq!(britain().mixed_date(LocalDate::ymd(1752, September, 13).unwrap()),
+               MixedDate::Julian(JulianDate::ymd(1752, September, 2).unwrap()));
+    assert_eq!(britain().mixed_date(LocalDate::ymd(1752, September, 14).unwrap()),
+               MixedDate::Gregorian(LocalDate::ymd(1752, September, 14).unwrap()));
+    assert_eq!(britain().mixed_date(LocalDate::ymd(1752, September, 13).unwrap()).day(), 2);
+}
@@ -0,0 +1,91 @@
+extern crate datetime;
+use datetime::{LocalDate, LocalDateTime, LocalTime, Instant};
+use datetime::Month::*;
+
+
+#[test]
+fn j2000() {
+    assert_eq!(LocalDate::ymd(2000, January, 1).unwrap().julian_day_number(), 2_451_545);
+    assert_eq!(LocalDate::ymd(2000, January, 1).unwrap().modified_julian_day(), 51_544);
+}
+
+#[test]
+fn unix_epoch() {
+    assert_eq!(LocalDate::ymd(1970, January, 1).unwrap().julian_day_number(), 2_440_588);
+    assert_eq!(LocalDate::ymd(1970, January, 1).unwrap().modified_julian_day(), 40_587);
+}
+
+#[test]
+fn gregorian_reform() {
+    assert_eq!(LocalDate::ymd(1582, October, 15).unwrap().julian_day_number(), 2_299_161);
+}
+
+#[test]
+fn start_of_the_julian_period() {
+    assert_eq!(LocalDate::from_julian_day_number(0), LocalDate::ymd(-4713, November, 24).unwrap());
+}
+
+#[test]
+fn start_of_modified_julian_days() {
+    assert_eq!(LocalDate::from_modified_julian_day(0), LocalDate::ymd(1858, November, 17).unwrap());
+    assert_eq!(LocalDate::ymd(1858, November, 17).unwrap().julian_day_number(), 2_400_001);
+}
+
+#[test]
+fn gps_epoch() {
+    assert_eq!(LocalDate::from_modified_julian_day(44_244), LocalDate::ymd(1980, January, 6).unwrap());
+}
+
+#[test]
+fn round_trip() {
+    for jdn in (-1_000_000 .. 4_000_000).step_by(997) {
+        assert_eq!(LocalDate::from_julian_day_number(jdn).julian_day_number(), jdn);
+    }
+}
+
+
+// Reference values from Jean Meeus, “Astronomical Algorithms”,
+// chapter 7.
+#[test]
+fn meeus() {
+    assert_eq!(LocalDateTime::new(LocalDate::ymd(2000, January, 1).unwrap(), LocalTime::hm(12, 0).unwrap()).julian_date(), 2_451_545.0);
+    assert_eq!(LocalDateTime::new(LocalDate::ymd(1999, January, 1).unwrap(), LocalTime::midnight()).julian_date(), 2_451_179.5);
+    assert_eq!(LocalDateTime::new(LocalDate::ymd(1987, January, 27).unwrap(), LocalTime::midnight()).julian_date(), 2_446_822.5);
+    assert_eq!(LocalDateTime::new(LocalDate::ymd(1987, June, 19).unwrap(), LocalTime::hm(12, 0).unwrap()).julian_date(), 2_446_966.0);
+    assert_eq!(LocalDateTime::new(LocalDate::ymd(1900, January, 1).unwrap(), LocalTime::midnight()).julian_date(), 2_415_020.5);
+    assert_eq!(LocalDateTime::new(LocalDate::ymd(1600, December, 31).unwrap(), LocalTime::midnight()).julian_date(), 2_305_812.5);
+}
+
+#[test]
+fn sputnik() {
+    let launch = LocalDateTime::new(LocalDate::ymd(1957, October, 4).unwrap(), LocalTime::hms(19, 26, 24).unwrap());
+    assert!((launch.julian_date() - 2_436_116.31).abs() < 1e-6);
+    assert_eq!(LocalDateTime::from_julian_date(2_436_116.31), launch);
+}
+
+#[test]
+fn modified() {
+    let noon = LocalDateTime::new(LocalDate::ymd(2000, January, 1).unwrap(), LocalTime::hm(12, 0).unwrap());
+    assert_eq!(noon.modified_julian_date(), 51_544.5);
+
+    let morning = LocalDateTime::new(LocalDate::ymd(1858, November, 17).unwrap(), LocalTime::hm(6, 0).unwrap());
+    assert_eq!(morning.modified_julian_date(), 0.25);
+
+    let evening = LocalDateTime::new(LocalDate::ymd(1858, November, 16).unwrap(), LocalTime::hm(18, 0).unwrap());
+    assert_eq!(LocalDateTime::from_modified_julian_date(-0.25), evening);
+}
+
+#[test]
+fn milliseconds() {
+    let local = LocalDateTime::new(LocalDate::ymd(2017, June, 1).unwrap(), LocalTime::hms_ms(3, 4, 5, 678).unwrap());
+    assert_eq!(LocalDateTime::from_julian_date(local.julian_date()), local);
+    assert_eq!(LocalDateTime::from_modified_julian_date(local.modified_julian_date()), local);
+}
+
+#[test]
+fn instants() {
+    let instant = Instant::at_ms(1_500_000_000, 250);
+    assert_eq!(Instant::from_julian_date(instant.julian_date()), instant);
+    assert_eq!(Instant::from_modified_julian_date(instant.modified_julian_date()), instant);
+    assert_eq!(Instant::from_modified_julian_date(40_588.5), Instant::at(86_400 + 43_200));
+}
@@ -0,0 +1,21 @@
+extern crate datetime;
+use datetime::{MonotonicTime, Stopwatch, Duration};
+
+
+#[test]
+fn never_goes_backwards() {
+    let first = MonotonicTime::now();
+    let second = MonotonicTime::now();
+    assert!(second >= first);
+    assert!(second - first >= Duration::zero());
+}
+
+#[test]
+fn stopwatch_laps_add_up() {
+    let mut watch = Stopwatch::start();
+    let one = watch.lap();
+    let two = watch.lap();
+
+    assert_eq!(watch.laps(), &[ one, two ]);
+    assert!(watch.elapsed() >= one + two);
+}
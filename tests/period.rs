@@ -0,0 +1,83 @@
+extern crate datetime;
+use datetime::{LocalDate, LocalDateTime, LocalTime, Month, Period, MonthOverflow};
+
+
+mod dates {
+    use super::*;
+
+    #[test]
+    fn add_months_clamps() {
+        let date = LocalDate::ymd(2015, Month::January, 31).unwrap();
+        assert_eq!(date + Period::months(1), LocalDate::ymd(2015, Month::February, 28).unwrap());
+    }
+
+    #[test]
+    fn add_months_rolls_over() {
+        let date = LocalDate::ymd(2015, Month::January, 31).unwrap();
+        assert_eq!(date.add_period(Period::months(1), MonthOverflow::RollOver), LocalDate::ymd(2015, Month::March, 3));
+    }
+
+    #[test]
+    fn add_months_rejects() {
+        let date = LocalDate::ymd(2015, Month::January, 31).unwrap();
+        assert!(date.add_period(Period::months(1), MonthOverflow::Reject).is_err());
+    }
+
+    #[test]
+    fn add_across_years() {
+        let date = LocalDate::ymd(2015, Month::November, 30).unwrap();
+        assert_eq!(date + Period::ymd(1, 3, 2), LocalDate::ymd(2017, Month::March, 2).unwrap());
+    }
+
+    #[test]
+    fn subtract_weeks() {
+        let date = LocalDate::ymd(2016, Month::March, 3).unwrap();
+        assert_eq!(date - Period::weeks(1), LocalDate::ymd(2016, Month::February, 25).unwrap());
+    }
+}
+
+
+mod datetimes {
+    use super::*;
+
+    #[test]
+    fn add_with_time() {
+        let then = LocalDateTime::new(LocalDate::ymd(2016, Month::December, 31).unwrap(),
+                                      LocalTime::hms(23, 0, 0).unwrap());
+        let period = Period { months: 2, hours: 2, ..Period::zero() };
+
+        assert_eq!(then + period, LocalDateTime::new(LocalDate::ymd(2017, Month::March, 1).unwrap(),
+                                                     LocalTime::hms(1, 0, 0).unwrap()));
+    }
+}
+
+
+mod between {
+    use super::*;
+
+    #[test]
+    fn same_date() {
+        let date = LocalDate::ymd(2016, Month::June, 1).unwrap();
+        assert!(Period::between(date, date).is_zero());
+    }
+
+    #[test]
+    fn round_trip() {
+        let start = LocalDate::ymd(1999, Month::August, 31).unwrap();
+        let end = LocalDate::ymd(2016, Month::February, 29).unwrap();
+        assert_eq!(start + Period::between(start, end), end);
+    }
+
+    #[test]
+    fn reversed() {
+        let start = LocalDate::ymd(2017, Month::March, 10).unwrap();
+        let end = LocalDate::ymd(2017, Month::January, 20).unwrap();
+        assert_eq!(Period::between(start, end), Period::ymd(0, -1, -21));
+    }
+}
+
+
+#[test]
+fn negation() {
+    assert_eq!(-Period::ymd(1, -2, 3), Period::ymd(-1, 2, -3));
+}
@@ -0,0 +1,147 @@
+extern crate datetime;
+extern crate locale;
+pub use datetime::{Duration, Instant};
+pub use datetime::relative::{RelativeFormat, Language, Thresholds};
+
+
+fn english(offset: i64) -> String {
+    RelativeFormat::default().format_duration(&Duration::of(offset), &locale::Time::english())
+}
+
+fn in_language(language: Language, offset: i64) -> String {
+    let format = RelativeFormat { language: Some(language), ..RelativeFormat::default() };
+    format.format_duration(&Duration::of(offset), &locale::Time::english())
+}
+
+
+mod english {
+    use super::*;
+
+    #[test]
+    fn now() {
+        assert_eq!(english(0), "now");
+    }
+
+    #[test]
+    fn seconds_ago() {
+        assert_eq!(english(-30), "30 seconds ago");
+    }
+
+    #[test]
+    fn one_minute() {
+        assert_eq!(english(60), "in 1 minute");
+    }
+
+    #[test]
+    fn hours() {
+        assert_eq!(english(-3 * 3600), "3 hours ago");
+    }
+
+    #[test]
+    fn yesterday() {
+        assert_eq!(english(-86400), "yesterday");
+    }
+
+    #[test]
+    fn days() {
+        assert_eq!(english(2 * 86400), "in 2 days");
+    }
+
+    #[test]
+    fn weeks() {
+        assert_eq!(english(3 * 7 * 86400), "in 3 weeks");
+    }
+
+    #[test]
+    fn months() {
+        assert_eq!(english(-90 * 86400), "3 months ago");
+    }
+
+    #[test]
+    fn years() {
+        assert_eq!(english(5 * 365 * 86400), "in 5 years");
+    }
+
+    #[test]
+    fn between_instants() {
+        let now = Instant::at_ms(1_000_000, 200);
+        let then = Instant::at_ms(1_000_000 - 120, 500);
+        let format = RelativeFormat::default();
+        assert_eq!(format.format(then, now, &locale::Time::english()), "2 minutes ago");
+    }
+
+    #[test]
+    fn not_idiomatic() {
+        let format = RelativeFormat { idiomatic: false, ..RelativeFormat::default() };
+        assert_eq!(format.format_duration(&Duration::of(86400), &locale::Time::english()), "in 1 day");
+    }
+}
+
+
+mod thresholds {
+    use super::*;
+
+    #[test]
+    fn custom_thresholds() {
+        let thresholds = Thresholds { hours: 48, ..Thresholds::default() };
+        let format = RelativeFormat { thresholds, ..RelativeFormat::default() };
+        assert_eq!(format.format_duration(&Duration::of(-30 * 3600), &locale::Time::english()), "30 hours ago");
+    }
+
+    #[test]
+    fn wider_now() {
+        let thresholds = Thresholds { now: 10, ..Thresholds::default() };
+        let format = RelativeFormat { thresholds, ..RelativeFormat::default() };
+        assert_eq!(format.format_duration(&Duration::of(9), &locale::Time::english()), "now");
+    }
+}
+
+
+mod languages {
+    use super::*;
+
+    #[test]
+    fn detected_from_locale() {
+        assert_eq!(Language::from_locale(&locale::Time::english()), Some(Language::English));
+    }
+
+    #[test]
+    fn german() {
+        assert_eq!(in_language(Language::German, -2 * 86400), "vor 2 Tagen");
+    }
+
+    #[test]
+    fn french() {
+        assert_eq!(in_language(Language::French, 86400), "demain");
+    }
+
+    #[test]
+    fn spanish() {
+        assert_eq!(in_language(Language::Spanish, -3600), "hace 1 hora");
+    }
+
+    #[test]
+    fn russian_few() {
+        assert_eq!(in_language(Language::Russian, -3 * 60), "3 минуты назад");
+    }
+
+    #[test]
+    fn russian_many() {
+        assert_eq!(in_language(Language::Russian, 5 * 3600), "через 5 часов");
+    }
+
+    #[test]
+    fn russian_one() {
+        assert_eq!(in_language(Language::Russian, -21), "21 секунду назад");
+    }
+
+    #[test]
+    fn polish() {
+        assert_eq!(in_language(Language::Polish, 2 * 7 * 86400), "za 2 tygodnie");
+    }
+
+    #[test]
+    fn japanese() {
+        assert_eq!(in_language(Language::Japanese, -10 * 60), "10 分前");
+    }
+}
@@ -0,0 +1,642 @@
+extern crate datetime;
+use datetime::{LocalDate, LocalDateTime, LocalTime, TimePiece};
+use datetime::rrule::{Recurrence, RecurrenceRule, RecurrenceError, Frequency};
+use datetime::Month::*;
+use datetime::zone::{StaticTimeZone, FixedTimespanSet, FixedTimespan, TimeZoneSource, TimeZone};
+use std::borrow::Cow;
+
+
+// The examples from section 3.8.5.3 of RFC 5545.
+
+#[test]
+fn daily_for_ten_occurrences() {
+    let recurrence: Recurrence = "DTSTART:19970902T090000\nRRULE:FREQ=DAILY;COUNT=10".parse().unwrap();
+    let dates: Vec<LocalDate> = recurrence.occurrences().map(|o| o.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(1997, September, 2).unwrap(), LocalDate::ymd(1997, September, 3).unwrap(),
+        LocalDate::ymd(1997, September, 4).unwrap(), LocalDate::ymd(1997, September, 5).unwrap(),
+        LocalDate::ymd(1997, September, 6).unwrap(), LocalDate::ymd(1997, September, 7).unwrap(),
+        LocalDate::ymd(1997, September, 8).unwrap(), LocalDate::ymd(1997, September, 9).unwrap(),
+        LocalDate::ymd(1997, September, 10).unwrap(), LocalDate::ymd(1997, September, 11).unwrap(),
+    ]);
+}
+
+#[test]
+fn daily_until_christmas_eve() {
+    let recurrence: Recurrence = "DTSTART:19970902T090000\nRRULE:FREQ=DAILY;UNTIL=19971224T000000Z".parse().unwrap();
+    let dates: Vec<LocalDate> = recurrence.occurrences().map(|o| o.date()).collect();
+    assert_eq!(dates.len(), 113);
+    assert_eq!(dates.last(), Some(&LocalDate::ymd(1997, December, 23).unwrap()));
+}
+
+#[test]
+fn every_other_day() {
+    let recurrence: Recurrence = "DTSTART:19970902T090000\nRRULE:FREQ=DAILY;INTERVAL=2".parse().unwrap();
+    let dates: Vec<LocalDate> = recurrence.occurrences().take(4).map(|o| o.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(1997, September, 2).unwrap(), LocalDate::ymd(1997, September, 4).unwrap(),
+        LocalDate::ymd(1997, September, 6).unwrap(), LocalDate::ymd(1997, September, 8).unwrap(),
+    ]);
+}
+
+#[test]
+fn every_ten_days() {
+    let recurrence: Recurrence = "DTSTART:19970902T090000\nRRULE:FREQ=DAILY;INTERVAL=10;COUNT=5".parse().unwrap();
+    let dates: Vec<LocalDate> = recurrence.occurrences().map(|o| o.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(1997, September, 2).unwrap(), LocalDate::ymd(1997, September, 12).unwrap(),
+        LocalDate::ymd(1997, September, 22).unwrap(), LocalDate::ymd(1997, October, 2).unwrap(),
+        LocalDate::ymd(1997, October, 12).unwrap(),
+    ]);
+}
+
+#[test]
+fn every_day_in_january_yearly() {
+    let input = "DTSTART:19980101T090000\nRRULE:FREQ=YEARLY;UNTIL=20000131T140000Z;BYMONTH=1;BYDAY=SU,MO,TU,WE,TH,FR,SA";
+    let recurrence: Recurrence = input.parse().unwrap();
+    let dates: Vec<LocalDate> = recurrence.occurrences().map(|o| o.date()).collect();
+    assert_eq!(dates.len(), 93);
+    assert_eq!(dates[31], LocalDate::ymd(1999, January, 1).unwrap());
+}
+
+#[test]
+fn every_day_in_january_daily() {
+    let input = "DTSTART:19980101T090000\nRRULE:FREQ=DAILY;UNTIL=20000131T140000Z;BYMONTH=1";
+    let recurrence: Recurrence = input.parse().unwrap();
+    let dates: Vec<LocalDate> = recurrence.occurrences().map(|o| o.date()).collect();
+    assert_eq!(dates.len(), 93);
+    assert_eq!(dates[92], LocalDate::ymd(2000, January, 31).unwrap());
+}
+
+#[test]
+fn weekly_for_ten_occurrences() {
+    let recurrence: Recurrence = "DTSTART:19970902T090000\nRRULE:FREQ=WEEKLY;COUNT=10".parse().unwrap();
+    let dates: Vec<LocalDate> = recurrence.occurrences().map(|o| o.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(1997, September, 2).unwrap(), LocalDate::ymd(1997, September, 9).unwrap(),
+        LocalDate::ymd(1997, September, 16).unwrap(), LocalDate::ymd(1997, September, 23).unwrap(),
+        LocalDate::ymd(1997, September, 30).unwrap(), LocalDate::ymd(1997, October, 7).unwrap(),
+        LocalDate::ymd(1997, October, 14).unwrap(), LocalDate::ymd(1997, October, 21).unwrap(),
+        LocalDate::ymd(1997, October, 28).unwrap(), LocalDate::ymd(1997, November, 4).unwrap(),
+    ]);
+}
+
+#[test]
+fn every_other_week() {
+    let recurrence: Recurrence = "DTSTART:19970902T090000\nRRULE:FREQ=WEEKLY;INTERVAL=2;WKST=SU".parse().unwrap();
+    let dates: Vec<LocalDate> = recurrence.occurrences().take(5).map(|o| o.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(1997, September, 2).unwrap(), LocalDate::ymd(1997, September, 16).unwrap(),
+        LocalDate::ymd(1997, September, 30).unwrap(), LocalDate::ymd(1997, October, 14).unwrap(),
+        LocalDate::ymd(1997, October, 28).unwrap(),
+    ]);
+}
+
+#[test]
+fn weekly_on_tuesday_and_thursday() {
+    let expected = vec![
+        LocalDate::ymd(1997, September, 2).unwrap(), LocalDate::ymd(1997, September, 4).unwrap(),
+        LocalDate::ymd(1997, September, 9).unwrap(), LocalDate::ymd(1997, September, 11).unwrap(),
+        LocalDate::ymd(1997, September, 16).unwrap(), LocalDate::ymd(1997, September, 18).unwrap(),
+        LocalDate::ymd(1997, September, 23).unwrap(), LocalDate::ymd(1997, September, 25).unwrap(),
+        LocalDate::ymd(1997, September, 30).unwrap(), LocalDate::ymd(1997, October, 2).unwrap(),
+    ];
+
+    let until: Recurrence = "DTSTART:19970902T090000\nRRULE:FREQ=WEEKLY;UNTIL=19971007T000000Z;WKST=SU;BYDAY=TU,TH".parse().unwrap();
+    assert_eq!(until.occurrences().map(|o| o.date()).collect::<Vec<_>>(), expected);
+
+    let count: Recurrence = "DTSTART:19970902T090000\nRRULE:FREQ=WEEKLY;COUNT=10;WKST=SU;BYDAY=TU,TH".parse().unwrap();
+    assert_eq!(count.occurrences().map(|o| o.date()).collect::<Vec<_>>(), expected);
+}
+
+#[test]
+fn every_other_week_on_monday_wednesday_and_friday() {
+    let input = "DTSTART:19970901T090000\nRRULE:FREQ=WEEKLY;INTERVAL=2;UNTIL=19971224T000000Z;WKST=SU;BYDAY=MO,WE,FR";
+    let recurrence: Recurrence = input.parse().unwrap();
+    let dates: Vec<LocalDate> = recurrence.occurrences().map(|o| o.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(1997, September, 1).unwrap(), LocalDate::ymd(1997, September, 3).unwrap(),
+        LocalDate::ymd(1997, September, 5).unwrap(), LocalDate::ymd(1997, September, 15).unwrap(),
+        LocalDate::ymd(1997, September, 17).unwrap(), LocalDate::ymd(1997, September, 19).unwrap(),
+        LocalDate::ymd(1997, September, 29).unwrap(), LocalDate::ymd(1997, October, 1).unwrap(),
+        LocalDate::ymd(1997, October, 3).unwrap(), LocalDate::ymd(1997, October, 13).unwrap(),
+        LocalDate::ymd(1997, October, 15).unwrap(), LocalDate::ymd(1997, October, 17).unwrap(),
+        LocalDate::ymd(1997, October, 27).unwrap(), LocalDate::ymd(1997, October, 29).unwrap(),
+        LocalDate::ymd(1997, October, 31).unwrap(), LocalDate::ymd(1997, November, 10).unwrap(),
+        LocalDate::ymd(1997, November, 12).unwrap(), LocalDate::ymd(1997, November, 14).unwrap(),
+        LocalDate::ymd(1997, November, 24).unwrap(), LocalDate::ymd(1997, November, 26).unwrap(),
+        LocalDate::ymd(1997, November, 28).unwrap(), LocalDate::ymd(1997, December, 8).unwrap(),
+        LocalDate::ymd(1997, December, 10).unwrap(), LocalDate::ymd(1997, December, 12).unwrap(),
+        LocalDate::ymd(1997, December, 22).unwrap(),
+    ]);
+}
+
+#[test]
+fn every_other_week_on_tuesday_and_thursday() {
+    let input = "DTSTART:19970902T090000\nRRULE:FREQ=WEEKLY;INTERVAL=2;COUNT=8;WKST=SU;BYDAY=TU,TH";
+    let recurrence: Recurrence = input.parse().unwrap();
+    let dates: Vec<LocalDate> = recurrence.occurrences().map(|o| o.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(1997, September, 2).unwrap(), LocalDate::ymd(1997, September, 4).unwrap(),
+        LocalDate::ymd(1997, September, 16).unwrap(), LocalDate::ymd(1997, September, 18).unwrap(),
+        LocalDate::ymd(1997, September, 30).unwrap(), LocalDate::ymd(1997, October, 2).unwrap(),
+        LocalDate::ymd(1997, October, 14).unwrap(), LocalDate::ymd(1997, October, 16).unwrap(),
+    ]);
+}
+
+#[test]
+fn first_friday_of_the_month() {
+    let recurrence: Recurrence = "DTSTART:19970905T090000\nRRULE:FREQ=MONTHLY;COUNT=10;BYDAY=1FR".parse().unwrap();
+    let dates: Vec<LocalDate> = recurrence.occurrences().map(|o| o.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(1997, September, 5).unwrap(), LocalDate::ymd(1997, October, 3).unwrap(),
+        LocalDate::ymd(1997, November, 7).unwrap(), LocalDate::ymd(1997, December, 5).unwrap(),
+        LocalDate::ymd(1998, January, 2).unwrap(), LocalDate::ymd(1998, February, 6).unwrap(),
+        LocalDate::ymd(1998, March, 6).unwrap(), LocalDate::ymd(1998, April, 3).unwrap(),
+        LocalDate::ymd(1998, May, 1).unwrap(), LocalDate::ymd(1998, June, 5).unwrap(),
+    ]);
+}
+
+#[test]
+fn first_and_last_sunday_every_other_month() {
+    let input = "DTSTART:19970907T090000\nRRULE:FREQ=MONTHLY;INTERVAL=2;COUNT=10;BYDAY=1SU,-1SU";
+    let recurrence: Recurrence = input.parse().unwrap();
+    let dates: Vec<LocalDate> = recurrence.occurrences().map(|o| o.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(1997, September, 7).unwrap(), LocalDate::ymd(1997, September, 28).unwrap(),
+        LocalDate::ymd(1997, November, 2).unwrap(), LocalDate::ymd(1997, November, 30).unwrap(),
+        LocalDate::ymd(1998, January, 4).unwrap(), LocalDate::ymd(1998, January, 25).unwrap(),
+        LocalDate::ymd(1998, March, 1).unwrap(), LocalDate::ymd(1998, March, 29).unwrap(),
+        LocalDate::ymd(1998, May, 3).unwrap(), LocalDate::ymd(1998, May, 31).unwrap(),
+    ]);
+}
+
+#[test]
+fn second_to_last_monday() {
+    let recurrence: Recurrence = "DTSTART:19970922T090000\nRRULE:FREQ=MONTHLY;COUNT=6;BYDAY=-2MO".parse().unwrap();
+    let dates: Vec<LocalDate> = recurrence.occurrences().map(|o| o.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(1997, September, 22).unwrap(), LocalDate::ymd(1997, October, 20).unwrap(),
+        LocalDate::ymd(1997, November, 17).unwrap(), LocalDate::ymd(1997, December, 22).unwrap(),
+        LocalDate::ymd(1998, January, 19).unwrap(), LocalDate::ymd(1998, February, 16).unwrap(),
+    ]);
+}
+
+#[test]
+fn third_to_last_day() {
+    let recurrence: Recurrence = "DTSTART:19970928T090000\nRRULE:FREQ=MONTHLY;BYMONTHDAY=-3".parse().unwrap();
+    let dates: Vec<LocalDate> = recurrence.occurrences().take(6).map(|o| o.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(1997, September, 28).unwrap(), LocalDate::ymd(1997, October, 29).unwrap(),
+        LocalDate::ymd(1997, November, 28).unwrap(), LocalDate::ymd(1997, December, 29).unwrap(),
+        LocalDate::ymd(1998, January, 29).unwrap(), LocalDate::ymd(1998, February, 26).unwrap(),
+    ]);
+}
+
+#[test]
+fn second_and_fifteenth() {
+    let recurrence: Recurrence = "DTSTART:19970902T090000\nRRULE:FREQ=MONTHLY;COUNT=10;BYMONTHDAY=2,15".parse().unwrap();
+    let dates: Vec<LocalDate> = recurrence.occurrences().map(|o| o.date()).collect();
+    assert_eq!(dates.len(), 10);
+    assert_eq!(dates[8..].to_vec(), vec![
+        LocalDate::ymd(1998, January, 2).unwrap(), LocalDate::ymd(1998, January, 15).unwrap(),
+    ]);
+}
+
+#[test]
+fn first_and_last_day() {
+    let recurrence: Recurrence = "DTSTART:19970930T090000\nRRULE:FREQ=MONTHLY;COUNT=10;BYMONTHDAY=1,-1".parse().unwrap();
+    let dates: Vec<LocalDate> = recurrence.occurrences().map(|o| o.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(1997, September, 30).unwrap(), LocalDate::ymd(1997, October, 1).unwrap(),
+        LocalDate::ymd(1997, October, 31).unwrap(), LocalDate::ymd(1997, November, 1).unwrap(),
+        LocalDate::ymd(1997, November, 30).unwrap(), LocalDate::ymd(1997, December, 1).unwrap(),
+        LocalDate::ymd(1997, December, 31).unwrap(), LocalDate::ymd(1998, January, 1).unwrap(),
+        LocalDate::ymd(1998, January, 31).unwrap(), LocalDate::ymd(1998, February, 1).unwrap(),
+    ]);
+}
+
+#[test]
+fn every_eighteen_months() {
+    let input = "DTSTART:19970910T090000\nRRULE:FREQ=MONTHLY;INTERVAL=18;COUNT=10;BYMONTHDAY=10,11,12,13,14,15";
+    let recurrence: Recurrence = input.parse().unwrap();
+    let dates: Vec<LocalDate> = recurrence.occurrences().map(|o| o.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(1997, September, 10).unwrap(), LocalDate::ymd(1997, September, 11).unwrap(),
+        LocalDate::ymd(1997, September, 12).unwrap(), LocalDate::ymd(1997, September, 13).unwrap(),
+        LocalDate::ymd(1997, September, 14).unwrap(), LocalDate::ymd(1997, September, 15).unwrap(),
+        LocalDate::ymd(1999, March, 10).unwrap(), LocalDate::ymd(1999, March, 11).unwrap(),
+        LocalDate::ymd(1999, March, 12).unwrap(), LocalDate::ymd(1999, March, 13).unwrap(),
+    ]);
+}
+
+#[test]
+fn every_tuesday_every_other_month() {
+    let recurrence: Recurrence = "DTSTART:19970902T090000\nRRULE:FREQ=MONTHLY;INTERVAL=2;BYDAY=TU".parse().unwrap();
+    let dates: Vec<LocalDate> = recurrence.occurrences().take(13).map(|o| o.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(1997, September, 2).unwrap(), LocalDate::ymd(1997, September, 9).unwrap(),
+        LocalDate::ymd(1997, September, 16).unwrap(), LocalDate::ymd(1997, September, 23).unwrap(),
+        LocalDate::ymd(1997, September, 30).unwrap(), LocalDate::ymd(1997, November, 4).unwrap(),
+        LocalDate::ymd(1997, November, 11).unwrap(), LocalDate::ymd(1997, November, 18).unwrap(),
+        LocalDate::ymd(1997, November, 25).unwrap(), LocalDate::ymd(1998, January, 6).unwrap(),
+        LocalDate::ymd(1998, January, 13).unwrap(), LocalDate::ymd(1998, January, 20).unwrap(),
+        LocalDate::ymd(1998, January, 27).unwrap(),
+    ]);
+}
+
+#[test]
+fn june_and_july() {
+    let recurrence: Recurrence = "DTSTART:19970610T090000\nRRULE:FREQ=YEARLY;COUNT=10;BYMONTH=6,7".parse().unwrap();
+    let dates: Vec<LocalDate> = recurrence.occurrences().map(|o| o.date()).collect();
+    assert_eq!(dates.len(), 10);
+    assert_eq!(dates[9], LocalDate::ymd(2001, July, 10).unwrap());
+}
+
+#[test]
+fn first_quarter_every_other_year() {
+    let input = "DTSTART:19970310T090000\nRRULE:FREQ=YEARLY;INTERVAL=2;COUNT=10;BYMONTH=1,2,3";
+    let recurrence: Recurrence = input.parse().unwrap();
+    let dates: Vec<LocalDate> = recurrence.occurrences().map(|o| o.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(1997, March, 10).unwrap(), LocalDate::ymd(1999, January, 10).unwrap(),
+        LocalDate::ymd(1999, February, 10).unwrap(), LocalDate::ymd(1999, March, 10).unwrap(),
+        LocalDate::ymd(2001, January, 10).unwrap(), LocalDate::ymd(2001, February, 10).unwrap(),
+        LocalDate::ymd(2001, March, 10).unwrap(), LocalDate::ymd(2003, January, 10).unwrap(),
+        LocalDate::ymd(2003, February, 10).unwrap(), LocalDate::ymd(2003, March, 10).unwrap(),
+    ]);
+}
+
+#[test]
+fn year_days_every_third_year() {
+    let input = "DTSTART:19970101T090000\nRRULE:FREQ=YEARLY;INTERVAL=3;COUNT=10;BYYEARDAY=1,100,200";
+    let recurrence: Recurrence = input.parse().unwrap();
+    let dates: Vec<LocalDate> = recurrence.occurrences().map(|o| o.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(1997, January, 1).unwrap(), LocalDate::ymd(1997, April, 10).unwrap(),
+        LocalDate::ymd(1997, July, 19).unwrap(), LocalDate::ymd(2000, January, 1).unwrap(),
+        LocalDate::ymd(2000, April, 9).unwrap(), LocalDate::ymd(2000, July, 18).unwrap(),
+        LocalDate::ymd(2003, January, 1).unwrap(), LocalDate::ymd(2003, April, 10).unwrap(),
+        LocalDate::ymd(2003, July, 19).unwrap(), LocalDate::ymd(2006, January, 1).unwrap(),
+    ]);
+}
+
+#[test]
+fn twentieth_monday_of_the_year() {
+    let recurrence: Recurrence = "DTSTART:19970519T090000\nRRULE:FREQ=YEARLY;BYDAY=20MO".parse().unwrap();
+    let dates: Vec<LocalDate> = recurrence.occurrences().take(3).map(|o| o.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(1997, May, 19).unwrap(), LocalDate::ymd(1998, May, 18).unwrap(),
+        LocalDate::ymd(1999, May, 17).unwrap(),
+    ]);
+}
+
+#[test]
+fn monday_of_week_twenty() {
+    let recurrence: Recurrence = "DTSTART:19970512T090000\nRRULE:FREQ=YEARLY;BYWEEKNO=20;BYDAY=MO".parse().unwrap();
+    let dates: Vec<LocalDate> = recurrence.occurrences().take(3).map(|o| o.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(1997, May, 12).unwrap(), LocalDate::ymd(1998, May, 11).unwrap(),
+        LocalDate::ymd(1999, May, 17).unwrap(),
+    ]);
+}
+
+#[test]
+fn every_thursday_in_march() {
+    let recurrence: Recurrence = "DTSTART:19970313T090000\nRRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=TH".parse().unwrap();
+    let dates: Vec<LocalDate> = recurrence.occurrences().take(11).map(|o| o.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(1997, March, 13).unwrap(), LocalDate::ymd(1997, March, 20).unwrap(),
+        LocalDate::ymd(1997, March, 27).unwrap(), LocalDate::ymd(1998, March, 5).unwrap(),
+        LocalDate::ymd(1998, March, 12).unwrap(), LocalDate::ymd(1998, March, 19).unwrap(),
+        LocalDate::ymd(1998, March, 26).unwrap(), LocalDate::ymd(1999, March, 4).unwrap(),
+        LocalDate::ymd(1999, March, 11).unwrap(), LocalDate::ymd(1999, March, 18).unwrap(),
+        LocalDate::ymd(1999, March, 25).unwrap(),
+    ]);
+}
+
+#[test]
+fn every_thursday_in_summer() {
+    let recurrence: Recurrence = "DTSTART:19970605T090000\nRRULE:FREQ=YEARLY;BYDAY=TH;BYMONTH=6,7,8".parse().unwrap();
+    let dates: Vec<LocalDate> = recurrence.occurrences().take(14).map(|o| o.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(1997, June, 5).unwrap(), LocalDate::ymd(1997, June, 12).unwrap(),
+        LocalDate::ymd(1997, June, 19).unwrap(), LocalDate::ymd(1997, June, 26).unwrap(),
+        LocalDate::ymd(1997, July, 3).unwrap(), LocalDate::ymd(1997, July, 10).unwrap(),
+        LocalDate::ymd(1997, July, 17).unwrap(), LocalDate::ymd(1997, July, 24).unwrap(),
+        LocalDate::ymd(1997, July, 31).unwrap(), LocalDate::ymd(1997, August, 7).unwrap(),
+        LocalDate::ymd(1997, August, 14).unwrap(), LocalDate::ymd(1997, August, 21).unwrap(),
+        LocalDate::ymd(1997, August, 28).unwrap(), LocalDate::ymd(1998, June, 4).unwrap(),
+    ]);
+}
+
+#[test]
+fn friday_the_thirteenth() {
+    let input = "DTSTART:19970902T090000\nEXDATE:19970902T090000\nRRULE:FREQ=MONTHLY;BYDAY=FR;BYMONTHDAY=13";
+    let recurrence: Recurrence = input.parse().unwrap();
+    let dates: Vec<LocalDate> = recurrence.occurrences().take(5).map(|o| o.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(1998, February, 13).unwrap(), LocalDate::ymd(1998, March, 13).unwrap(),
+        LocalDate::ymd(1998, November, 13).unwrap(), LocalDate::ymd(1999, August, 13).unwrap(),
+        LocalDate::ymd(2000, October, 13).unwrap(),
+    ]);
+}
+
+#[test]
+fn saturday_after_first_sunday() {
+    let input = "DTSTART:19970913T090000\nRRULE:FREQ=MONTHLY;BYDAY=SA;BYMONTHDAY=7,8,9,10,11,12,13";
+    let recurrence: Recurrence = input.parse().unwrap();
+    let dates: Vec<LocalDate> = recurrence.occurrences().take(10).map(|o| o.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(1997, September, 13).unwrap(), LocalDate::ymd(1997, October, 11).unwrap(),
+        LocalDate::ymd(1997, November, 8).unwrap(), LocalDate::ymd(1997, December, 13).unwrap(),
+        LocalDate::ymd(1998, January, 10).unwrap(), LocalDate::ymd(1998, February, 7).unwrap(),
+        LocalDate::ymd(1998, March, 7).unwrap(), LocalDate::ymd(1998, April, 11).unwrap(),
+        LocalDate::ymd(1998, May, 9).unwrap(), LocalDate::ymd(1998, June, 13).unwrap(),
+    ]);
+}
+
+#[test]
+fn election_day() {
+    let input = "DTSTART:19961105T090000\nRRULE:FREQ=YEARLY;INTERVAL=4;BYMONTH=11;BYDAY=TU;BYMONTHDAY=2,3,4,5,6,7,8";
+    let recurrence: Recurrence = input.parse().unwrap();
+    let dates: Vec<LocalDate> = recurrence.occurrences().take(3).map(|o| o.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(1996, November, 5).unwrap(), LocalDate::ymd(2000, November, 7).unwrap(),
+        LocalDate::ymd(2004, November, 2).unwrap(),
+    ]);
+}
+
+#[test]
+fn third_tuesday_wednesday_or_thursday() {
+    let input = "DTSTART:19970904T090000\nRRULE:FREQ=MONTHLY;COUNT=3;BYDAY=TU,WE,TH;BYSETPOS=3";
+    let recurrence: Recurrence = input.parse().unwrap();
+    let dates: Vec<LocalDate> = recurrence.occurrences().map(|o| o.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(1997, September, 4).unwrap(), LocalDate::ymd(1997, October, 7).unwrap(),
+        LocalDate::ymd(1997, November, 6).unwrap(),
+    ]);
+}
+
+#[test]
+fn second_to_last_weekday() {
+    let input = "DTSTART:19970929T090000\nRRULE:FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-2";
+    let recurrence: Recurrence = input.parse().unwrap();
+    let dates: Vec<LocalDate> = recurrence.occurrences().take(7).map(|o| o.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(1997, September, 29).unwrap(), LocalDate::ymd(1997, October, 30).unwrap(),
+        LocalDate::ymd(1997, November, 27).unwrap(), LocalDate::ymd(1997, December, 30).unwrap(),
+        LocalDate::ymd(1998, January, 29).unwrap(), LocalDate::ymd(1998, February, 26).unwrap(),
+        LocalDate::ymd(1998, March, 30).unwrap(),
+    ]);
+}
+
+#[test]
+fn every_three_hours() {
+    let recurrence: Recurrence = "DTSTART:19970902T090000\nRRULE:FREQ=HOURLY;INTERVAL=3;UNTIL=19970902T170000".parse().unwrap();
+    let hours: Vec<i8> = recurrence.occurrences().map(|o| o.hour()).collect();
+    assert_eq!(hours, vec![ 9, 12, 15 ]);
+}
+
+#[test]
+fn every_fifteen_minutes() {
+    let recurrence: Recurrence = "DTSTART:19970902T090000\nRRULE:FREQ=MINUTELY;INTERVAL=15;COUNT=6".parse().unwrap();
+    let day = LocalDate::ymd(1997, September, 2).unwrap();
+    assert_eq!(recurrence.occurrences().collect::<Vec<_>>(), vec![
+        LocalDateTime::new(day, LocalTime::hm(9, 0).unwrap()), LocalDateTime::new(day, LocalTime::hm(9, 15).unwrap()),
+        LocalDateTime::new(day, LocalTime::hm(9, 30).unwrap()), LocalDateTime::new(day, LocalTime::hm(9, 45).unwrap()),
+        LocalDateTime::new(day, LocalTime::hm(10, 0).unwrap()), LocalDateTime::new(day, LocalTime::hm(10, 15).unwrap()),
+    ]);
+}
+
+#[test]
+fn every_hour_and_a_half() {
+    let recurrence: Recurrence = "DTSTART:19970902T090000\nRRULE:FREQ=MINUTELY;INTERVAL=90;COUNT=4".parse().unwrap();
+    let day = LocalDate::ymd(1997, September, 2).unwrap();
+    assert_eq!(recurrence.occurrences().collect::<Vec<_>>(), vec![
+        LocalDateTime::new(day, LocalTime::hm(9, 0).unwrap()), LocalDateTime::new(day, LocalTime::hm(10, 30).unwrap()),
+        LocalDateTime::new(day, LocalTime::hm(12, 0).unwrap()), LocalDateTime::new(day, LocalTime::hm(13, 30).unwrap()),
+    ]);
+}
+
+#[test]
+fn every_twenty_minutes_in_office_hours() {
+    let daily: Recurrence = "DTSTART:19970902T090000\nRRULE:FREQ=DAILY;BYHOUR=9,10,11,12,13,14,15,16;BYMINUTE=0,20,40".parse().unwrap();
+    let minutely: Recurrence = "DTSTART:19970902T090000\nRRULE:FREQ=MINUTELY;INTERVAL=20;BYHOUR=9,10,11,12,13,14,15,16".parse().unwrap();
+
+    let expected: Vec<LocalDateTime> = daily.occurrences().take(48).collect();
+    assert_eq!(expected[23], LocalDateTime::new(LocalDate::ymd(1997, September, 2).unwrap(), LocalTime::hm(16, 40).unwrap()));
+    assert_eq!(expected[24], LocalDateTime::new(LocalDate::ymd(1997, September, 3).unwrap(), LocalTime::hm(9, 0).unwrap()));
+    assert_eq!(minutely.occurrences().take(48).collect::<Vec<_>>(), expected);
+}
+
+#[test]
+fn times_that_never_come_up() {
+    let rule: RecurrenceRule = "FREQ=MINUTELY;INTERVAL=2;BYMINUTE=1".parse().unwrap();
+    let start = LocalDateTime::new(LocalDate::ymd(2000, January, 1).unwrap(), LocalTime::midnight());
+    assert_eq!(rule.occurrences(start).next(), None);
+}
+
+#[test]
+fn times_late_in_the_day() {
+    let rule: RecurrenceRule = "FREQ=SECONDLY;BYHOUR=23;BYMINUTE=59;BYSECOND=59;COUNT=3".parse().unwrap();
+    let start = LocalDateTime::new(LocalDate::ymd(2000, January, 1).unwrap(), LocalTime::midnight());
+    let times: Vec<LocalDateTime> = rule.occurrences(start).collect();
+    assert_eq!(times, vec![
+        LocalDateTime::new(LocalDate::ymd(2000, January, 1).unwrap(), LocalTime::hms(23, 59, 59).unwrap()),
+        LocalDateTime::new(LocalDate::ymd(2000, January, 2).unwrap(), LocalTime::hms(23, 59, 59).unwrap()),
+        LocalDateTime::new(LocalDate::ymd(2000, January, 3).unwrap(), LocalTime::hms(23, 59, 59).unwrap()),
+    ]);
+}
+
+#[test]
+fn week_start_monday() {
+    let input = "DTSTART:19970805T090000\nRRULE:FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=MO";
+    let recurrence: Recurrence = input.parse().unwrap();
+    let dates: Vec<LocalDate> = recurrence.occurrences().map(|o| o.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(1997, August, 5).unwrap(), LocalDate::ymd(1997, August, 10).unwrap(),
+        LocalDate::ymd(1997, August, 19).unwrap(), LocalDate::ymd(1997, August, 24).unwrap(),
+    ]);
+}
+
+#[test]
+fn week_start_sunday() {
+    let input = "DTSTART:19970805T090000\nRRULE:FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=SU";
+    let recurrence: Recurrence = input.parse().unwrap();
+    let dates: Vec<LocalDate> = recurrence.occurrences().map(|o| o.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(1997, August, 5).unwrap(), LocalDate::ymd(1997, August, 17).unwrap(),
+        LocalDate::ymd(1997, August, 19).unwrap(), LocalDate::ymd(1997, August, 31).unwrap(),
+    ]);
+}
+
+#[test]
+fn invalid_dates_are_ignored() {
+    let recurrence: Recurrence = "DTSTART:20070115T090000\nRRULE:FREQ=MONTHLY;BYMONTHDAY=15,30;COUNT=5".parse().unwrap();
+    let dates: Vec<LocalDate> = recurrence.occurrences().map(|o| o.date()).collect();
+    assert_eq!(dates, vec![
+        LocalDate::ymd(2007, January, 15).unwrap(), LocalDate::ymd(2007, January, 30).unwrap(),
+        LocalDate::ymd(2007, February, 15).unwrap(), LocalDate::ymd(2007, March, 15).unwrap(),
+        LocalDate::ymd(2007, March, 30).unwrap(),
+    ]);
+}
+
+
+#[test]
+fn extra_dates_are_merged_in_order() {
+    let input = "DTSTART:20170102T090000\nRRULE:FREQ=WEEKLY;COUNT=3\nRDATE:20170104T090000,20170102T090000";
+    let recurrence: Recurrence = input.parse().unwrap();
+    assert_eq!(recurrence.occurrences().map(|o| o.date()).collect::<Vec<_>>(), vec![
+        LocalDate::ymd(2017, January, 2).unwrap(), LocalDate::ymd(2017, January, 4).unwrap(),
+        LocalDate::ymd(2017, January, 9).unwrap(), LocalDate::ymd(2017, January, 16).unwrap(),
+    ]);
+}
+
+#[test]
+fn two_rules() {
+    let input = "DTSTART:20170102T090000\nRRULE:FREQ=WEEKLY;COUNT=2\nRRULE:FREQ=MONTHLY;BYMONTHDAY=3;COUNT=3";
+    let recurrence: Recurrence = input.parse().unwrap();
+    assert_eq!(recurrence.occurrences().map(|o| o.date()).collect::<Vec<_>>(), vec![
+        LocalDate::ymd(2017, January, 2).unwrap(), LocalDate::ymd(2017, January, 3).unwrap(),
+        LocalDate::ymd(2017, January, 9).unwrap(), LocalDate::ymd(2017, February, 3).unwrap(),
+    ]);
+}
+
+#[test]
+fn start_is_the_first_occurrence() {
+    // The 3rd of January 2017 is a Tuesday, so the rule doesn’t produce
+    // it, but it still counts towards the three occurrences.
+    let input = "DTSTART:20170103T090000\nRRULE:FREQ=WEEKLY;BYDAY=MO;COUNT=3";
+    let recurrence: Recurrence = input.parse().unwrap();
+    assert_eq!(recurrence.occurrences().map(|o| o.date()).collect::<Vec<_>>(), vec![
+        LocalDate::ymd(2017, January, 3).unwrap(), LocalDate::ymd(2017, January, 9).unwrap(),
+        LocalDate::ymd(2017, January, 16).unwrap(),
+    ]);
+}
+
+#[test]
+fn no_rules() {
+    let recurrence: Recurrence = "DTSTART;VALUE=DATE:20170102".parse().unwrap();
+    let start = LocalDateTime::new(LocalDate::ymd(2017, January, 2).unwrap(), LocalTime::midnight());
+    assert_eq!(recurrence.occurrences().collect::<Vec<_>>(), vec![ start ]);
+}
+
+#[test]
+fn time_zone_name() {
+    let recurrence: Recurrence = "DTSTART;TZID=America/New_York:19970902T090000".parse().unwrap();
+    assert_eq!(recurrence.time_zone, Some("America/New_York".to_owned()));
+}
+
+
+#[test]
+fn round_trip() {
+    let input = "FREQ=MONTHLY;INTERVAL=2;COUNT=10;BYDAY=1SU,-1SU;WKST=SU";
+    let rule: RecurrenceRule = input.parse().unwrap();
+    assert_eq!(rule.to_string(), input);
+}
+
+#[test]
+fn round_trip_until_in_utc() {
+    let input = "FREQ=DAILY;UNTIL=19971224T000000Z";
+    let rule: RecurrenceRule = input.parse().unwrap();
+    assert!(rule.until_utc);
+    assert_eq!(rule.to_string(), input);
+}
+
+#[test]
+fn prefix() {
+    let rule: RecurrenceRule = "RRULE:FREQ=DAILY".parse().unwrap();
+    assert_eq!(rule, RecurrenceRule::new(Frequency::Daily));
+}
+
+#[test]
+fn missing_frequency() {
+    assert_eq!("COUNT=3".parse::<RecurrenceRule>(), Err(RecurrenceError::MissingFrequency));
+}
+
+#[test]
+fn count_and_until() {
+    assert_eq!("FREQ=DAILY;COUNT=3;UNTIL=20170101".parse::<RecurrenceRule>(), Err(RecurrenceError::CountWithUntil));
+}
+
+#[test]
+fn bad_weekday() {
+    assert!("FREQ=DAILY;BYDAY=XX".parse::<RecurrenceRule>().is_err());
+    assert!("FREQ=DAILY;BYDAY=0MO".parse::<RecurrenceRule>().is_err());
+}
+
+#[test]
+fn out_of_range() {
+    assert!("FREQ=DAILY;BYHOUR=24".parse::<RecurrenceRule>().is_err());
+    assert!("FREQ=DAILY;BYMONTHDAY=0".parse::<RecurrenceRule>().is_err());
+    assert!("FREQ=DAILY;INTERVAL=0".parse::<RecurrenceRule>().is_err());
+}
+
+#[test]
+fn unknown_part() {
+    assert_eq!("FREQ=DAILY;BYFOO=1".parse::<RecurrenceRule>(), Err(RecurrenceError::UnknownPart("BYFOO".to_owned())));
+}
+
+#[test]
+fn missing_start() {
+    assert_eq!("RRULE:FREQ=DAILY".parse::<Recurrence>(), Err(RecurrenceError::MissingStart));
+}
+
+
+// The clocks go forward from 01:00 to 02:00 on the 30th of March 2008,
+// and back from 02:00 to 01:00 on the 26th of October.
+const TEST_ZONESET: &StaticTimeZone<'static> = &StaticTimeZone {
+    name: "Test Zoneset",
+    fixed_timespans: FixedTimespanSet {
+        first: FixedTimespan { offset: 0, is_dst: false, name: Cow::Borrowed("ZONE_A") },
+        rest: &[
+            (1206838800, FixedTimespan { offset: 3600, is_dst: true, name: Cow::Borrowed("ZONE_B") }),
+            (1224982800, FixedTimespan { offset: 0, is_dst: false, name: Cow::Borrowed("ZONE_A") }),
+        ]
+    }
+};
+
+// New York in the winter, five hours behind UTC.
+const EASTERN_STANDARD: &StaticTimeZone<'static> = &StaticTimeZone {
+    name: "Eastern Standard",
+    fixed_timespans: FixedTimespanSet {
+        first: FixedTimespan { offset: -18000, is_dst: false, name: Cow::Borrowed("EST") },
+        rest: &[],
+    }
+};
+
+#[test]
+fn gap_moves_forward() {
+    let zone = TimeZone(TimeZoneSource::Static(TEST_ZONESET));
+    let recurrence: Recurrence = "DTSTART:20080329T013000\nRRULE:FREQ=DAILY;COUNT=3".parse().unwrap();
+    let times: Vec<LocalDateTime> = recurrence.zoned_occurrences(&zone).map(|z| z.local()).collect();
+    assert_eq!(times, vec![
+        LocalDateTime::new(LocalDate::ymd(2008, March, 29).unwrap(), LocalTime::hm(1, 30).unwrap()),
+        LocalDateTime::new(LocalDate::ymd(2008, March, 30).unwrap(), LocalTime::hm(2, 30).unwrap()),
+        LocalDateTime::new(LocalDate::ymd(2008, March, 31).unwrap(), LocalTime::hm(1, 30).unwrap()),
+    ]);
+}
+
+#[test]
+fn overlap_happens_once() {
+    let zone = TimeZone(TimeZoneSource::Static(TEST_ZONESET));
+    let recurrence: Recurrence = "DTSTART:20081025T013000\nRRULE:FREQ=DAILY;COUNT=2".parse().unwrap();
+    let instants: Vec<i64> = recurrence.zoned_occurrences(&zone).map(|z| z.to_instant().seconds()).collect();
+
+    // 01:30 on the 26th happens first at 00:30 UTC.
+    assert_eq!(instants, vec![ 1224894600, 1224981000 ]);
+}
+
+#[test]
+fn until_in_utc() {
+    let zone = TimeZone(TimeZoneSource::Static(EASTERN_STANDARD));
+    let recurrence: Recurrence = "DTSTART;TZID=America/New_York:19971220T090000\nRRULE:FREQ=DAILY;UNTIL=19971224T120000Z".parse().unwrap();
+    let dates: Vec<LocalDate> = recurrence.zoned_occurrences(&zone).map(|z| z.local().date()).collect();
+
+    // 09:00 on the 24th is 14:00 UTC, which is after the end.
+    assert_eq!(dates, vec![
+        LocalDate::ymd(1997, December, 20).unwrap(), LocalDate::ymd(1997, December, 21).unwrap(),
+        LocalDate::ymd(1997, December, 22).unwrap(), LocalDate::ymd(1997, December, 23).unwrap(),
+    ]);
+}
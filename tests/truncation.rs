@@ -0,0 +1,221 @@
+extern crate datetime;
+use datetime::{LocalDate, LocalDateTime, LocalTime, Weekday, Instant, TimeUnit};
+use datetime::Month::*;
+use datetime::zone::{StaticTimeZone, FixedTimespanSet, FixedTimespan, TimeZoneSource, TimeZone, LocalTimes};
+use std::borrow::Cow;
+
+
+#[test]
+fn quarter_hours() {
+    assert_eq!(LocalTime::hms(14, 44, 59).unwrap().truncate_to(TimeUnit::Minutes(15)), LocalTime::hm(14, 30).unwrap());
+    assert_eq!(LocalTime::hm(14, 45).unwrap().truncate_to(TimeUnit::Minutes(15)), LocalTime::hm(14, 45).unwrap());
+}
+
+#[test]
+fn milliseconds() {
+    let precise = LocalTime::hms_ms(9, 0, 1, 789).unwrap();
+    assert_eq!(precise.truncate_to(TimeUnit::Milliseconds(100)), LocalTime::hms_ms(9, 0, 1, 700).unwrap());
+    assert_eq!(precise.round_to(TimeUnit::Seconds(1)), LocalTime::hms(9, 0, 2).unwrap());
+}
+
+#[test]
+fn round_half_up() {
+    assert_eq!(LocalTime::hms(14, 37, 29).unwrap().round_to(TimeUnit::Minutes(15)), LocalTime::hm(14, 30).unwrap());
+    assert_eq!(LocalTime::hms(14, 37, 30).unwrap().round_to(TimeUnit::Minutes(15)), LocalTime::hm(14, 45).unwrap());
+}
+
+#[test]
+fn round_wraps_past_midnight() {
+    assert_eq!(LocalTime::hm(23, 45).unwrap().round_to(TimeUnit::Hours(1)), LocalTime::midnight());
+}
+
+#[test]
+fn days_give_midnight() {
+    assert_eq!(LocalTime::hm(13, 0).unwrap().truncate_to(TimeUnit::Days(1)), LocalTime::midnight());
+    assert_eq!(LocalTime::hm(13, 0).unwrap().truncate_to(TimeUnit::Years(1)), LocalTime::midnight());
+}
+
+#[test]
+#[should_panic]
+fn zero_count() {
+    let _ = LocalTime::hm(13, 0).unwrap().truncate_to(TimeUnit::Minutes(0));
+}
+
+
+#[test]
+fn days() {
+    let day = LocalDate::ymd(2017, August, 14).unwrap();
+    let midnight = LocalDateTime::new(day, LocalTime::midnight());
+    assert_eq!(LocalDateTime::new(day, LocalTime::hm(16, 20).unwrap()).truncate_to(TimeUnit::Days(1)), midnight);
+    assert_eq!(LocalDateTime::new(day, LocalTime::hm(11, 59).unwrap()).round_to(TimeUnit::Days(1)), midnight);
+    assert_eq!(LocalDateTime::new(day, LocalTime::hm(12, 0).unwrap()).round_to(TimeUnit::Days(1)),
+               LocalDateTime::new(LocalDate::ymd(2017, August, 15).unwrap(), LocalTime::midnight()));
+}
+
+#[test]
+fn weeks_start_on_monday() {
+    let sunday = LocalDateTime::new(LocalDate::ymd(2017, August, 13).unwrap(), LocalTime::hm(16, 20).unwrap());
+    assert_eq!(sunday.truncate_to(TimeUnit::Weeks(1)),
+               LocalDateTime::new(LocalDate::ymd(2017, August, 7).unwrap(), LocalTime::midnight()));
+
+    let monday = LocalDateTime::new(LocalDate::ymd(2017, August, 14).unwrap(), LocalTime::midnight());
+    assert_eq!(monday.truncate_to(TimeUnit::Weeks(1)), monday);
+}
+
+#[test]
+fn before_the_epoch() {
+    let then = LocalDateTime::new(LocalDate::ymd(1969, December, 31).unwrap(), LocalTime::hm(23, 59).unwrap());
+    assert_eq!(then.truncate_to(TimeUnit::Weeks(1)),
+               LocalDateTime::new(LocalDate::ymd(1969, December, 29).unwrap(), LocalTime::midnight()));
+    assert_eq!(then.truncate_to(TimeUnit::Days(1)),
+               LocalDateTime::new(LocalDate::ymd(1969, December, 31).unwrap(), LocalTime::midnight()));
+}
+
+#[test]
+fn datetimes_months() {
+    let then = LocalDateTime::new(LocalDate::ymd(2017, August, 14).unwrap(), LocalTime::hm(16, 20).unwrap());
+    let start = LocalDateTime::new(LocalDate::ymd(2017, August, 1).unwrap(), LocalTime::midnight());
+    assert_eq!(then.truncate_to(TimeUnit::Months(1)), start);
+
+    let sixteenth = LocalDateTime::new(LocalDate::ymd(2017, August, 16).unwrap(), LocalTime::midnight());
+    let seventeenth = LocalDateTime::new(LocalDate::ymd(2017, August, 17).unwrap(), LocalTime::midnight());
+    assert_eq!(sixteenth.round_to(TimeUnit::Months(1)), start);
+    assert_eq!(seventeenth.round_to(TimeUnit::Months(1)),
+               LocalDateTime::new(LocalDate::ymd(2017, September, 1).unwrap(), LocalTime::midnight()));
+}
+
+#[test]
+fn datetimes_quarters() {
+    let then = LocalDateTime::new(LocalDate::ymd(2017, December, 31).unwrap(), LocalTime::hm(23, 59).unwrap());
+    assert_eq!(then.truncate_to(TimeUnit::Months(3)),
+               LocalDateTime::new(LocalDate::ymd(2017, October, 1).unwrap(), LocalTime::midnight()));
+
+    let first = LocalDateTime::new(LocalDate::ymd(2017, December, 1).unwrap(), LocalTime::midnight());
+    assert_eq!(first.round_to(TimeUnit::Months(3)),
+               LocalDateTime::new(LocalDate::ymd(2018, January, 1).unwrap(), LocalTime::midnight()));
+}
+
+#[test]
+fn datetimes_years() {
+    let then = LocalDateTime::new(LocalDate::ymd(2017, August, 14).unwrap(), LocalTime::hm(16, 20).unwrap());
+    assert_eq!(then.truncate_to(TimeUnit::Years(1)),
+               LocalDateTime::new(LocalDate::ymd(2017, January, 1).unwrap(), LocalTime::midnight()));
+    assert_eq!(then.round_to(TimeUnit::Years(1)),
+               LocalDateTime::new(LocalDate::ymd(2018, January, 1).unwrap(), LocalTime::midnight()));
+    assert_eq!(then.truncate_to(TimeUnit::Years(10)),
+               LocalDateTime::new(LocalDate::ymd(2010, January, 1).unwrap(), LocalTime::midnight()));
+}
+
+#[test]
+fn short_last_multiple() {
+    // Seven-minute multiples from midnight end with a five-minute one
+    // at 23:55, so 23:58 is nearer the next midnight.
+    let day = LocalDate::ymd(2017, August, 14).unwrap();
+    let then = LocalDateTime::new(day, LocalTime::hm(23, 58).unwrap());
+    assert_eq!(then.truncate_to(TimeUnit::Minutes(7)), LocalDateTime::new(day, LocalTime::hm(23, 55).unwrap()));
+    assert_eq!(then.round_to(TimeUnit::Minutes(7)),
+               LocalDateTime::new(LocalDate::ymd(2017, August, 15).unwrap(), LocalTime::midnight()));
+}
+
+
+#[test]
+fn hours() {
+    let instant = Instant::at(1_500_000_000);
+    assert_eq!(instant.truncate_to(TimeUnit::Hours(1)), Instant::at(1_499_997_600));
+    assert_eq!(instant.round_to(TimeUnit::Hours(1)), Instant::at(1_500_001_200));
+}
+
+#[test]
+fn negative() {
+    assert_eq!(Instant::at_ms(-1, 500).truncate_to(TimeUnit::Seconds(1)), Instant::at(-1));
+    assert_eq!(Instant::at(-1).truncate_to(TimeUnit::Days(1)), Instant::at(-86400));
+}
+
+#[test]
+fn instants_months() {
+    let then = LocalDateTime::new(LocalDate::ymd(2017, March, 3).unwrap(), LocalTime::hm(9, 0).unwrap());
+    let start = LocalDateTime::new(LocalDate::ymd(2017, March, 1).unwrap(), LocalTime::midnight());
+    assert_eq!(then.to_instant().truncate_to(TimeUnit::Months(1)), start.to_instant());
+}
+
+
+#[test]
+fn weeks() {
+    let thursday = LocalDate::ymd(2017, August, 17).unwrap();
+    assert_eq!(thursday.start_of_week(Weekday::Monday), LocalDate::ymd(2017, August, 14).unwrap());
+    assert_eq!(thursday.end_of_week(Weekday::Monday), LocalDate::ymd(2017, August, 20).unwrap());
+    assert_eq!(thursday.start_of_week(Weekday::Saturday), LocalDate::ymd(2017, August, 12).unwrap());
+    assert_eq!(thursday.start_of_week(Weekday::Thursday), thursday);
+}
+
+#[test]
+fn dates_months() {
+    assert_eq!(LocalDate::ymd(2016, February, 10).unwrap().start_of_month(), LocalDate::ymd(2016, February, 1).unwrap());
+    assert_eq!(LocalDate::ymd(2016, February, 10).unwrap().end_of_month(), LocalDate::ymd(2016, February, 29).unwrap());
+}
+
+#[test]
+fn dates_quarters() {
+    assert_eq!(LocalDate::ymd(2017, May, 20).unwrap().start_of_quarter(), LocalDate::ymd(2017, April, 1).unwrap());
+    assert_eq!(LocalDate::ymd(2017, May, 20).unwrap().end_of_quarter(), LocalDate::ymd(2017, June, 30).unwrap());
+    assert_eq!(LocalDate::ymd(2017, November, 2).unwrap().end_of_quarter(), LocalDate::ymd(2017, December, 31).unwrap());
+}
+
+#[test]
+fn dates_years() {
+    assert_eq!(LocalDate::ymd(2017, May, 20).unwrap().start_of_year(), LocalDate::ymd(2017, January, 1).unwrap());
+    assert_eq!(LocalDate::ymd(2017, May, 20).unwrap().end_of_year(), LocalDate::ymd(2017, December, 31).unwrap());
+}
+
+
+// The clocks go forward from midnight to 01:00 on the 30th of March
+// 2008, and back from 02:00 to 01:00 on the 26th of October.
+const TEST_ZONESET: &StaticTimeZone<'static> = &StaticTimeZone {
+    name: "Test Zoneset",
+    fixed_timespans: FixedTimespanSet {
+        first: FixedTimespan { offset: 0, is_dst: false, name: Cow::Borrowed("ZONE_A") },
+        rest: &[
+            (1206835200, FixedTimespan { offset: 3600, is_dst: true, name: Cow::Borrowed("ZONE_B") }),
+            (1224982800, FixedTimespan { offset: 0, is_dst: false, name: Cow::Borrowed("ZONE_A") }),
+        ]
+    }
+};
+
+#[test]
+fn start_of_ordinary_day() {
+    let zone = TimeZone(TimeZoneSource::Static(TEST_ZONESET));
+    let start = zone.start_of_day(LocalDate::ymd(2008, March, 29).unwrap());
+    assert_eq!(start.local(), LocalDateTime::new(LocalDate::ymd(2008, March, 29).unwrap(), LocalTime::midnight()));
+}
+
+#[test]
+fn start_of_day_without_midnight() {
+    let zone = TimeZone(TimeZoneSource::Static(TEST_ZONESET));
+    let start = zone.start_of_day(LocalDate::ymd(2008, March, 30).unwrap());
+    assert_eq!(start.local(), LocalDateTime::new(LocalDate::ymd(2008, March, 30).unwrap(), LocalTime::hm(1, 0).unwrap()));
+    assert_eq!(start.to_instant(), Instant::at(1206835200));
+}
+
+#[test]
+fn truncate_to_missing_midnight() {
+    let zone = TimeZone(TimeZoneSource::Static(TEST_ZONESET));
+    let day = LocalDate::ymd(2008, March, 30).unwrap();
+    let noon = zone.convert_local(LocalDateTime::new(day, LocalTime::hm(12, 0).unwrap())).unwrap_precise();
+    assert_eq!(noon.truncate_to(TimeUnit::Days(1)).local(), LocalDateTime::new(day, LocalTime::hm(1, 0).unwrap()));
+}
+
+#[test]
+fn truncate_in_overlap() {
+    let zone = TimeZone(TimeZoneSource::Static(TEST_ZONESET));
+    let then = LocalDateTime::new(LocalDate::ymd(2008, October, 26).unwrap(), LocalTime::hm(1, 15).unwrap());
+    let (earlier, later) = match zone.convert_local(then) {
+        LocalTimes::Ambiguous { earlier, later } => (earlier, later),
+        _ => panic!("01:15 should be ambiguous"),
+    };
+
+    assert_eq!(earlier.truncate_to(TimeUnit::Hours(1)).to_instant(), Instant::at(1224979200));
+    assert_eq!(later.truncate_to(TimeUnit::Hours(1)).to_instant(), Instant::at(1224982800));
+
+    // Midnight was an hour before the first 01:00.
+    assert_eq!(later.truncate_to(TimeUnit::Days(1)).to_instant(), Instant::at(1224975600));
+}
@@ -0,0 +1,98 @@
+extern crate datetime;
+use datetime::{DatePiece, LocalDate, Month, Weekday, Year};
+
+
+#[test]
+fn succ_wraps() {
+    assert_eq!(Weekday::Saturday.succ(), Weekday::Sunday);
+    assert_eq!(Weekday::Monday.succ(), Weekday::Tuesday);
+}
+
+#[test]
+fn pred_wraps() {
+    assert_eq!(Weekday::Sunday.pred(), Weekday::Saturday);
+    assert_eq!(Weekday::Tuesday.pred(), Weekday::Monday);
+}
+
+#[test]
+fn whole_weeks() {
+    assert_eq!(Weekday::Wednesday.add_days(70), Weekday::Wednesday);
+    assert_eq!(Weekday::Wednesday.add_days(-70), Weekday::Wednesday);
+}
+
+#[test]
+fn differences() {
+    assert_eq!(Weekday::Sunday.days_since(Weekday::Sunday), 0);
+    assert_eq!(Weekday::Sunday.days_since(Weekday::Monday), 6);
+    assert_eq!(Weekday::Sunday.days_until(Weekday::Monday), 1);
+}
+
+
+#[test]
+fn first() {
+    assert_eq!(Year(2017).month(Month::September).nth_weekday(1, Weekday::Monday),
+               Ok(LocalDate::ymd(2017, Month::September, 4).unwrap()));
+}
+
+#[test]
+fn first_on_the_first() {
+    assert_eq!(Year(2017).month(Month::September).nth_weekday(1, Weekday::Friday),
+               Ok(LocalDate::ymd(2017, Month::September, 1).unwrap()));
+}
+
+#[test]
+fn fifth() {
+    assert_eq!(Year(2017).month(Month::September).nth_weekday(5, Weekday::Friday),
+               Ok(LocalDate::ymd(2017, Month::September, 29).unwrap()));
+    assert!(Year(2017).month(Month::September).nth_weekday(5, Weekday::Monday).is_err());
+}
+
+#[test]
+fn last_on_the_last() {
+    assert_eq!(Year(2017).month(Month::September).nth_weekday(-1, Weekday::Saturday),
+               Ok(LocalDate::ymd(2017, Month::September, 30).unwrap()));
+}
+
+#[test]
+fn second_to_last() {
+    assert_eq!(Year(2017).month(Month::September).nth_weekday(-2, Weekday::Monday),
+               Ok(LocalDate::ymd(2017, Month::September, 18).unwrap()));
+}
+
+#[test]
+fn zero() {
+    assert!(Year(2017).month(Month::September).nth_weekday(0, Weekday::Monday).is_err());
+}
+
+#[test]
+fn from_date() {
+    let date = LocalDate::ymd(2017, Month::February, 14).unwrap();
+    assert_eq!(date.nth_weekday(-1, Weekday::Tuesday), Ok(LocalDate::ymd(2017, Month::February, 28).unwrap()));
+}
+
+
+#[test]
+fn next_across_year() {
+    assert_eq!(LocalDate::ymd(2017, Month::December, 29).unwrap().next_weekday(Weekday::Monday),
+               LocalDate::ymd(2018, Month::January, 1).unwrap());
+}
+
+#[test]
+fn previous_same_weekday() {
+    let monday = LocalDate::ymd(2017, Month::July, 3).unwrap();
+    assert_eq!(monday.previous_weekday(Weekday::Monday), LocalDate::ymd(2017, Month::June, 26).unwrap());
+}
+
+#[test]
+fn on_or_before() {
+    let monday = LocalDate::ymd(2017, Month::July, 3).unwrap();
+    assert_eq!(monday.weekday_on_or_before(Weekday::Monday), monday);
+    assert_eq!(monday.weekday_on_or_before(Weekday::Tuesday), LocalDate::ymd(2017, Month::June, 27).unwrap());
+}
+
+#[test]
+fn on_or_after() {
+    let monday = LocalDate::ymd(2017, Month::July, 3).unwrap();
+    assert_eq!(monday.weekday_on_or_after(Weekday::Sunday), LocalDate::ymd(2017, Month::July, 9).unwrap());
+    assert_eq!(monday.weekday_on_or_after(Weekday::Sunday).weekday(), Weekday::Sunday);
+}
@@ -0,0 +1,169 @@
+extern crate datetime;
+use datetime::{LocalDate, LocalDateTime, LocalTime, Instant, Duration};
+use datetime::window::{FixedWindows, DailyWindows, Window};
+use datetime::Month::*;
+use datetime::zone::{StaticTimeZone, FixedTimespanSet, FixedTimespan, TimeZoneSource, TimeZone};
+use std::borrow::Cow;
+
+fn window(start: i64, end: i64) -> Window {
+    Window { start: Instant::at(start), end: Instant::at(end) }
+}
+
+
+#[test]
+fn five_minutes() {
+    let windows = FixedWindows::tumbling(Duration::of(300));
+    assert_eq!(windows.window(Instant::at(600)), window(600, 900));
+    assert_eq!(windows.window(Instant::at_ms(899, 999)), window(600, 900));
+}
+
+#[test]
+fn before_the_epoch() {
+    let windows = FixedWindows::tumbling(Duration::of(3600));
+    assert_eq!(windows.window(Instant::at(-1)), window(-3600, 0));
+}
+
+#[test]
+fn aligned() {
+    // Hours in a zone that’s 30 minutes ahead of a whole hour.
+    let windows = FixedWindows::tumbling(Duration::of(3600)).aligned_to(Instant::at(1800));
+    assert_eq!(windows.window(Instant::at(7000)), window(5400, 9000));
+}
+
+#[test]
+fn tumbling_between() {
+    let windows = FixedWindows::tumbling(Duration::of(300));
+    let all: Vec<Window> = windows.windows_between(Instant::at(250), Instant::at(900)).collect();
+    assert_eq!(all, vec![ window(0, 300), window(300, 600), window(600, 900) ]);
+}
+
+#[test]
+fn between_is_exact_size() {
+    let windows = FixedWindows::tumbling(Duration::of(60));
+    let range = windows.windows_between(Instant::at(0), Instant::at(3601));
+    assert_eq!(range.len(), 61);
+    assert_eq!(range.clone().next_back(), Some(window(3600, 3660)));
+}
+
+#[test]
+fn tumbling_empty_span() {
+    let windows = FixedWindows::tumbling(Duration::of(60));
+    assert_eq!(windows.windows_between(Instant::at(100), Instant::at(100)).count(), 0);
+    assert_eq!(windows.windows_between(Instant::at(100), Instant::at(50)).count(), 0);
+}
+
+#[test]
+fn duration() {
+    assert_eq!(window(600, 900).duration(), Duration::of(300));
+}
+
+#[test]
+#[should_panic]
+fn zero_length() {
+    let _ = FixedWindows::tumbling(Duration::zero());
+}
+
+
+#[test]
+fn containing() {
+    let windows = FixedWindows::sliding(Duration::of(600), Duration::of(200));
+    let all: Vec<Window> = windows.windows_containing(Instant::at(1000)).collect();
+    assert_eq!(all, vec![ window(600, 1200), window(800, 1400), window(1000, 1600) ]);
+}
+
+#[test]
+fn latest_window() {
+    let windows = FixedWindows::sliding(Duration::of(600), Duration::of(200));
+    assert_eq!(windows.window(Instant::at(1100)), window(1000, 1600));
+}
+
+#[test]
+fn sliding_between() {
+    let windows = FixedWindows::sliding(Duration::of(300), Duration::of(100));
+    let starts: Vec<i64> = windows.windows_between(Instant::at(0), Instant::at(200))
+                                  .map(|w| w.start.seconds())
+                                  .collect();
+    assert_eq!(starts, vec![ -200, -100, 0, 100 ]);
+}
+
+#[test]
+fn hopping_with_gaps() {
+    // One-minute windows every five minutes don’t cover everything.
+    let windows = FixedWindows::sliding(Duration::of(60), Duration::of(300));
+    assert_eq!(windows.windows_containing(Instant::at(100)).count(), 0);
+    assert_eq!(windows.windows_containing(Instant::at(310)).collect::<Vec<_>>(), vec![ window(300, 360) ]);
+}
+
+
+// The clocks go forward from 01:00 to 02:00 on the 30th of March 2008,
+// and back from 02:00 to 01:00 on the 26th of October.
+const TEST_ZONESET: &StaticTimeZone<'static> = &StaticTimeZone {
+    name: "Test Zoneset",
+    fixed_timespans: FixedTimespanSet {
+        first: FixedTimespan { offset: 0, is_dst: false, name: Cow::Borrowed("ZONE_A") },
+        rest: &[
+            (1206838800, FixedTimespan { offset: 3600, is_dst: true, name: Cow::Borrowed("ZONE_B") }),
+            (1224982800, FixedTimespan { offset: 0, is_dst: false, name: Cow::Borrowed("ZONE_A") }),
+        ]
+    }
+};
+
+#[test]
+fn day_lengths() {
+    let zone = TimeZone(TimeZoneSource::Static(TEST_ZONESET));
+    let daily = DailyWindows::new(&zone);
+
+    assert_eq!(daily.day(LocalDate::ymd(2008, March, 29).unwrap()).duration(), Duration::of(86400));
+    assert_eq!(daily.day(LocalDate::ymd(2008, March, 30).unwrap()).duration(), Duration::of(82800));
+    assert_eq!(daily.day(LocalDate::ymd(2008, October, 26).unwrap()).duration(), Duration::of(90000));
+}
+
+#[test]
+fn window_containing() {
+    let zone = TimeZone(TimeZoneSource::Static(TEST_ZONESET));
+    let daily = DailyWindows::new(&zone);
+
+    // 23:30 UTC on the 25th of October is 00:30 on the 26th locally.
+    let instant = Instant::at(1224977400);
+    assert_eq!(daily.window(instant), daily.day(LocalDate::ymd(2008, October, 26).unwrap()));
+    assert!(daily.window(instant).contains(instant));
+}
+
+#[test]
+fn at_the_change() {
+    let zone = TimeZone(TimeZoneSource::Static(TEST_ZONESET));
+    let daily = DailyWindows::new(&zone);
+
+    for instant in &[ 1206838799, 1206838800, 1206838801, 1224982799, 1224982800, 1224982801 ] {
+        assert!(daily.window(Instant::at(*instant)).contains(Instant::at(*instant)));
+    }
+}
+
+#[test]
+fn daily_between() {
+    let zone = TimeZone(TimeZoneSource::Static(TEST_ZONESET));
+    let daily = DailyWindows::new(&zone);
+
+    let start = LocalDateTime::new(LocalDate::ymd(2008, March, 29).unwrap(), LocalTime::hm(12, 0).unwrap()).to_instant();
+    let end   = LocalDateTime::new(LocalDate::ymd(2008, March, 31).unwrap(), LocalTime::hm(0, 0).unwrap()).to_instant();
+
+    let lengths: Vec<Duration> = daily.windows_between(start, end).map(|w| w.duration()).collect();
+    assert_eq!(lengths, vec![ Duration::of(86400), Duration::of(82800), Duration::of(86400) ]);
+}
+
+#[test]
+fn windows_follow_on() {
+    let zone = TimeZone(TimeZoneSource::Static(TEST_ZONESET));
+    let daily = DailyWindows::new(&zone);
+
+    let days: Vec<Window> = daily.windows_between(Instant::at(1199145600), Instant::at(1230768000)).collect();
+    assert_eq!(days.len(), 366);
+    assert!(days.windows(2).all(|pair| pair[0].end == pair[1].start));
+}
+
+#[test]
+fn daily_empty_span() {
+    let zone = TimeZone(TimeZoneSource::Static(TEST_ZONESET));
+    let daily = DailyWindows::new(&zone);
+    assert_eq!(daily.windows_between(Instant::at(1224977400), Instant::at(1224977400)).count(), 0);
+}